@@ -2,6 +2,10 @@
 ///
 /// Derives the [`FormatMarker`] trait, and defines an `const_debug_fmt` inherent
 /// method to format a type at compile-time.
+///
+/// With the "const_trait_impl" feature, it also implements the [`FmtDebug`] trait,
+/// so that the type can be Debug formatted inside of arrays, `Option`s, and `Result`s,
+/// and at runtime by wrapping it in [`ConstFmt`].
 /// 
 /// # Features 
 /// 
@@ -11,6 +15,10 @@
 /// require the unstable
 /// [`const_mut_refs`](https://github.com/rust-lang/rust/issues/57349) feature..
 ///
+/// When the "const_trait_impl" feature is enabled,
+/// crates using this derive macro also need to enable the `const_trait_impl` nightly feature,
+/// because [`FmtDebug`] is a const trait.
+///
 /// # Limitations
 ///
 /// Compile-time formatting currently imposes these limitations on users,
@@ -32,6 +40,12 @@
 /// - Allows users to provide a function/macro/wrapper to format a field.
 ///
 /// - Automatically detect some builtin/standard library types that are generic.
+/// Arrays, slices, `Option`s, `Result`s, and tuples (up to any arity)
+/// are detected syntactically,
+/// the elements of arrays/slices/tuples and the payloads of `Option`s/`Result`s
+/// are formatted based on their own detected type,
+/// so nested types like `[[Foo; 4]; 4]`, `[(u8, Foo); 2]`,
+/// and `Option<Result<Foo, Bar>>` can be formatted.
 ///
 /// - Allow users to ignore a field.
 ///
@@ -55,6 +69,7 @@
 /// 
 /// ```rust
 /// # #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 /// #[derive(const_format::ConstDebug)]
 /// #[cdeb(impls(
 ///     "Foo<u8, u64>",
@@ -120,10 +135,19 @@
 /// Treats the field as being a slice/array,
 /// printing the elements with debug formatting.
 ///
+/// - `#[cdeb(is_a(map))]`:
+/// Treats the field as being a slice/array of `(key, value)` tuples,
+/// printing it like a map (eg: `{"foo": 3, "bar": 5}`),
+/// with the keys and values printed with debug formatting.
+///
 /// - `#[cdeb(is_a(Option))]`/`#[cdeb(is_a(option))]`:
 /// Treats the field as being an Option, 
 /// printing the contents with debug formatting.
 ///
+/// - `#[cdeb(is_a(Result))]`/`#[cdeb(is_a(result))]`:
+/// Treats the field as being a Result, 
+/// printing the contents with debug formatting.
+///
 /// - `#[cdeb(is_a(newtype))]`:
 /// Treats the field as being being a single field tuple struct, 
 /// using the identifier of the field type as the name of the struct,
@@ -141,6 +165,7 @@
 /// 
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 /// 
 /// use const_format::{ConstDebug, formatc};
 /// 
@@ -189,6 +214,7 @@
 /// 
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 /// 
 /// use const_format::{ConstDebug, formatc};
 /// 
@@ -226,6 +252,7 @@
 /// 
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 /// 
 /// use const_format::{ConstDebug, formatc};
 /// 
@@ -239,6 +266,7 @@
 ///     arr: [3, 5, 8, 13],
 ///     opt: Some(Unit),
 ///     wrap: Wrapping(21),
+///     table: &[("foo", 3), ("bar", 5)],
 ///     not_option: Option(PhantomData), // This is not the standard library `Option`
 /// };
 /// 
@@ -258,6 +286,10 @@
 ///     wrap: Wrapping(
 ///         21,
 ///     ),
+///     table: {
+///         \"foo\": 3,
+///         \"bar\": 5,
+///     },
 ///     not_option: Option(
 ///         PhantomData,
 ///     ),
@@ -278,6 +310,9 @@
 ///     #[cdeb(is_a(newtype))]
 ///     wrap: Wrapping<usize>,
 ///
+///     #[cdeb(is_a(map))]
+///     table: Table,
+///
 ///     #[cdeb(is_a(not_std))]
 ///     not_option: Option<u32>, 
 ///     
@@ -285,6 +320,7 @@
 /// 
 /// type Array = [u32; 4];
 /// type Opt = std::option::Option<Unit>;
+/// type Table = &'static [(&'static str, u32)];
 /// 
 /// #[derive(ConstDebug)]
 /// struct Unit;
@@ -295,6 +331,8 @@
 /// ```
 ///
 /// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
+/// [`FmtDebug`]: ./fmt/trait.FmtDebug.html
+/// [`ConstFmt`]: ./fmt/struct.ConstFmt.html
 /// [`impls attribute`]: #cdebimpls
 ///
 ///
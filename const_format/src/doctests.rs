@@ -43,6 +43,7 @@ pub struct ImplFmtWhereClause;
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// #[derive(const_format::ConstDebug)]
 /// struct Foo<T>(*const T)
@@ -97,3 +98,26 @@ pub struct ConstDebugWhereClause;
 /// ```
 ///
 pub struct AsStr_For_StrWriterMut_NoEncoding;
+
+/// ```rust
+/// const_format::str_index!("foo", 0..3);
+/// const_format::str_index!("ñ", 0..2);
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_index!("foo", 0..4);
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_index!("foo", 2..1);
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_index!("ñ", 0..1);
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_index!("foo", ..=usize::MAX);
+/// ```
+///
+pub struct StrIndexErrors;
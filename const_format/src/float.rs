@@ -0,0 +1,612 @@
+//! Compile-time formatting of `f32` and `f64`.
+//!
+//! The digits are generated with only integer arithmetic,
+//! using the algorithm from "Printing Floating-Point Numbers Quickly and Accurately"
+//! by Burger and Dybvig, with arbitrary precision integers.
+
+use crate::formatting::{Formatting, FormattingFlags};
+
+/// The amount of limbs in a `Big`,
+/// which is enough for every intermediate value when formatting an `f64`.
+const LIMBS: usize = 20;
+
+/// The maximum amount of significant digits that a float is formatted with,
+/// `f64`s can't have more than 767 significant digits in their exact representation.
+const MAX_DIGITS: usize = 768;
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An unsigned arbitrary precision integer, with only the operations used in this module.
+#[derive(Copy, Clone)]
+struct Big {
+    limbs: [u64; LIMBS],
+    /// The amount of used limbs, the limbs at and after `len` are all zero.
+    len: usize,
+}
+
+impl Big {
+    const fn new(n: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = n;
+        Big {
+            limbs,
+            len: (n != 0) as usize,
+        }
+    }
+
+    const fn normalized(limbs: [u64; LIMBS], mut len: usize) -> Self {
+        while len != 0 && limbs[len - 1] == 0 {
+            len -= 1;
+        }
+        Big { limbs, len }
+    }
+
+    const fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    const fn mul_small(mut self, mul: u64) -> Self {
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < self.len {
+            let prod = (self.limbs[i] as u128) * (mul as u128) + (carry as u128);
+            self.limbs[i] = prod as u64;
+            carry = (prod >> 64) as u64;
+            i += 1;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry;
+            self.len += 1;
+        }
+        self
+    }
+
+    const fn mul_pow2(self, bits: u32) -> Self {
+        let limb_shift = (bits / 64) as usize;
+        let bit_shift = bits % 64;
+
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < self.len {
+            let limb = self.limbs[i];
+            limbs[i + limb_shift] |= limb << bit_shift;
+            if bit_shift != 0 && (limb >> (64 - bit_shift)) != 0 {
+                limbs[i + limb_shift + 1] |= limb >> (64 - bit_shift);
+            }
+            i += 1;
+        }
+
+        let len = if self.len == 0 {
+            0
+        } else {
+            self.len + limb_shift + 1
+        };
+        Self::normalized(limbs, if len > LIMBS { LIMBS } else { len })
+    }
+
+    const fn mul_pow10(mut self, mut exp: u32) -> Self {
+        while exp >= 19 {
+            self = self.mul_small(10_000_000_000_000_000_000);
+            exp -= 19;
+        }
+        let mut mul = 1;
+        while exp != 0 {
+            mul *= 10;
+            exp -= 1;
+        }
+        self.mul_small(mul)
+    }
+
+    const fn add(mut self, other: &Big) -> Self {
+        let len = if self.len > other.len {
+            self.len
+        } else {
+            other.len
+        };
+        let mut carry = false;
+        let mut i = 0;
+        while i < len {
+            let (sum, carry_a) = self.limbs[i].overflowing_add(other.limbs[i]);
+            let (sum, carry_b) = sum.overflowing_add(carry as u64);
+            self.limbs[i] = sum;
+            carry = carry_a | carry_b;
+            i += 1;
+        }
+        self.len = len;
+        if carry {
+            self.limbs[len] = 1;
+            self.len += 1;
+        }
+        self
+    }
+
+    /// Subtracts `other` from `self`, requires `self >= other`.
+    const fn sub(mut self, other: &Big) -> Self {
+        let mut borrow = false;
+        let mut i = 0;
+        while i < self.len {
+            let (diff, borrow_a) = self.limbs[i].overflowing_sub(other.limbs[i]);
+            let (diff, borrow_b) = diff.overflowing_sub(borrow as u64);
+            self.limbs[i] = diff;
+            borrow = borrow_a | borrow_b;
+            i += 1;
+        }
+        Self::normalized(self.limbs, self.len)
+    }
+
+    /// Compares `self` and `other`, returning `-1` for less, `0` for equal, and `1` for greater.
+    const fn cmp(&self, other: &Big) -> i8 {
+        if self.len != other.len {
+            return if self.len < other.len { -1 } else { 1 };
+        }
+        let mut i = self.len;
+        while i != 0 {
+            i -= 1;
+            if self.limbs[i] != other.limbs[i] {
+                return if self.limbs[i] < other.limbs[i] {
+                    -1
+                } else {
+                    1
+                };
+            }
+        }
+        0
+    }
+
+    /// Computes the quotient and remainder of dividing `self` by `other`,
+    /// requires the quotient to be less than 10.
+    const fn div_rem_digit(mut self, other: &Big) -> (u8, Self) {
+        let mut digit = 0;
+        while self.cmp(other) >= 0 {
+            self = self.sub(other);
+            digit += 1;
+        }
+        (digit, self)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The bits of an `f32` or `f64`.
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct Float {
+    bits: u64,
+    is_f32: bool,
+}
+
+/// A float decomposed into its kind and, for nonzero finite floats,
+/// into `mantissa * 2^exponent`.
+#[derive(Copy, Clone)]
+enum Class {
+    NaN,
+    Infinite,
+    Zero,
+    Finite {
+        mantissa: u64,
+        exponent: i32,
+        /// Whether the next smaller float is closer than the next larger float.
+        lower_closer: bool,
+    },
+}
+
+impl Float {
+    #[inline]
+    pub const fn from_f32(n: f32) -> Self {
+        Float {
+            bits: n.to_bits() as u64,
+            is_f32: true,
+        }
+    }
+
+    #[inline]
+    pub const fn from_f64(n: f64) -> Self {
+        Float {
+            bits: n.to_bits(),
+            is_f32: false,
+        }
+    }
+
+    const fn is_negative(self) -> bool {
+        let sign_bit = if self.is_f32 { 31 } else { 63 };
+        (self.bits >> sign_bit) != 0
+    }
+
+    const fn classify(self) -> Class {
+        let (mantissa_bits, exponent_bits) = if self.is_f32 { (23, 8) } else { (52, 11) };
+        let max_exponent = (1 << exponent_bits) - 1;
+        let bias = max_exponent / 2 + mantissa_bits;
+
+        let fraction = self.bits & ((1 << mantissa_bits) - 1);
+        let biased = ((self.bits >> mantissa_bits) & max_exponent) as i32;
+        let (max_exponent, bias) = (max_exponent as i32, bias as i32);
+
+        if biased == max_exponent {
+            if fraction == 0 {
+                Class::Infinite
+            } else {
+                Class::NaN
+            }
+        } else if biased == 0 {
+            if fraction == 0 {
+                Class::Zero
+            } else {
+                Class::Finite {
+                    mantissa: fraction,
+                    exponent: 1 - bias,
+                    lower_closer: false,
+                }
+            }
+        } else {
+            Class::Finite {
+                mantissa: fraction | (1 << mantissa_bits),
+                exponent: biased - bias,
+                lower_closer: fraction == 0 && biased > 1,
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The decimal digits of a finite float,
+/// whose value is `0.{digits} * 10^exponent`.
+///
+/// The digits after `len` are implicitly zeroes.
+#[derive(Copy, Clone)]
+struct Decimal {
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    exponent: isize,
+}
+
+impl Decimal {
+    const ZERO: Self = Decimal {
+        digits: [0; MAX_DIGITS],
+        len: 0,
+        exponent: 0,
+    };
+
+    /// Gets the digit for `10^place`
+    const fn digit_at(&self, place: isize) -> u8 {
+        let index = self.exponent - 1 - place;
+        if 0 <= index && (index as usize) < self.len {
+            self.digits[index as usize]
+        } else {
+            b'0'
+        }
+    }
+}
+
+/// Estimates the exponent of a `Decimal` for `mantissa * 2^exponent`,
+/// never returning more than the exponent it has.
+const fn estimate_exponent(mantissa: u64, exponent: i32) -> isize {
+    let log2 = exponent as i64 + (63 - mantissa.leading_zeros() as i64);
+    // 78913 / 2^18 is slightly less than `log10(2)`
+    ((log2 * 78913) >> 18) as isize
+}
+
+/// Scales `r` or `s` so that `r / s` is multiplied by `10^-exponent`
+const fn scale(r: Big, s: Big, exponent: isize) -> (Big, Big) {
+    if exponent >= 0 {
+        (r, s.mul_pow10(exponent as u32))
+    } else {
+        (r.mul_pow10((-exponent) as u32), s)
+    }
+}
+
+/// Computes the shortest digits that round trip to the `mantissa * 2^exponent` float.
+const fn shortest_digits(mantissa: u64, exponent: i32, lower_closer: bool) -> Decimal {
+    // Whether the numbers exactly halfway to the adjacent floats round to this float.
+    let inclusive = (mantissa & 1) == 0;
+
+    // The value is `r / s`, and the halfway points to the adjacent floats are
+    // `(r + m_plus) / s` and `(r - m_minus) / s`.
+    let (r, s, m_plus, m_minus) = match (exponent >= 0, lower_closer) {
+        (true, false) => {
+            let m = Big::new(1).mul_pow2(exponent as u32);
+            (
+                Big::new(mantissa).mul_pow2(exponent as u32 + 1),
+                Big::new(2),
+                m,
+                m,
+            )
+        }
+        (true, true) => {
+            let m = Big::new(1).mul_pow2(exponent as u32);
+            (
+                Big::new(mantissa).mul_pow2(exponent as u32 + 2),
+                Big::new(4),
+                m.mul_pow2(1),
+                m,
+            )
+        }
+        (false, false) => (
+            Big::new(mantissa).mul_pow2(1),
+            Big::new(1).mul_pow2((1 - exponent) as u32),
+            Big::new(1),
+            Big::new(1),
+        ),
+        (false, true) => (
+            Big::new(mantissa).mul_pow2(2),
+            Big::new(1).mul_pow2((2 - exponent) as u32),
+            Big::new(2),
+            Big::new(1),
+        ),
+    };
+
+    let mut dec_exponent = estimate_exponent(mantissa, exponent);
+    let (mut r, mut s) = scale(r, s, dec_exponent);
+    let (mut m_plus, mut m_minus) = if dec_exponent >= 0 {
+        (m_plus, m_minus)
+    } else {
+        let exp = (-dec_exponent) as u32;
+        (m_plus.mul_pow10(exp), m_minus.mul_pow10(exp))
+    };
+
+    loop {
+        let high = r.add(&m_plus).cmp(&s);
+        if high > 0 || (inclusive && high == 0) {
+            s = s.mul_small(10);
+            dec_exponent += 1;
+        } else {
+            break;
+        }
+    }
+
+    let mut out = Decimal::ZERO;
+    out.exponent = dec_exponent;
+    loop {
+        r = r.mul_small(10);
+        m_plus = m_plus.mul_small(10);
+        m_minus = m_minus.mul_small(10);
+
+        let (mut digit, rem) = r.div_rem_digit(&s);
+        r = rem;
+
+        let low = r.cmp(&m_minus);
+        let low = low < 0 || (inclusive && low == 0);
+        let high = r.add(&m_plus).cmp(&s);
+        let high = high > 0 || (inclusive && high == 0);
+
+        if high && (!low || r.mul_small(2).cmp(&s) >= 0) {
+            digit += 1;
+        }
+        out.digits[out.len] = b'0' + digit;
+        out.len += 1;
+
+        if low || high {
+            break out;
+        }
+    }
+}
+
+/// Computes the digits of the `mantissa * 2^exponent` float,
+/// rounded to `precision` decimal places (with ties rounding to even).
+const fn exact_digits(mantissa: u64, exponent: i32, precision: usize) -> Decimal {
+    let (r, s) = if exponent >= 0 {
+        (Big::new(mantissa).mul_pow2(exponent as u32), Big::new(1))
+    } else {
+        (Big::new(mantissa), Big::new(1).mul_pow2((-exponent) as u32))
+    };
+
+    let mut dec_exponent = estimate_exponent(mantissa, exponent);
+    let (mut r, mut s) = scale(r, s, dec_exponent);
+
+    while r.cmp(&s) >= 0 {
+        s = s.mul_small(10);
+        dec_exponent += 1;
+    }
+
+    let mut out = Decimal::ZERO;
+    out.exponent = dec_exponent;
+
+    let wanted = dec_exponent + precision as isize;
+    if wanted < 0 {
+        // Less than half of the last decimal place, rounds to zero.
+        return out;
+    }
+    let wanted = wanted as usize;
+
+    while out.len < wanted && out.len < MAX_DIGITS && !r.is_zero() {
+        r = r.mul_small(10);
+        let (digit, rem) = r.div_rem_digit(&s);
+        r = rem;
+        out.digits[out.len] = b'0' + digit;
+        out.len += 1;
+    }
+
+    let half = r.mul_small(2).cmp(&s);
+    let is_odd = out.len != 0 && out.digits[out.len - 1] % 2 == 1;
+    if out.len == wanted && (half > 0 || (half == 0 && is_odd)) {
+        let mut i = out.len;
+        loop {
+            if i == 0 {
+                out.digits[0] = b'1';
+                out.len = 1;
+                out.exponent += 1;
+                break;
+            }
+            i -= 1;
+            if out.digits[i] == b'9' {
+                out.digits[i] = b'0';
+            } else {
+                out.digits[i] += 1;
+                out.len = i + 1;
+                break;
+            }
+        }
+    }
+
+    out
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+enum Style {
+    /// For `inf` and `NaN`
+    Word(&'static [u8]),
+    Decimal {
+        int_len: usize,
+        frac_len: usize,
+    },
+    Exponential {
+        exponent: isize,
+        exponent_len: usize,
+    },
+}
+
+#[doc(hidden)]
+/// A float formatted with some `Formatting` and `FormattingFlags`,
+/// where the byte at each position is gotten with the `byte_at` method.
+///
+/// This mirrors how `std` formats floats,
+/// with Debug formatting using exponential notation for very large and very small numbers.
+#[derive(Copy, Clone)]
+pub struct FmtFloat {
+    decimal: Decimal,
+    sign: Option<u8>,
+    style: Style,
+    /// The length of the formatted float, all of its bytes are ascii.
+    pub len: usize,
+}
+
+impl FmtFloat {
+    pub const fn new(float: Float, fmt: Formatting, flags: FormattingFlags) -> Self {
+        let class = float.classify();
+
+        let sign = if float.is_negative() && !matches!(class, Class::NaN) {
+            Some(b'-')
+        } else if flags.sign_plus() && !matches!(class, Class::NaN) {
+            Some(b'+')
+        } else {
+            None
+        };
+
+        let decimal = match (class, flags.precision()) {
+            (Class::NaN, _) | (Class::Infinite, _) | (Class::Zero, _) => Decimal::ZERO,
+            (
+                Class::Finite {
+                    mantissa,
+                    exponent,
+                    lower_closer,
+                },
+                None,
+            ) => shortest_digits(mantissa, exponent, lower_closer),
+            (
+                Class::Finite {
+                    mantissa, exponent, ..
+                },
+                Some(precision),
+            ) => exact_digits(mantissa, exponent, precision),
+        };
+
+        let exponent = decimal.exponent;
+        let style = match class {
+            Class::NaN => Style::Word(b"NaN"),
+            Class::Infinite => Style::Word(b"inf"),
+            Class::Finite { .. }
+                if matches!(fmt, Formatting::Debug)
+                    && flags.precision().is_none()
+                    && (exponent <= -4 || exponent >= 17) =>
+            {
+                let exponent = exponent - 1;
+                let mut exponent_len = (exponent < 0) as usize + 1;
+                let mut abs = if exponent < 0 { -exponent } else { exponent };
+                while abs >= 10 {
+                    abs /= 10;
+                    exponent_len += 1;
+                }
+                Style::Exponential {
+                    exponent,
+                    exponent_len,
+                }
+            }
+            Class::Zero | Class::Finite { .. } => {
+                let fraction_digits = decimal.len as isize - exponent;
+                let fraction_digits = if fraction_digits < 0 {
+                    0
+                } else {
+                    fraction_digits as usize
+                };
+                Style::Decimal {
+                    int_len: if exponent > 1 { exponent as usize } else { 1 },
+                    frac_len: match (flags.precision(), fmt) {
+                        (Some(precision), _) => precision,
+                        (None, Formatting::Display) => fraction_digits,
+                        (None, Formatting::Debug) if fraction_digits == 0 => 1,
+                        (None, Formatting::Debug) => fraction_digits,
+                    },
+                }
+            }
+        };
+
+        let len = sign.is_some() as usize
+            + match style {
+                Style::Word(word) => word.len(),
+                Style::Decimal { int_len, frac_len } => {
+                    int_len + (frac_len != 0) as usize + frac_len
+                }
+                Style::Exponential { exponent_len, .. } => {
+                    decimal.len + (decimal.len > 1) as usize + 1 + exponent_len
+                }
+            };
+
+        FmtFloat {
+            decimal,
+            sign,
+            style,
+            len,
+        }
+    }
+
+    /// Gets the byte at the `index` position, requires `index < self.len`.
+    pub const fn byte_at(&self, index: usize) -> u8 {
+        let index = match self.sign {
+            Some(sign) if index == 0 => return sign,
+            Some(_) => index - 1,
+            None => index,
+        };
+
+        match self.style {
+            Style::Word(word) => word[index],
+            Style::Decimal { int_len, .. } => {
+                if index < int_len {
+                    self.decimal.digit_at((int_len - 1 - index) as isize)
+                } else if index == int_len {
+                    b'.'
+                } else {
+                    self.decimal.digit_at(int_len as isize - index as isize)
+                }
+            }
+            Style::Exponential {
+                exponent,
+                exponent_len,
+            } => {
+                let mantissa_len = self.decimal.len + (self.decimal.len > 1) as usize;
+                if index == 0 {
+                    self.decimal.digits[0]
+                } else if index < mantissa_len {
+                    if index == 1 {
+                        b'.'
+                    } else {
+                        self.decimal.digits[index - 1]
+                    }
+                } else if index == mantissa_len {
+                    b'e'
+                } else if exponent < 0 && index == mantissa_len + 1 {
+                    b'-'
+                } else {
+                    let mut abs = if exponent < 0 { -exponent } else { exponent };
+                    let mut from_end = mantissa_len + exponent_len - index;
+                    while from_end != 0 {
+                        abs /= 10;
+                        from_end -= 1;
+                    }
+                    b'0' + (abs % 10) as u8
+                }
+            }
+        }
+    }
+}
@@ -53,23 +53,75 @@
 //! - Named, from constant (eg: `formatc!("{FOO}")`):
 //! Uses the `FOO` constant from the enclosing scope.
 //!
+//! Arrays, slices, and `Option`s of standard library types
+//! (eg: `formatc!("{:?}", Some(10u8))`) can always be Debug formatted.
+//!
+//! With the "const_trait_impl" feature,
+//! tuples of up to 12 elements (eg: `formatc!("{:?}", (10u8, "foo", FOO))`)
+//! can be Debug formatted, each element is formatted with its [`FmtDebug`] impl.
+//!
+//! With the "const_trait_impl" feature,
+//! arrays, slices, `Option`s, and `Result`s of any type that implements [`FmtDebug`]
+//! (eg: `formatc!("{:?}", Some(FOO))`) can be Debug formatted,
+//! including those nested in other `Option`s or `Result`s to any depth
+//! (eg: `Some(Ok::<_, u8>(Some(FOO)))`).
+//!
 //! ### Formatters
 //!
 //! The format arguments can be formatted in these ways:
 //!
 //! - Debug formatting (eg: `formatc!("{:?}", 0u8)` ):<br>
 //! Similar to how Debug formatting in the standard library works,
-//! except that it does not escape unicode characters.
+//! escaping strings and characters the same way.
 //!
 //! - Display formatting (eg: `formatc!("{}", 0u8)`, `formatc!("{:}", 0u8)` )
 //!
-//! - Hexadecimal formatting (eg: `formatc!("{:x}", 0u8)`):
-//! Writes numbers in capialized hexadecimal.
-//! This can be combined with debug formatting, with the `"{:x?}"` formatter.
+//! - Hexadecimal formatting (eg: `formatc!("{:x}", 0u8)`, `formatc!("{:X}", 0u8)`):
+//! Writes numbers in capialized hexadecimal, `x` and `X` are equivalent.
+//! This can be combined with debug formatting, with the `"{:x?}"`/`"{:X?}"` formatters,
+//! unlike std, `"{:x?}"` also writes capitalized hexadecimal.
+//! Lowercase hexadecimal can be written with [`FormattingFlags::set_lower_hexadecimal`].
+//!
+//! - Octal formatting (eg: `formatc!("{:o}", 0u8)`):
+//! This can be combined with debug formatting, with the `"{:o?}"` formatter.
 //!
 //! - Binary formatting (eg: `formatc!("{:b}", 0u8)`):
 //! This can be combined with debug formatting, with the `"{:b?}"` formatter.
 //!
+//! ### Width, fill, and alignment
+//!
+//! Arguments can be padded to a minimum amount of chars,
+//! with the same `[[fill]align][0][width]` syntax as the standard library.
+//! eg: `"{:8}"`, `"{:>8}"`, `"{:*^20}"`, `"{:08}"`.
+//!
+//! Numbers are right aligned by default, and everything else is left aligned.
+//! The `0` flag pads numbers with zeroes after the sign.
+//!
+//! Display formatted arguments whose `const_display_fmt` method ignores the width
+//! are padded as a whole.
+//!
+//! Like the standard library, Debug formatting passes the width down to
+//! the numbers, `bool`s, and `()`s that the argument contains, which are padded individually,
+//! while Debug formatted strings and chars aren't padded.
+//!
+//! ### Sign
+//!
+//! The `+` flag (eg: `"{:+}"`, `"{:+#010x}"`) writes a `+` before non-negative numbers,
+//! and before every hexadecimal, octal, or binary number.
+//!
+//! ### Precision
+//!
+//! A precision (eg: `"{:.3}"`, `"{:>8.3}"`) is the maximum amount of chars that
+//! Display formatted strings are written with,
+//! and the minimum amount of digits that integers are written with.
+//!
+//! Note that the standard library ignores the precision of integers.
+//!
+//! The width and precision can also be taken from `usize` arguments,
+//! eg: `"{:1$}"`, `"{:>w$}"`, `"{:.prec$}"`,
+//! and `"{:.*}"` takes the precision from the next positional argument,
+//! before the formatted argument.
+//!
 //! ### Alternate flag
 //!
 //! The alternate flag allows types to format themselves in an alternate way,
@@ -80,9 +132,12 @@
 //! - The Debug formater (eg: `formatc!("{:#?}", FOO)`):
 //! pretty print structs and enums.
 //!
-//! - The hexadecimal formater (eg: `formatc!("{:#x}", FOO)`):
+//! - The hexadecimal formaters (eg: `formatc!("{:#x}", FOO)`, `formatc!("{:#X}", FOO)`):
 //! prefixes numbers with `0x`.
 //!
+//! - The octal formater (eg: `formatc!("{:#o}", FOO)`):
+//! prefixes numbers with `0o`.
+//!
 //! - The binary formater (eg: `formatc!("{:#b}", FOO)`):
 //! prefixes numbers with `0b`.
 //!
@@ -98,6 +153,7 @@
 #![cfg_attr(feature = "derive", doc = "```rust")]
 #![cfg_attr(not(feature = "derive"), doc = "```ignore")]
 //! #![feature(const_mut_refs)]
+//! # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 //!
 //! use const_format::{Error, Formatter, FormattingFlags, PWrapper, StrWriter};
 //! use const_format::{ConstDebug, strwriter_as_str, try_, unwrap, writec};
@@ -229,6 +285,7 @@
 //! ```
 //!
 //! [`FormatMarker`]: ../marker_traits/trait.FormatMarker.html
+//! [`FmtDebug`]: ./trait.FmtDebug.html
 //! [`ConstDebug`]: ../derive.ConstDebug.html
 //!
 //!
@@ -236,17 +293,30 @@
 //!
 //!
 
+mod const_fmt;
 mod error;
+mod fmt_writer;
 mod formatter;
 mod std_type_impls;
 mod str_writer;
 mod str_writer_mut;
 
-pub use crate::formatting::{FormattingFlags, NumberFormatting};
+pub use crate::formatting::{Alignment, FormattingFlags, NumberFormatting};
+
+#[doc(no_inline)]
+pub use crate::{formatc_writer, formatted_len};
+
+#[cfg(feature = "const_trait_impl")]
+pub use self::const_fmt::FmtDebug;
 
 pub use self::{
+    const_fmt::{ConstFmt, FmtDisplay},
     error::Error,
-    formatter::{ComputeStrLength, DebugList, DebugSet, DebugStruct, DebugTuple, Formatter},
+    fmt_writer::FmtWriter,
+    formatter::{
+        Checkpoint, ComputeStrLength, DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple,
+        Formatter, Join, PaddingStart,
+    },
     str_writer::StrWriter,
-    str_writer_mut::{NoEncoding, StrWriterMut, Utf8Encoding},
+    str_writer_mut::{NoEncoding, StrWriterCheckpoint, StrWriterMut, Truncation, Utf8Encoding},
 };
@@ -0,0 +1,202 @@
+use crate::{
+    fmt::{Error, FmtWriter, Formatter, FormattingFlags},
+    formatting::Alignment,
+    wrapper_types::PWrapper,
+};
+
+use core::fmt as cfmt;
+
+#[cfg(feature = "const_trait_impl")]
+mod fmt_debug;
+
+#[cfg(feature = "const_trait_impl")]
+pub use self::fmt_debug::FmtDebug;
+
+/// Calls the `const_display_fmt` method of a type at runtime,
+/// used by [`ConstFmt`] to implement `core::fmt::Display`.
+///
+/// The [`impl_fmt`] macro implements this trait for types
+/// with a `const fn const_display_fmt(&self, ...)` method.
+///
+/// [`ConstFmt`]: ./struct.ConstFmt.html
+/// [`impl_fmt`]: ../macro.impl_fmt.html
+pub trait FmtDisplay {
+    /// Formats `self` with Display formatting.
+    fn fmt_display(&self, f: &mut Formatter<'_>) -> Result<(), Error>;
+}
+
+/// Implements the `core::fmt` formatting traits for types with const formatting methods,
+/// so that they can be printed at runtime (eg: with `println`).
+///
+/// This implements:
+///
+/// - `Display` with [`FmtDisplay`].
+///
+/// - `Debug` with [`FmtDebug`], requires the "const_trait_impl" feature.
+///
+/// - `LowerHex`, `UpperHex`, `Octal`, and `Binary` with [`FmtDebug`],
+/// requires the "const_trait_impl" feature.
+///
+/// These work like the `{:x}`/`{:X}`/`{:o}`/`{:b}` formatting of the macros in this crate.
+///
+/// The width, fill, alignment, sign, precision, and alternate flags
+/// of the `core::fmt::Formatter` are passed to the [`Formatter`].
+///
+/// # Length
+///
+/// The text is written to the `core::fmt::Formatter` with a [`FmtWriter`],
+/// so there's no limit on its length,
+/// text longer than 4 KiB is formatted once for every 4 KiB written.
+///
+/// # Example
+///
+#[cfg_attr(all(feature = "derive", feature = "const_trait_impl"), doc = "```rust")]
+#[cfg_attr(
+    not(all(feature = "derive", feature = "const_trait_impl")),
+    doc = "```ignore"
+)]
+/// #![feature(const_mut_refs, const_trait_impl)]
+///
+/// use const_format::{ConstDebug, formatc};
+/// use const_format::fmt::ConstFmt;
+///
+/// #[derive(ConstDebug)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// const POINT: Point = Point{x: 3, y: 21};
+///
+/// assert_eq!(formatc!("{:?}", POINT), "Point { x: 3, y: 21 }");
+/// assert_eq!(format!("{:?}", ConstFmt(&POINT)), "Point { x: 3, y: 21 }");
+///
+/// assert_eq!(formatc!("{:x}", POINT), "Point { x: 3, y: 15 }");
+/// assert_eq!(format!("{:x}", ConstFmt(&POINT)), "Point { x: 3, y: 15 }");
+///
+/// assert_eq!(
+///     format!("{:#b}", ConstFmt(&POINT)),
+///     "Point {\n    x: 0b11,\n    y: 0b10101,\n}",
+/// );
+///
+/// ```
+///
+/// [`FmtDebug`]: ./trait.FmtDebug.html
+/// [`FmtDisplay`]: ./trait.FmtDisplay.html
+/// [`FmtWriter`]: ./struct.FmtWriter.html
+/// [`Formatter`]: ./struct.Formatter.html
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ConstFmt<T>(pub T);
+
+#[cfg(feature = "const_trait_impl")]
+impl<T: FmtDebug> cfmt::Debug for ConstFmt<T> {
+    fn fmt(&self, f: &mut cfmt::Formatter<'_>) -> cfmt::Result {
+        write_const_fmt(f, flags_from(f), |fmt| self.0.fmt_debug(fmt))
+    }
+}
+
+impl<T: FmtDisplay> cfmt::Display for ConstFmt<T> {
+    fn fmt(&self, f: &mut cfmt::Formatter<'_>) -> cfmt::Result {
+        write_const_fmt(f, flags_from(f), |fmt| self.0.fmt_display(fmt))
+    }
+}
+
+#[cfg(feature = "const_trait_impl")]
+macro_rules! impl_number_fmt_traits {
+    ( $( $trait:ident => $set_num_fmt:ident, )* ) => (
+        $(
+            impl<T: FmtDebug> cfmt::$trait for ConstFmt<T> {
+                fn fmt(&self, f: &mut cfmt::Formatter<'_>) -> cfmt::Result {
+                    let flags = flags_from(f).$set_num_fmt();
+                    write_const_fmt(f, flags, |fmt| self.0.fmt_debug(fmt))
+                }
+            }
+        )*
+    )
+}
+
+#[cfg(feature = "const_trait_impl")]
+impl_number_fmt_traits! {
+    LowerHex => set_lower_hexadecimal,
+    UpperHex => set_hexadecimal,
+    Octal => set_octal,
+    Binary => set_binary,
+}
+
+fn flags_from(f: &cfmt::Formatter<'_>) -> FormattingFlags {
+    let mut flags = FormattingFlags::NEW
+        .set_alternate(f.alternate())
+        .set_sign_plus(f.sign_plus())
+        .set_sign_aware_zero_pad(f.sign_aware_zero_pad())
+        .set_fill(f.fill());
+
+    if let Some(width) = f.width() {
+        flags = flags.set_width(width);
+    }
+    if let Some(precision) = f.precision() {
+        flags = flags.set_precision(precision);
+    }
+    match f.align() {
+        Some(cfmt::Alignment::Left) => flags = flags.set_alignment(Alignment::Left),
+        Some(cfmt::Alignment::Right) => flags = flags.set_alignment(Alignment::Right),
+        Some(cfmt::Alignment::Center) => flags = flags.set_alignment(Alignment::Center),
+        None => {}
+    }
+    flags
+}
+
+fn write_const_fmt<F>(out: &mut cfmt::Formatter<'_>, flags: FormattingFlags, fmt: F) -> cfmt::Result
+where
+    F: Fn(&mut Formatter<'_>) -> Result<(), Error>,
+{
+    FmtWriter::new(out)
+        .write_with(flags, fmt)
+        .map_err(|_| cfmt::Error)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_pwrapper_fmt {
+    ($($ty:ty),* $(,)?) => (
+        $(
+            impl FmtDisplay for $ty {
+                #[inline]
+                fn fmt_display(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(*self).const_display_fmt(f)
+                }
+            }
+        )*
+    );
+}
+
+impl_pwrapper_fmt! {
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize,
+    bool, char,
+}
+
+#[cfg(feature = "float")]
+impl_pwrapper_fmt! {
+    f32, f64,
+}
+
+impl FmtDisplay for str {
+    #[inline]
+    fn fmt_display(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self).const_display_fmt(f)
+    }
+}
+
+macro_rules! impl_for_references {
+    ($($ref:ty),*) => (
+        $(
+            impl<T: ?Sized + FmtDisplay> FmtDisplay for $ref {
+                #[inline]
+                fn fmt_display(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    (**self).fmt_display(f)
+                }
+            }
+        )*
+    )
+}
+
+impl_for_references! { &T, &mut T }
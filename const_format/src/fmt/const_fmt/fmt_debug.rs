@@ -0,0 +1,157 @@
+use crate::{
+    fmt::{Error, Formatter},
+    wrapper_types::PWrapper,
+};
+
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+    ptr::NonNull,
+};
+
+/// Calls the `const_debug_fmt` method of a type,
+/// used to debug format the elements of arrays, slices, tuples, `Option`s, and `Result`s,
+/// and by [`ConstFmt`] to implement `core::fmt::Debug`.
+///
+/// The [`impl_fmt`] and [`ConstDebug`] macros implement this trait for types
+/// with a `const fn const_debug_fmt(&self, ...)` method.
+///
+/// # Features
+///
+/// This trait requires the "const_trait_impl" cargo feature.
+///
+/// This is a const trait, so crates that debug format arrays/slices/tuples/`Option`s/`Result`s
+/// in const contexts, or use the [`impl_fmt`] and [`ConstDebug`] macros,
+/// need to enable the `const_trait_impl` nightly feature.
+///
+/// [`ConstFmt`]: ./struct.ConstFmt.html
+/// [`impl_fmt`]: ../macro.impl_fmt.html
+/// [`ConstDebug`]: ../derive.ConstDebug.html
+pub const trait FmtDebug {
+    /// Formats `self` with Debug formatting.
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error>;
+}
+
+macro_rules! impl_pwrapper_fmt {
+    ($($ty:ty),* $(,)?) => (
+        $(
+            impl const FmtDebug for $ty {
+                #[inline]
+                fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(*self).const_debug_fmt(f)
+                }
+            }
+        )*
+    );
+}
+
+impl_pwrapper_fmt! {
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize,
+    bool, char, (),
+}
+
+#[cfg(feature = "float")]
+impl_pwrapper_fmt! {
+    f32, f64,
+}
+
+// Implements `FmtDebug` for the remaining std types that can be debug formatted,
+// by coercing them to the type that has the `const_debug_fmt` method.
+macro_rules! impl_coerced_fmt {
+    ($( impl[$($impl_:tt)*] $ty:ty; )*) => (
+        $(
+            impl<$($impl_)*> const FmtDebug for $ty {
+                #[inline]
+                fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    coerce_to_fmt!(self).const_debug_fmt(f)
+                }
+            }
+        )*
+    );
+}
+
+impl_coerced_fmt! {
+    impl[] NonZeroU8;
+    impl[] NonZeroI8;
+    impl[] NonZeroU16;
+    impl[] NonZeroI16;
+    impl[] NonZeroU32;
+    impl[] NonZeroI32;
+    impl[] NonZeroU64;
+    impl[] NonZeroI64;
+    impl[] NonZeroU128;
+    impl[] NonZeroI128;
+    impl[] NonZeroUsize;
+    impl[] NonZeroIsize;
+    impl[T,] *const T;
+    impl[T,] *mut T;
+    impl[T,] NonNull<T>;
+    impl[] PhantomPinned;
+    impl[] Range<usize>;
+    impl[] RangeFrom<usize>;
+    impl[] RangeTo<usize>;
+    impl[] RangeToInclusive<usize>;
+    impl[] RangeInclusive<usize>;
+    impl[] RangeFull;
+}
+
+impl const FmtDebug for str {
+    #[inline]
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self).const_debug_fmt(f)
+    }
+}
+
+impl<T: ?Sized> const FmtDebug for PhantomData<T> {
+    #[inline]
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(*self).const_debug_fmt(f)
+    }
+}
+
+impl<T: [const] FmtDebug> const FmtDebug for [T] {
+    #[inline]
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self).const_debug_fmt(f)
+    }
+}
+
+impl<T: [const] FmtDebug, const N: usize> const FmtDebug for [T; N] {
+    #[inline]
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self as &[T]).const_debug_fmt(f)
+    }
+}
+
+impl<T: [const] FmtDebug> const FmtDebug for Option<T> {
+    #[inline]
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self).const_debug_fmt(f)
+    }
+}
+
+impl<T: [const] FmtDebug, E: [const] FmtDebug> const FmtDebug for Result<T, E> {
+    #[inline]
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self).const_debug_fmt(f)
+    }
+}
+
+macro_rules! impl_for_references {
+    ($($ref:ty),*) => (
+        $(
+            impl<T: ?Sized + [const] FmtDebug> const FmtDebug for $ref {
+                #[inline]
+                fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    (**self).fmt_debug(f)
+                }
+            }
+        )*
+    )
+}
+
+impl_for_references! { &T, &mut T }
@@ -11,6 +11,18 @@ pub enum Error {
     /// Attempted to index a string arguent by an range where the one of the bounds
     /// was not on a char boundary.
     NotOnCharBoundary,
+    /// The `core::fmt::Write` that a [`FmtWriter`] writes into returned an error.
+    ///
+    /// [`FmtWriter`]: ./struct.FmtWriter.html
+    FmtWrite,
+    /// Attempted to right or center align text written into a [`FmtWriter`]
+    /// with [`Formatter::pad_since`], without writing the padding that goes before it with
+    /// [`Formatter::padding_start_for`].
+    ///
+    /// [`FmtWriter`]: ./struct.FmtWriter.html
+    /// [`Formatter::pad_since`]: ./struct.Formatter.html#method.pad_since
+    /// [`Formatter::padding_start_for`]: ./struct.Formatter.html#method.padding_start_for
+    UnmeasuredPadding,
 }
 
 impl Display for Error {
@@ -23,6 +35,10 @@ impl Display for Error {
             Self::NotOnCharBoundary => {
                 fmt.write_str("Attempted to index a byte that's not on a char boundary.")
             }
+            Self::FmtWrite => fmt.write_str("Could not write into the core::fmt::Write"),
+            Self::UnmeasuredPadding => {
+                fmt.write_str("Could not write the padding before the text into a FmtWriter")
+            }
         }
     }
 }
@@ -48,6 +64,8 @@ impl Error {
             NotEnoughSpace,
             NotAscii,
             NotOnCharBoundary,
+            FmtWrite,
+            UnmeasuredPadding,
         };
 
         match self {
@@ -56,6 +74,10 @@ impl Error {
             Error::NotOnCharBoundary => {
                 ["Attempted to index a byte that's not on a char boundary."][i]
             }
+            Error::FmtWrite => ["Could not write into the core::fmt::Write"][i],
+            Error::UnmeasuredPadding => {
+                ["Could not write the padding before the text into a FmtWriter"][i]
+            }
         };
         loop {}
     }
@@ -0,0 +1,397 @@
+use crate::{
+    fmt::{
+        str_writer_mut::is_valid_str_range, ComputeStrLength, Error, Formatter, FormattingFlags,
+        NoEncoding, StrWriterMut,
+    },
+    formatting::{count_chars, integer_pad_position, EncodedChar},
+    utils::{min_usize, saturate_range},
+    wrapper_types::AsciiStr,
+};
+
+use core::{fmt as cfmt, ops::Range};
+
+/// Writes the text that a [`Formatter`] formats into a `core::fmt::Write`, at runtime.
+///
+/// This allows the same `const_debug_fmt`/`const_display_fmt` methods
+/// to write into a `String`, a serial port, a logger, etc,
+/// without a buffer that's large enough for the entire text.
+///
+/// # How it works
+///
+/// Because `const fn`s can't call `core::fmt::Write` methods,
+/// [`write_with`] formats the text in windows of 4 KiB,
+/// calling the formatting function once per window
+/// and writing each window into the `core::fmt::Write` after it's formatted.
+///
+/// This means that the formatting function must write the same text every time it's called.
+///
+/// # Performance
+///
+/// Every call formats all of the text up to the end of its window,
+/// so formatting text that's N windows long formats about N * N / 2 windows of text,
+/// taking quadratic time in the length of the text.
+/// Text that's much longer than 4 KiB is faster to format into a [`StrWriter`]
+/// that's large enough for it.
+///
+/// # Limitations
+///
+/// Because the text before the window isn't stored:
+///
+/// - [`Formatter::pad_since`] can only write padding after the text,
+///   unless the position was constructed with [`Formatter::padding_start_for`],
+///   which writes the padding that goes before the text.
+///   Right or center aligning text without it returns an `Error::UnmeasuredPadding`.
+///
+/// - Integers and floats are formatted into a 1 KiB stack buffer before being written,
+///   integers longer than that (including the zeroes that the precision adds)
+///   can't be written.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::for_examples::Point3;
+/// use const_format::fmt::{FmtWriter, FormattingFlags};
+/// use const_format::writec;
+///
+/// let point = Point3{x: 3, y: 5, z: 8};
+///
+/// let mut string = String::new();
+/// let mut writer = FmtWriter::new(&mut string);
+///
+/// writer.write_with(FormattingFlags::NEW, |f| {
+///     writec!(f, "{:?} {:>6}|{:<6}|{:^6}|", point, "foo", 13u8, 'c')
+/// })?;
+/// assert_eq!(string, "Point3 { x: 3, y: 5, z: 8 }    foo|13    |  c   |");
+///
+/// string.clear();
+///
+/// let mut writer = FmtWriter::new(&mut string);
+/// let flags = FormattingFlags::NEW.set_alternate(true);
+/// writer.write_with(flags, |f| point.const_debug_fmt(f))?;
+/// assert_eq!(string, "Point3 {\n    x: 3,\n    y: 5,\n    z: 8,\n}");
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
+/// [`Formatter`]: ./struct.Formatter.html
+/// [`StrWriter`]: ./struct.StrWriter.html
+/// [`write_with`]: #method.write_with
+/// [`Formatter::pad_since`]: ./struct.Formatter.html#method.pad_since
+/// [`Formatter::padding_start_for`]: ./struct.Formatter.html#method.padding_start_for
+pub struct FmtWriter<'w> {
+    out: &'w mut (dyn cfmt::Write + 'w),
+    len: usize,
+}
+
+impl<'w> FmtWriter<'w> {
+    /// Constructs a `FmtWriter` that writes into `out`.
+    pub fn new(out: &'w mut (dyn cfmt::Write + 'w)) -> Self {
+        Self { out, len: 0 }
+    }
+
+    /// Formats text with `fmt`, writing it into the wrapped `core::fmt::Write`.
+    ///
+    /// `fmt` is called with a `Formatter` constructed with `flags`,
+    /// once for every 4 KiB of text that it writes.
+    ///
+    /// # Errors
+    ///
+    /// If `fmt` returns an error, it's returned before anything is written.
+    ///
+    /// If the wrapped `core::fmt::Write` returns an error, this returns `Error::FmtWrite`.
+    pub fn write_with<F>(&mut self, flags: FormattingFlags, mut fmt: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut Formatter<'_>) -> Result<(), Error>,
+    {
+        let mut window = [0u8; WINDOW_LEN];
+        let mut offset = 0;
+        loop {
+            let mut str_len = ComputeStrLength::new();
+            fmt(&mut Formatter::from_fmt_writer(
+                FmtWriterMut {
+                    window: &mut window,
+                    offset,
+                    str_len: &mut str_len,
+                },
+                flags,
+            ))?;
+
+            let remaining = str_len.len().saturating_sub(offset);
+            let is_last = remaining <= WINDOW_LEN;
+
+            // Leaving the last char of a full window for the next one,
+            // since the window might end in the middle of it.
+            let mut written = min_usize(remaining, WINDOW_LEN);
+            if !is_last {
+                let mut last_char = written - 1;
+                while last_char != 0 && (window[last_char] as i8) < -0x40 {
+                    last_char -= 1;
+                }
+                if last_char != 0 {
+                    written = last_char;
+                }
+            }
+
+            let text =
+                core::str::from_utf8(&window[..written]).map_err(|_| Error::NotOnCharBoundary)?;
+            self.out.write_str(text).map_err(|_| Error::FmtWrite)?;
+            self.len += written;
+
+            if is_last {
+                return Ok(());
+            }
+            offset += written;
+        }
+    }
+
+    /// How many bytes have been written into the wrapped `core::fmt::Write`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether nothing has been written into the wrapped `core::fmt::Write`.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+// How many bytes of the formatted text are written into the `core::fmt::Write` at a time.
+const WINDOW_LEN: usize = 4096;
+
+// The length of the buffer that numbers are formatted into,
+// which is enough for any float.
+pub(super) const NUMBER_BUFFER_LEN: usize = 1024;
+
+// How many bytes of a string are Debug formatted at a time.
+const DEBUG_PIECE_LEN: usize = 32;
+
+// The length of the buffer that a piece of a string is Debug formatted into,
+// every byte is escaped into at most 6 bytes (eg: `\u{1b}`), plus the quotes.
+const DEBUG_BUFFER_LEN: usize = DEBUG_PIECE_LEN * 6 + 3;
+
+/// Stores the part of the formatted text that goes in the window,
+/// and counts the length of all of it.
+pub(super) struct FmtWriterMut<'w> {
+    window: &'w mut [u8],
+    // The position of the window in the formatted text.
+    offset: usize,
+    pub(super) str_len: &'w mut ComputeStrLength,
+}
+
+impl<'w> FmtWriterMut<'w> {
+    pub(super) const fn reborrow(&mut self) -> FmtWriterMut<'_> {
+        FmtWriterMut {
+            window: &mut *self.window,
+            offset: self.offset,
+            str_len: &mut *self.str_len,
+        }
+    }
+
+    // Writes `bytes[start..end]`, which must be utf8 encoded text.
+    const fn write_out(&mut self, bytes: &[u8], Range { mut start, end }: Range<usize>) {
+        let mut pos = self.str_len.len();
+        self.str_len
+            .add_len_chars(end - start, count_chars(bytes, start..end));
+
+        let window_end = self.offset + self.window.len();
+        if pos < self.offset {
+            let skipped = min_usize(self.offset - pos, end - start);
+            start += skipped;
+            pos += skipped;
+        }
+        while start < end && pos < window_end {
+            self.window[pos - self.offset] = bytes[start];
+            start += 1;
+            pos += 1;
+        }
+    }
+
+    // Debug formats `bytes[start..end]` in pieces,
+    // byte strings (`is_bytes == true`) are split anywhere,
+    // and strings are split on char boundaries.
+    const fn write_out_debug(
+        &mut self,
+        bytes: &[u8],
+        Range { start, end }: Range<usize>,
+        is_bytes: bool,
+    ) -> Result<(), Error> {
+        let mut buffer = [0u8; DEBUG_BUFFER_LEN];
+        let prefix: &[u8] = if is_bytes { b"b\"" } else { b"\"" };
+
+        self.write_out(prefix, 0..prefix.len());
+        let mut piece_start = start;
+        while piece_start < end {
+            let mut piece_end = min_usize(piece_start + DEBUG_PIECE_LEN, end);
+            // Not splitting chars
+            while !is_bytes && piece_end < end && (bytes[piece_end] as i8) < -0x40 {
+                piece_end -= 1;
+            }
+
+            let mut len = 0;
+            let mut piece = StrWriterMut::from_custom(&mut buffer, &mut len);
+            try_!(write_piece_debug(
+                &mut piece,
+                bytes,
+                piece_start..piece_end,
+                is_bytes
+            ));
+
+            // Writing the piece without its quotes
+            let piece = piece.as_bytes_alt();
+            self.write_out(piece, prefix.len()..piece.len() - 1);
+            piece_start = piece_end;
+        }
+        self.write_out(b"\"", 0..1);
+        Ok(())
+    }
+
+    /// Writes `padding` fill characters.
+    pub(super) const fn write_fill(
+        &mut self,
+        fill: EncodedChar,
+        padding: usize,
+    ) -> Result<(), Error> {
+        let mut i = 0;
+        while i < padding {
+            self.write_out(&fill.encoded, 0..fill.len);
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// Writes a number that was formatted without padding, padding it with `flags`.
+    pub(super) const fn write_number(
+        &mut self,
+        number: &[u8],
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        let padding = flags.__integer_padding(number.len());
+        let pad_at = if flags.sign_aware_zero_pad() {
+            integer_pad_position(number, 0..number.len())
+        } else {
+            0
+        };
+
+        self.write_out(number, 0..pad_at);
+        try_!(self.write_fill(padding.fill, padding.before));
+        self.write_out(number, pad_at..number.len());
+        self.write_fill(padding.fill, padding.after)
+    }
+}
+
+impl<'w> FmtWriterMut<'w> {
+    pub(super) const fn write_str_range(
+        &mut self,
+        s: &str,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let bytes = s.as_bytes();
+        let range = saturate_range(bytes, &range);
+        if !is_valid_str_range(bytes, range.start..range.end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+        self.write_out(bytes, range);
+        Ok(())
+    }
+
+    pub(super) const fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        self.write_out(s.as_bytes(), 0..s.len());
+        Ok(())
+    }
+
+    pub(super) const fn write_ascii_range(
+        &mut self,
+        ascii: AsciiStr<'_>,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let bytes = ascii.as_bytes();
+        self.write_out(bytes, saturate_range(bytes, &range));
+        Ok(())
+    }
+
+    pub(super) const fn write_ascii(&mut self, ascii: AsciiStr<'_>) -> Result<(), Error> {
+        let bytes = ascii.as_bytes();
+        self.write_out(bytes, 0..bytes.len());
+        Ok(())
+    }
+
+    pub(super) const fn write_ascii_repeated(
+        &mut self,
+        character: u8,
+        repeated: usize,
+    ) -> Result<(), Error> {
+        // Truncating non-ascii u8s
+        let character = character & 0b111_1111;
+        self.write_fill(EncodedChar::new(character as char), repeated)
+    }
+
+    pub(super) const fn write_char(&mut self, character: char) -> Result<(), Error> {
+        let encoded = EncodedChar::new(character);
+        self.write_out(&encoded.encoded, 0..encoded.len);
+        Ok(())
+    }
+
+    pub(super) const fn write_str_range_debug(
+        &mut self,
+        s: &str,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let bytes = s.as_bytes();
+        let range = saturate_range(bytes, &range);
+        if !is_valid_str_range(bytes, range.start..range.end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+        self.write_out_debug(bytes, range, false)
+    }
+
+    pub(super) const fn write_str_debug(&mut self, s: &str) -> Result<(), Error> {
+        self.write_out_debug(s.as_bytes(), 0..s.len(), false)
+    }
+
+    pub(super) const fn write_ascii_range_debug(
+        &mut self,
+        ascii: AsciiStr<'_>,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let bytes = ascii.as_bytes();
+        self.write_out_debug(bytes, saturate_range(bytes, &range), false)
+    }
+
+    pub(super) const fn write_ascii_debug(&mut self, ascii: AsciiStr<'_>) -> Result<(), Error> {
+        let bytes = ascii.as_bytes();
+        self.write_out_debug(bytes, 0..bytes.len(), false)
+    }
+
+    pub(super) const fn write_char_debug(&mut self, character: char) -> Result<(), Error> {
+        let mut buffer = [0u8; 16];
+        let mut len = 0;
+        let mut writer = StrWriterMut::from_custom(&mut buffer, &mut len);
+        try_!(writer.write_char_debug(character));
+        let bytes = writer.as_bytes_alt();
+        self.write_out(bytes, 0..bytes.len());
+        Ok(())
+    }
+
+    pub(super) const fn write_bytes_debug(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.write_out_debug(bytes, 0..bytes.len(), true)
+    }
+}
+
+const fn write_piece_debug(
+    writer: &mut StrWriterMut<'_, NoEncoding>,
+    bytes: &[u8],
+    range: Range<usize>,
+    is_bytes: bool,
+) -> Result<(), Error> {
+    if is_bytes {
+        writer.write_bytes_debug(bytes.split_at(range.end).0.split_at(range.start).1)
+    } else {
+        // The callers only pass utf8 encoded text
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        writer.write_str_range_debug(s, range)
+    }
+}
@@ -1,5 +1,11 @@
 use crate::{
-    fmt::{Error, FormattingFlags, NoEncoding, StrWriter, StrWriterMut},
+    fmt::{
+        fmt_writer::{FmtWriterMut, NUMBER_BUFFER_LEN},
+        Alignment, Error, FormattingFlags, NoEncoding, StrWriter, StrWriterCheckpoint,
+        StrWriterMut,
+    },
+    formatting::{char_debug, count_chars, count_debug_chars, EncodedChar, Formatting},
+    pargument::PConvWrapper,
     utils::saturate_range,
     wrapper_types::{AsciiStr, PWrapper},
 };
@@ -51,12 +57,13 @@ use core::ops::Range;
 ///
 pub struct ComputeStrLength {
     len: usize,
+    chars: usize,
 }
 
 impl ComputeStrLength {
     /// Constructs a ComputeStrLength of length 0.
     pub const fn new() -> Self {
-        Self { len: 0 }
+        Self { len: 0, chars: 0 }
     }
 
     /// Constructs a `Formatter`,
@@ -64,14 +71,34 @@ impl ComputeStrLength {
     pub const fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
         Formatter {
             margin: 0,
+            depth: 0,
+            muted: false,
             flags,
             writer: WriterBackend::Length(self),
         }
     }
 
     /// Adds `len` to the calculated length.
+    ///
+    /// This assumes that the `len` bytes are ascii characters,
+    /// which is only relevant when computing the length of padded text.
     pub const fn add_len(&mut self, len: usize) {
         self.len += len;
+        self.chars += len;
+    }
+
+    /// Adds `len` bytes of text, containing `chars` characters.
+    pub(super) const fn add_len_chars(&mut self, len: usize, chars: usize) {
+        self.len += len;
+        self.chars += chars;
+    }
+
+    /// Undoes everything added after the first `len` bytes, which contain `chars` characters.
+    pub(super) const fn rollback(&mut self, len: usize, chars: usize) {
+        if len <= self.len {
+            self.len = len;
+            self.chars = chars;
+        }
     }
 
     /// The length of the string when formatted.
@@ -88,9 +115,48 @@ impl ComputeStrLength {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// A position in a [`Formatter`], used to pad the text written after it.
+///
+/// This is constructed with [`Formatter::padding_start`],
+/// and passed to [`Formatter::pad_since`].
+///
+/// [`Formatter`]: ./struct.Formatter.html
+/// [`Formatter::padding_start`]: ./struct.Formatter.html#method.padding_start
+/// [`Formatter::pad_since`]: ./struct.Formatter.html#method.pad_since
+#[derive(Debug, Copy, Clone)]
+pub struct PaddingStart {
+    len: usize,
+    chars: usize,
+    // Whether the padding before the text was already written,
+    // by `Formatter::padding_start_for`.
+    padded_before: bool,
+}
+
+/// The state of a [`Formatter`] at some point,
+/// used to undo everything written after it.
+///
+/// This is constructed with [`Formatter::checkpoint`],
+/// and passed to [`Formatter::rollback`].
+///
+/// [`Formatter`]: ./struct.Formatter.html
+/// [`Formatter::checkpoint`]: ./struct.Formatter.html#method.checkpoint
+/// [`Formatter::rollback`]: ./struct.Formatter.html#method.rollback
+#[derive(Debug, Copy, Clone)]
+pub struct Checkpoint {
+    pub(super) len: usize,
+    pub(super) chars: usize,
+    pub(super) truncated: bool,
+    pub(super) margin: u16,
+    pub(super) depth: u16,
+    pub(super) muted: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 enum WriterBackend<'w> {
     Str(StrWriterMut<'w, NoEncoding>),
     Length(&'w mut ComputeStrLength),
+    Fmt(FmtWriterMut<'w>),
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -122,6 +188,10 @@ enum WriterBackend<'w> {
 /// with the [`from_custom_cleared`] constructor,
 /// or the [`from_custom`] constructor.
 ///
+/// - From a [`FmtWriter`], which passes one to the closure of its
+///   [`write_with`](./struct.FmtWriter.html#method.write_with) method.
+///   This writes into a `core::fmt::Write` at runtime.
+///
 /// # Errors
 ///
 /// The `write_*` methods can only return an `Error::NotEnoughSpace`,
@@ -212,6 +282,7 @@ enum WriterBackend<'w> {
 /// [`StrWriter`]: ./struct.StrWriter.html
 /// [`StrWriterMut`]: ./struct.StrWriterMut.html
 /// [`ComputeStrLength`]: ./struct.ComputeStrLength.html
+/// [`FmtWriter`]: ./struct.FmtWriter.html
 /// [`from_sw`]: #method.from_sw
 /// [`from_sw_mut`]: #method.from_sw_mut
 /// [`from_custom_cleared`]: #method.from_custom_cleared
@@ -221,12 +292,15 @@ enum WriterBackend<'w> {
 ///
 pub struct Formatter<'w> {
     margin: u16,
+    // How many structs/collections this is nested inside of.
+    depth: u16,
+    // Whether writes are skipped,
+    // set while writing the fields/entries that a builder elided with `..`.
+    muted: bool,
     flags: FormattingFlags,
     writer: WriterBackend<'w>,
 }
 
-const MARGIN_STEP: u16 = 4;
-
 impl<'w> Formatter<'w> {
     /// Constructs a `Formatter`.
     ///
@@ -257,6 +331,8 @@ impl<'w> Formatter<'w> {
     pub const fn from_sw(writer: &'w mut StrWriter, flags: FormattingFlags) -> Self {
         Self {
             margin: 0,
+            depth: 0,
+            muted: false,
             flags,
             // safety:
             // Formatter only writes valid utf8, which is valid for both
@@ -300,6 +376,8 @@ impl<'w> Formatter<'w> {
     ) -> Self {
         Self {
             margin: 0,
+            depth: 0,
+            muted: false,
             flags,
             // safety:
             // Formatter only writes valid utf8, which is valid for both
@@ -359,6 +437,8 @@ impl<'w> Formatter<'w> {
     ) -> Self {
         Self {
             margin: 0,
+            depth: 0,
+            muted: false,
             flags,
             writer: WriterBackend::Str(StrWriterMut::from_custom(buffer, length)),
         }
@@ -380,6 +460,8 @@ impl<'w> Formatter<'w> {
         *length = 0;
         Self {
             margin: 0,
+            depth: 0,
+            muted: false,
             flags,
             writer: WriterBackend::Str(StrWriterMut::from_custom(buffer, length)),
         }
@@ -391,20 +473,308 @@ impl<'w> Formatter<'w> {
         self.flags
     }
 
-    /// Gets how much indentation a data structure is printed with.
+    /// Gets how much indentation a data structure is printed with,
+    /// in spaces (or tabs, if tab indentation is enabled in the flags).
     pub const fn margin(&self) -> usize {
         self.margin as usize
     }
 
     #[inline(always)]
     const fn increment_margin(&mut self) -> &mut Self {
-        self.margin += 4;
+        self.margin += self.flags.__indent_step();
+        self.depth += 1;
         self
     }
 
     #[inline(always)]
     const fn decrement_margin(&mut self) {
-        self.margin -= 4;
+        self.margin -= self.flags.__indent_step();
+        self.depth -= 1;
+    }
+
+    // How many fields/entries a struct/collection started at the current depth can write
+    // before the rest are elided with `..`.
+    const fn entry_limit(&self) -> usize {
+        let flags = self.flags;
+        match (flags.max_depth(), flags.max_entries()) {
+            (Some(max_depth), _) if self.depth as usize >= max_depth => 0,
+            (_, Some(max_entries)) => max_entries,
+            (_, None) => usize::MAX,
+        }
+    }
+
+    /// Gets the current position of this Formatter,
+    /// to pass it to [`pad_since`](#method.pad_since) after writing the text to pad.
+    #[inline]
+    pub const fn padding_start(&self) -> PaddingStart {
+        match &self.writer {
+            WriterBackend::Str(writer) => PaddingStart {
+                len: writer.len(),
+                chars: 0,
+                padded_before: false,
+            },
+            WriterBackend::Length(fmt_len) => PaddingStart {
+                len: fmt_len.len,
+                chars: fmt_len.chars,
+                padded_before: false,
+            },
+            WriterBackend::Fmt(writer) => PaddingStart {
+                len: writer.str_len.len,
+                chars: writer.str_len.chars,
+                padded_before: false,
+            },
+        }
+    }
+
+    /// Gets the current position of this Formatter,
+    /// to pass it to [`pad_since`](#method.pad_since) after writing
+    /// `chars` characters of text to pad.
+    ///
+    /// This is equivalent to [`padding_start`](#method.padding_start),
+    /// except for `Formatter`s constructed by a [`FmtWriter`],
+    /// which don't store the text before the part that they're writing,
+    /// so this method writes the padding that goes before the text
+    /// (eg: for right and center aligned text).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Error, Formatter, FormattingFlags};
+    /// use const_format::fmt::{Alignment, FmtWriter};
+    /// use const_format::try_;
+    ///
+    /// const fn write_pair(f: &mut Formatter<'_>, l: &str, r: &str) -> Result<(), Error> {
+    ///     // Only ascii strings are passed to this function, so chars == bytes
+    ///     let start = match f.padding_start_for(l.len() + r.len() + 1) {
+    ///         Ok(x) => x,
+    ///         Err(e) => return Err(e),
+    ///     };
+    ///     try_!(f.write_str(l));
+    ///     try_!(f.write_str("-"));
+    ///     try_!(f.write_str(r));
+    ///     f.pad_since(start)
+    /// }
+    ///
+    /// let mut string = String::new();
+    /// let mut writer = FmtWriter::new(&mut string);
+    ///
+    /// let flags = FormattingFlags::NEW.set_width(9).set_alignment(Alignment::Right);
+    /// writer.write_with(flags, |f| write_pair(f, "foo", "bar"))?;
+    ///
+    /// assert_eq!(string, "  foo-bar");
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`FmtWriter`]: ./struct.FmtWriter.html
+    pub const fn padding_start_for(&mut self, chars: usize) -> Result<PaddingStart, Error> {
+        let flags = self.flags;
+        match &mut self.writer {
+            WriterBackend::Fmt(writer) if !self.muted && flags.width() != 0 => {
+                let padding = flags.__padding(chars);
+                try_!(writer.write_fill(padding.fill, padding.before));
+                Ok(PaddingStart {
+                    len: writer.str_len.len,
+                    chars: writer.str_len.chars,
+                    padded_before: true,
+                })
+            }
+            _ => Ok(self.padding_start()),
+        }
+    }
+
+    /// Gets the current state of this Formatter,
+    /// to pass it to [`rollback`](#method.rollback) if writing fails.
+    #[inline]
+    pub const fn checkpoint(&self) -> Checkpoint {
+        let (len, chars, truncated) = match &self.writer {
+            WriterBackend::Str(writer) => (writer.len(), 0, writer.is_truncated()),
+            WriterBackend::Length(fmt_len) => (fmt_len.len, fmt_len.chars, false),
+            WriterBackend::Fmt(writer) => (writer.str_len.len, writer.str_len.chars, false),
+        };
+        Checkpoint {
+            len,
+            chars,
+            truncated,
+            margin: self.margin,
+            depth: self.depth,
+            muted: self.muted,
+        }
+    }
+
+    /// Undoes everything written since `checkpoint` was taken,
+    /// restoring the indentation and the state of the writer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    /// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
+    ///
+    /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
+    /// use const_format::{call_debug_fmt, writec};
+    ///
+    /// // Writes `list` if it fits, otherwise writes how many elements it has.
+    /// const fn list_or_count(list: &[u32], f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///     let checkpoint = f.checkpoint();
+    ///     match writec!(f, "{:?}", list) {
+    ///         Ok(()) => Ok(()),
+    ///         Err(_) => {
+    ///             f.rollback(checkpoint);
+    ///             writec!(f, "<{} elements>", list.len())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+    ///
+    /// list_or_count(&[3, 5, 8], &mut writer.make_formatter(FormattingFlags::NEW))?;
+    /// assert_eq!(writer.as_str(), "[3, 5, 8]");
+    ///
+    /// writer.clear();
+    /// list_or_count(&[3, 5, 8, 13, 21, 34], &mut writer.make_formatter(FormattingFlags::NEW))?;
+    /// assert_eq!(writer.as_str(), "<6 elements>");
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub const fn rollback(&mut self, checkpoint: Checkpoint) {
+        match &mut self.writer {
+            WriterBackend::Str(writer) => writer.rollback(StrWriterCheckpoint {
+                len: checkpoint.len,
+                truncated: checkpoint.truncated,
+            }),
+            WriterBackend::Length(fmt_len) => fmt_len.rollback(checkpoint.len, checkpoint.chars),
+            WriterBackend::Fmt(writer) => writer.str_len.rollback(checkpoint.len, checkpoint.chars),
+        }
+        self.margin = checkpoint.margin;
+        self.depth = checkpoint.depth;
+        self.muted = checkpoint.muted;
+    }
+
+    /// Pads the text written since `start`,
+    /// using the width, fill, and alignment from this Formatter's flags.
+    ///
+    /// The text is left aligned if the flags don't specify an alignment.
+    ///
+    /// This is how the formatting macros pad arguments with a width (eg: `"{:>10}"`),
+    /// and it can be used to pad text written in multiple steps.
+    ///
+    /// Formatters that write into a [`FmtWriter`](./struct.FmtWriter.html)
+    /// can only write the padding before the text if `start` was constructed with
+    /// [`padding_start_for`](#method.padding_start_for).
+    ///
+    /// # Errors
+    ///
+    /// For formatters that write into a [`FmtWriter`](./struct.FmtWriter.html),
+    /// this returns an `Error::UnmeasuredPadding` if the text is right or center aligned,
+    /// and `start` wasn't constructed with [`padding_start_for`](#method.padding_start_for).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
+    /// use const_format::{try_, writec};
+    /// use const_format::fmt::Alignment;
+    ///
+    /// const fn write_point(f: &mut Formatter<'_>, x: u32, y: u32) -> Result<(), Error> {
+    ///     let start = f.padding_start();
+    ///     // `writec` formats its arguments with the default flags,
+    ///     // so `x` and `y` aren't padded individually.
+    ///     try_!(writec!(f, "({}, {})", x, y));
+    ///     f.pad_since(start)
+    /// }
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    ///
+    /// let flags = FormattingFlags::NEW.set_width(12).set_alignment(Alignment::Center);
+    /// write_point(&mut writer.make_formatter(flags), 3, 5).unwrap();
+    ///
+    /// assert_eq!(writer.as_str(), "   (3, 5)   ");
+    ///
+    /// ```
+    pub const fn pad_since(&mut self, start: PaddingStart) -> Result<(), Error> {
+        if self.muted {
+            return Ok(());
+        }
+        if !start.padded_before && self.__pads_ahead() {
+            return Err(Error::UnmeasuredPadding);
+        }
+        let flags = self.flags;
+        match &mut self.writer {
+            WriterBackend::Str(writer) => writer.pad_since(start.len, flags),
+            WriterBackend::Length(fmt_len) => {
+                let padding = flags.__padding(fmt_len.chars - start.chars);
+                fmt_len.add_len_chars(padding.len(), padding.before + padding.after);
+                Ok(())
+            }
+            WriterBackend::Fmt(writer) => {
+                let padding = flags.__padding(writer.str_len.chars - start.chars);
+                let after = if start.padded_before {
+                    padding.after
+                } else {
+                    // Left aligned text, so all of the padding goes after it
+                    padding.before + padding.after
+                };
+                writer.write_fill(padding.fill, after)
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+impl<'w> Formatter<'w> {
+    /// Constructs a `Formatter` that computes the length of what this one would write
+    /// with `flags`, used to check whether collections fit in the compact width.
+    pub const fn __measuring_formatter<'a>(
+        &self,
+        str_len: &'a mut ComputeStrLength,
+        flags: FormattingFlags,
+    ) -> Formatter<'a> {
+        Formatter {
+            margin: self.margin,
+            depth: self.depth,
+            muted: false,
+            flags,
+            writer: WriterBackend::Length(str_len),
+        }
+    }
+
+    /// Whether the padding that goes before the text has to be written before formatting it,
+    /// because this writes into a `FmtWriter`.
+    pub const fn __pads_ahead(&self) -> bool {
+        let flags = self.flags;
+        matches!(self.writer, WriterBackend::Fmt(_))
+            && !self.muted
+            && flags.width() != 0
+            && matches!(
+                flags.alignment(),
+                Some(Alignment::Right) | Some(Alignment::Center)
+            )
+    }
+
+    /// Equivalent to `self.padding_start_for(measured.chars)`,
+    /// where `measured` is the length of the text to pad.
+    pub const fn __padding_start_measured(
+        &mut self,
+        measured: &ComputeStrLength,
+    ) -> Result<PaddingStart, Error> {
+        self.padding_start_for(measured.chars)
+    }
+}
+
+impl<'w> Formatter<'w> {
+    pub(super) const fn from_fmt_writer(writer: FmtWriterMut<'w>, flags: FormattingFlags) -> Self {
+        Self {
+            margin: 0,
+            depth: 0,
+            muted: false,
+            flags,
+            writer: WriterBackend::Fmt(writer),
+        }
     }
 }
 
@@ -428,6 +798,7 @@ impl<'w> Formatter<'w> {
     ///
     /// ```rust
     /// #![feature(const_mut_refs)]
+    /// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
     ///
     /// use const_format::{Error, Formatter, PWrapper};
     /// use const_format::{coerce_to_fmt, formatc, impl_fmt, try_};
@@ -479,10 +850,13 @@ impl<'w> Formatter<'w> {
     pub const fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
         Formatter {
             margin: self.margin,
+            depth: self.depth,
+            muted: self.muted,
             flags,
             writer: match &mut self.writer {
                 WriterBackend::Str(x) => WriterBackend::Str(x.reborrow()),
                 WriterBackend::Length(x) => WriterBackend::Length(x),
+                WriterBackend::Fmt(x) => WriterBackend::Fmt(x.reborrow()),
             },
         }
     }
@@ -500,8 +874,10 @@ impl<'w> Formatter<'w> {
     pub const fn debug_struct(&mut self, name: &str) -> DebugStruct<'_, 'w> {
         let err = self.write_str(name);
         DebugStruct {
+            remaining_entries: self.entry_limit(),
             fmt: self.increment_margin(),
             wrote_field: false,
+            elided: false,
             err,
         }
     }
@@ -518,8 +894,11 @@ impl<'w> Formatter<'w> {
     pub const fn debug_tuple(&mut self, name: &str) -> DebugTuple<'_, 'w> {
         let err = self.write_str(name);
         DebugTuple {
+            remaining_entries: self.entry_limit(),
             fmt: self.increment_margin(),
             wrote_field: false,
+            elided: false,
+            field_count: 0,
             err,
         }
     }
@@ -535,8 +914,10 @@ impl<'w> Formatter<'w> {
     #[inline]
     pub const fn debug_list(&mut self) -> DebugList<'_, 'w> {
         DebugList {
+            remaining_entries: self.entry_limit(),
             fmt: self.increment_margin(),
             wrote_field: false,
+            elided: false,
             err: Ok(()),
         }
     }
@@ -552,8 +933,48 @@ impl<'w> Formatter<'w> {
     #[inline]
     pub const fn debug_set(&mut self) -> DebugSet<'_, 'w> {
         DebugSet {
+            remaining_entries: self.entry_limit(),
+            fmt: self.increment_margin(),
+            wrote_field: false,
+            elided: false,
+            err: Ok(()),
+        }
+    }
+
+    /// For debug writing a map.
+    ///
+    /// # Examples
+    ///
+    /// For examples of using this method, you can look at the docs for [`DebugMap`]
+    ///
+    /// [`DebugMap`]: ./struct.DebugMap.html
+    ///
+    #[inline]
+    pub const fn debug_map(&mut self) -> DebugMap<'_, 'w> {
+        DebugMap {
+            remaining_entries: self.entry_limit(),
             fmt: self.increment_margin(),
             wrote_field: false,
+            elided: false,
+            err: Ok(()),
+        }
+    }
+
+    /// For writing entries separated by `separator`,
+    /// without any delimiters around them.
+    ///
+    /// # Examples
+    ///
+    /// For examples of using this method, you can look at the docs for [`Join`]
+    ///
+    /// [`Join`]: ./struct.Join.html
+    ///
+    #[inline]
+    pub const fn join<'s>(&mut self, separator: &'s str) -> Join<'_, 'w, 's> {
+        Join {
+            fmt: self,
+            separator,
+            wrote_entry: false,
             err: Ok(()),
         }
     }
@@ -572,12 +993,50 @@ macro_rules! trys {
 const COLON_SPACE_LEN: usize = ": ".len();
 const COMMA_SPACE_LEN: usize = ", ".len();
 const COMMA_NL_LEN: usize = ",\n".len();
+const ELLIPSIS_LEN: usize = "..".len();
+
+// The separator written before the closing token when pretty printing,
+// the `..` that elides fields/entries isn't followed by a comma.
+const fn pretty_close_sep(flags: FormattingFlags, elided: bool) -> &'static str {
+    if elided {
+        "\n"
+    } else {
+        flags.__pretty_close_sep()
+    }
+}
 
+// When the struct/collection already has as many fields/entries as the flags allow,
+// this writes `..` instead of the field/entry,
+// and mutes the formatter until the `finish` method is called,
+// so that the remaining fields/entries aren't written.
 macro_rules! field_method_impl {
     ($
         self: ident, $open_space:expr, $open_newline:expr;
         len(|$fmt_len:ident| $($write_name_len:tt)*)
         fmt(|$writer:ident| $($write_name_fmt:tt)*)
+    ) => ({
+        let elide = $self.remaining_entries == 0;
+        if !$self.fmt.muted {
+            field_method_impl!(@write $self, $open_space, $open_newline, elide;
+                len(|$fmt_len| $($write_name_len)*)
+                fmt(|$writer| $($write_name_fmt)*)
+            );
+            if elide {
+                $self.elided = true;
+                $self.fmt.muted = true;
+            }
+        }
+        if !elide {
+            $self.remaining_entries -= 1;
+        }
+        $self.wrote_field = true;
+
+        $self.fmt
+    });
+    (@write
+        $self: ident, $open_space:expr, $open_newline:expr, $elide:ident;
+        len(|$fmt_len:ident| $($write_name_len:tt)*)
+        fmt(|$writer:ident| $($write_name_fmt:tt)*)
     ) => ({
         match &mut $self.fmt.writer {
             WriterBackend::Length($fmt_len)=>{
@@ -586,35 +1045,62 @@ macro_rules! field_method_impl {
                 const OPEN_SPACE: usize = $open_space.len();
                 const OPEN_NEWLINE: usize = $open_newline.len();
 
-                let is_alternate = $self.fmt.flags.is_alternate();
-                $fmt_len.add_len(match ($self.wrote_field, is_alternate) {
+                let is_pretty = $self.fmt.flags.__is_pretty();
+                $fmt_len.add_len(match ($self.wrote_field, is_pretty) {
                     (false, false) => OPEN_SPACE,
                     (false, true) => OPEN_NEWLINE + $self.fmt.margin as usize,
                     (true , false) => COMMA_SPACE_LEN,
                     (true , true) => COMMA_NL_LEN + $self.fmt.margin as usize,
                 });
-                $($write_name_len)*
+                if $elide {
+                    $fmt_len.add_len(ELLIPSIS_LEN);
+                } else {
+                    $($write_name_len)*
+                }
             }
             WriterBackend::Str($writer)=>{
                 let $writer = &mut *$writer;
 
-                let is_alternate = $self.fmt.flags.is_alternate();
-                let sep = match ($self.wrote_field, is_alternate) {
+                let is_pretty = $self.fmt.flags.__is_pretty();
+                let sep = match ($self.wrote_field, is_pretty) {
+                    (false, false)=>$open_space,
+                    (false, true)=>$open_newline,
+                    (true, false)=>", ",
+                    (true, true)=>",\n",
+                };
+                trys!($writer.write_str(sep), $self);
+                if is_pretty {
+                    let indent = $self.fmt.flags.__indent_byte();
+                    trys!($writer.write_ascii_repeated(indent, $self.fmt.margin as usize), $self);
+                }
+                if $elide {
+                    trys!($writer.write_str(".."), $self);
+                } else {
+                    $($write_name_fmt)*
+                }
+            }
+            WriterBackend::Fmt($writer)=>{
+                let $writer = &mut *$writer;
+
+                let is_pretty = $self.fmt.flags.__is_pretty();
+                let sep = match ($self.wrote_field, is_pretty) {
                     (false, false)=>$open_space,
                     (false, true)=>$open_newline,
                     (true, false)=>", ",
                     (true, true)=>",\n",
                 };
                 trys!($writer.write_str(sep), $self);
-                if is_alternate {
-                    trys!($writer.write_ascii_repeated(b' ', $self.fmt.margin as usize), $self);
+                if is_pretty {
+                    let indent = $self.fmt.flags.__indent_byte();
+                    trys!($writer.write_ascii_repeated(indent, $self.fmt.margin as usize), $self);
+                }
+                if $elide {
+                    trys!($writer.write_str(".."), $self);
+                } else {
+                    $($write_name_fmt)*
                 }
-                $($write_name_fmt)*
             }
         }
-        $self.wrote_field = true;
-
-        $self.fmt
     })
 }
 
@@ -625,7 +1111,10 @@ macro_rules! finish_method_impl {
         }
 
         $self.fmt.decrement_margin();
-        if $self.wrote_field {
+        if $self.elided {
+            $self.fmt.muted = false;
+        }
+        if $self.wrote_field && !$self.fmt.muted {
             match &mut $self.fmt.writer {
                 WriterBackend::Length(fmt_len) => {
                     let fmt_len = &mut **fmt_len;
@@ -633,8 +1122,10 @@ macro_rules! finish_method_impl {
                     const CLOSE_TOKEN: usize = $close_token.len();
                     const SPACE_CLOSE: usize = $space_close.len();
 
-                    if $self.fmt.flags.is_alternate() {
-                        fmt_len.add_len(COMMA_NL_LEN + $self.fmt.margin as usize + CLOSE_TOKEN);
+                    let flags = $self.fmt.flags;
+                    if flags.__is_pretty() {
+                        let sep_len = pretty_close_sep(flags, $self.elided).len();
+                        fmt_len.add_len(sep_len + $self.fmt.margin as usize + CLOSE_TOKEN);
                     } else {
                         fmt_len.add_len(SPACE_CLOSE);
                     }
@@ -643,9 +1134,28 @@ macro_rules! finish_method_impl {
                 WriterBackend::Str(writer) => {
                     let writer = &mut *writer;
 
-                    if $self.fmt.flags.is_alternate() {
-                        try_!(writer.write_str(",\n"));
-                        try_!(writer.write_ascii_repeated(b' ', $self.fmt.margin as usize));
+                    let flags = $self.fmt.flags;
+                    if flags.__is_pretty() {
+                        try_!(writer.write_str(pretty_close_sep(flags, $self.elided)));
+                        try_!(writer.write_ascii_repeated(
+                            flags.__indent_byte(),
+                            $self.fmt.margin as usize
+                        ));
+                        writer.write_str($close_token)
+                    } else {
+                        writer.write_str($space_close)
+                    }
+                }
+                WriterBackend::Fmt(writer) => {
+                    let writer = &mut *writer;
+
+                    let flags = $self.fmt.flags;
+                    if flags.__is_pretty() {
+                        try_!(writer.write_str(pretty_close_sep(flags, $self.elided)));
+                        try_!(writer.write_ascii_repeated(
+                            flags.__indent_byte(),
+                            $self.fmt.margin as usize
+                        ));
                         writer.write_str($close_token)
                     } else {
                         writer.write_str($space_close)
@@ -669,6 +1179,7 @@ macro_rules! finish_method_impl {
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{Error, Formatter};
 /// use const_format::{call_debug_fmt, coerce_to_fmt, formatc, impl_fmt, try_};
@@ -731,6 +1242,8 @@ macro_rules! finish_method_impl {
 pub struct DebugStruct<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
     wrote_field: bool,
+    remaining_entries: usize,
+    elided: bool,
     err: Result<(), Error>,
 }
 
@@ -767,6 +1280,7 @@ impl<'f, 'w> DebugStruct<'f, 'w> {
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{Error, Formatter};
 /// use const_format::{call_debug_fmt, coerce_to_fmt, formatc, impl_fmt, try_};
@@ -822,12 +1336,16 @@ impl<'f, 'w> DebugStruct<'f, 'w> {
 pub struct DebugTuple<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
     wrote_field: bool,
+    remaining_entries: usize,
+    elided: bool,
+    field_count: usize,
     err: Result<(), Error>,
 }
 
 impl<'f, 'w> DebugTuple<'f, 'w> {
     /// Adds a field to the formatted output.
     pub const fn field(&mut self) -> &mut Formatter<'w> {
+        self.field_count += 1;
         field_method_impl!(self, "(", "(\n"; len(|fmt_len|) fmt(|writer|) )
     }
 
@@ -836,6 +1354,64 @@ impl<'f, 'w> DebugTuple<'f, 'w> {
     pub const fn finish(self) -> Result<(), Error> {
         finish_method_impl!(self, ")", ")")
     }
+
+    /// Finishes writing a tuple, the same way that `std` debug formats tuples,
+    /// and if anything went wrong in the `field` method,returns an error.
+    ///
+    /// This is meant to be used with an empty name (`f.debug_tuple("")`),
+    /// it writes `()` when no fields were written,
+    /// and a trailing comma for single field tuples, eg: `(3,)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
+    /// use const_format::{coerce_to_fmt, try_};
+    ///
+    /// const fn debug_pair(pair: &(u8, &str), f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///     let mut f = f.debug_tuple("");
+    ///     try_!(coerce_to_fmt!(&pair.0).const_debug_fmt(f.field()));
+    ///     try_!(coerce_to_fmt!(&pair.1).const_debug_fmt(f.field()));
+    ///     f.finish_tuple()
+    /// }
+    ///
+    /// const fn debug_single(single: &(u8,), f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///     let mut f = f.debug_tuple("");
+    ///     try_!(coerce_to_fmt!(&single.0).const_debug_fmt(f.field()));
+    ///     f.finish_tuple()
+    /// }
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    ///
+    /// debug_pair(&(3, "foo"), &mut writer.make_formatter(FormattingFlags::NEW))?;
+    /// assert_eq!(writer.as_str(), r#"(3, "foo")"#);
+    ///
+    /// writer.clear();
+    /// debug_single(&(5,), &mut writer.make_formatter(FormattingFlags::NEW))?;
+    /// assert_eq!(writer.as_str(), "(5,)");
+    ///
+    /// # Ok::<(), const_format::Error>(())
+    /// ```
+    pub const fn finish_tuple(self) -> Result<(), Error> {
+        if let result @ Err(_) = self.err {
+            return result;
+        }
+
+        if self.field_count == 0 {
+            let start = match self.fmt.padding_start_for(2) {
+                Ok(x) => x,
+                Err(e) => return Err(e),
+            };
+            try_!(self.fmt.write_str("()"));
+            try_!(self.fmt.pad_since(start));
+        } else if self.field_count == 1 && !self.elided && !self.fmt.flags.__is_pretty() {
+            try_!(self.fmt.write_str(","));
+        }
+
+        self.finish()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -846,16 +1422,24 @@ macro_rules! finish_listset_method_impl {
             return result;
         }
 
+        $self.fmt.decrement_margin();
+        if $self.elided {
+            $self.fmt.muted = false;
+        }
+        if $self.fmt.muted {
+            return Ok(());
+        }
+        let flags = $self.fmt.flags;
         match &mut $self.fmt.writer {
             WriterBackend::Length(fmt_len) => {
                 let fmt_len = &mut **fmt_len;
                 const CLOSE_TOKEN: usize = $close_token.len();
                 const OPEN_CLOSE: usize = $open_close.len();
 
-                $self.fmt.margin -= MARGIN_STEP;
                 if $self.wrote_field {
-                    if $self.fmt.flags.is_alternate() {
-                        fmt_len.add_len(COMMA_NL_LEN + $self.fmt.margin as usize);
+                    if flags.__is_pretty() {
+                        let sep_len = pretty_close_sep(flags, $self.elided).len();
+                        fmt_len.add_len(sep_len + $self.fmt.margin as usize);
                     }
                     fmt_len.add_len(CLOSE_TOKEN);
                 } else {
@@ -866,12 +1450,25 @@ macro_rules! finish_listset_method_impl {
             WriterBackend::Str(writer) => {
                 let writer = &mut *writer;
 
-                $self.fmt.margin -= MARGIN_STEP;
                 let margin = $self.fmt.margin as usize;
                 if $self.wrote_field {
-                    if $self.fmt.flags.is_alternate() {
-                        try_!(writer.write_str(",\n"));
-                        try_!(writer.write_ascii_repeated(b' ', margin));
+                    if flags.__is_pretty() {
+                        try_!(writer.write_str(pretty_close_sep(flags, $self.elided)));
+                        try_!(writer.write_ascii_repeated(flags.__indent_byte(), margin));
+                    }
+                    writer.write_str($close_token)
+                } else {
+                    writer.write_str($open_close)
+                }
+            }
+            WriterBackend::Fmt(writer) => {
+                let writer = &mut *writer;
+
+                let margin = $self.fmt.margin as usize;
+                if $self.wrote_field {
+                    if flags.__is_pretty() {
+                        try_!(writer.write_str(pretty_close_sep(flags, $self.elided)));
+                        try_!(writer.write_ascii_repeated(flags.__indent_byte(), margin));
                     }
                     writer.write_str($close_token)
                 } else {
@@ -892,6 +1489,7 @@ macro_rules! finish_listset_method_impl {
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{Error, Formatter};
 /// use const_format::{formatc, impl_fmt, try_};
@@ -925,6 +1523,8 @@ macro_rules! finish_listset_method_impl {
 pub struct DebugList<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
     wrote_field: bool,
+    remaining_entries: usize,
+    elided: bool,
     err: Result<(), Error>,
 }
 
@@ -951,6 +1551,7 @@ impl<'f, 'w> DebugList<'f, 'w> {
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{Error, Formatter};
 /// use const_format::{formatc, impl_fmt, try_};
@@ -984,6 +1585,8 @@ impl<'f, 'w> DebugList<'f, 'w> {
 pub struct DebugSet<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
     wrote_field: bool,
+    remaining_entries: usize,
+    elided: bool,
     err: Result<(), Error>,
 }
 
@@ -1002,47 +1605,253 @@ impl<'f, 'w> DebugSet<'f, 'w> {
 
 ////////////////////////////////////////////////////////////////////////////////
 
-macro_rules! delegate_write_methods {
-    (
-        shared_attrs $shared_attrs:tt
-        $(
-            $(#[$attrs:meta])*
-            fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
-            length = $len:expr;
-        )*
-    ) => (
-        impl Formatter<'_>{
-            $(
-                delegate_write_methods!{
-                    @inner
-                    shared_attrs $shared_attrs
-                    $(#[$attrs])*
-                    fn $method($($arg: $arg_ty ),* )
-                    length = $len;
-                }
-            )*
-        }
-    );
-    (
-        @inner
-        shared_attrs (
-            $( #[$shared_attrs:meta] )*
-        )
-        $(#[$attrs:meta])*
-        fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
-        length = $len:expr;
-    ) => (
-        $( #[$shared_attrs] )*
-        $(#[$attrs])*
-        pub const fn $method(&mut self, $($arg: $arg_ty ),*  ) -> Result<(), Error> {
-            match &mut self.writer {
-                WriterBackend::Length(fmt_len)=>{
-                    fmt_len.add_len($len);
-                    Ok(())
-                }
+/// For debug formatting a map.
+///
+/// # Example
+///
+/// This example demonstrates how you can debug format a lookup table as a map.
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
+///
+/// use const_format::{Error, Formatter};
+/// use const_format::{formatc, impl_fmt, try_};
+///
+/// fn main() {
+///     const MAP: &str = formatc!("{:?}", Table(&[("foo", 3), ("bar", 5)]));
+///     
+///     assert_eq!(MAP, r#"{"foo": 3, "bar": 5}"#);
+///
+///     const PRETTY: &str = formatc!("{:#?}", Table(&[("foo", 3), ("bar", 5)]));
+///
+///     assert_eq!(PRETTY, "{\n    \"foo\": 3,\n    \"bar\": 5,\n}");
+/// }
+///
+/// struct Table(&'static [(&'static str, u32)]);
+///
+/// impl_fmt!{
+///     impl Table;
+///     
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_map();
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             let (key, value) = self.0[i];
+///             if i % 2 == 0 {
+///                 try_!(f.entry(key).write_u32_debug(value));
+///             } else {
+///                 // Equivalent to the `entry` method call above
+///                 try_!(f.key().write_str_debug(key));
+///                 try_!(f.value().write_u32_debug(value));
+///             }
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// ```
+///
+pub struct DebugMap<'f, 'w> {
+    fmt: &'f mut Formatter<'w>,
+    wrote_field: bool,
+    remaining_entries: usize,
+    elided: bool,
+    err: Result<(), Error>,
+}
+
+impl<'f, 'w> DebugMap<'f, 'w> {
+    /// Adds the key of a map entry to the formatted output,
+    /// the value must be written with the `value` method afterwards.
+    pub const fn key(&mut self) -> &mut Formatter<'w> {
+        field_method_impl!(self, "{", "{\n"; len(|fmt_len|) fmt(|writer|) )
+    }
+
+    /// Adds the value of a map entry to the formatted output,
+    /// this must be called after the `key` method.
+    pub const fn value(&mut self) -> &mut Formatter<'w> {
+        if self.fmt.muted {
+            return self.fmt;
+        }
+        match &mut self.fmt.writer {
+            WriterBackend::Length(fmt_len) => fmt_len.add_len(COLON_SPACE_LEN),
+            WriterBackend::Str(writer) => trys!(writer.write_str(": "), self),
+            WriterBackend::Fmt(writer) => trys!(writer.write_str(": "), self),
+        }
+        self.fmt
+    }
+
+    /// Adds a map entry with a string key to the formatted output,
+    /// returning the `Formatter` that the value must be written into.
+    ///
+    /// This is equivalent to calling `write_str_debug(key)` on
+    /// the return value of the `key` method, and then calling the `value` method.
+    pub const fn entry(&mut self, key: &str) -> &mut Formatter<'w> {
+        let res = self.key().write_str_debug(key);
+        trys!(res, self);
+        self.value()
+    }
+
+    /// Finishes writing the map,
+    /// and if anything went wrong in the `key`/`value`/`entry` methods,returns an error.
+    pub const fn finish(self) -> Result<(), Error> {
+        finish_listset_method_impl!(self, "}", "{}")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// For writing entries separated by a separator, without any delimiters around them.
+///
+/// Unlike [`DebugList`], this can be used to write the entries with their
+/// `const_display_fmt` methods, eg: to write `foo, bar, baz` instead of
+/// `["foo", "bar", "baz"]`.
+///
+/// The entries are written with the flags of the [`Formatter`] that
+/// the [`join`](./struct.Formatter.html#method.join) method was called on.
+///
+/// # Example
+///
+/// This example demonstrates how you can display format a list of
+/// any type with a `const_display_fmt` method.
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{Error, Formatter};
+/// use const_format::{coerce_to_fmt, formatc, impl_fmt, try_};
+///
+/// fn main() {
+///     const FEATURES: &str = formatc!("features: {}", Features(&["fmt", "derive"]));
+///     assert_eq!(FEATURES, "features: fmt, derive");
+///
+///     const NUMBERS: &str = formatc!("{}", Numbers(&[3, 5, 8]));
+///     assert_eq!(NUMBERS, "3 | 5 | 8");
+///
+///     const NONE: &str = formatc!("[{}]", Numbers(&[]));
+///     assert_eq!(NONE, "[]");
+/// }
+///
+/// struct Features(&'static [&'static str]);
+///
+/// struct Numbers(&'static [u32]);
+///
+/// impl_fmt!{
+///     impl Features;
+///     
+///     const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.join(", ");
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             try_!(coerce_to_fmt!(self.0[i]).const_display_fmt(f.entry()));
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// impl_fmt!{
+///     impl Numbers;
+///     
+///     const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.join(" | ");
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             try_!(coerce_to_fmt!(self.0[i]).const_display_fmt(f.entry()));
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// ```
+///
+/// [`DebugList`]: ./struct.DebugList.html
+/// [`Formatter`]: ./struct.Formatter.html
+///
+pub struct Join<'f, 'w, 's> {
+    fmt: &'f mut Formatter<'w>,
+    separator: &'s str,
+    wrote_entry: bool,
+    err: Result<(), Error>,
+}
+
+impl<'f, 'w, 's> Join<'f, 'w, 's> {
+    /// Adds an entry to the formatted output,
+    /// writing the separator before it if it's not the first entry.
+    pub const fn entry(&mut self) -> &mut Formatter<'w> {
+        if self.wrote_entry {
+            let res = self.fmt.write_str(self.separator);
+            trys!(res, self);
+        }
+        self.wrote_entry = true;
+        self.fmt
+    }
+
+    /// Finishes writing the entries,
+    /// and if anything went wrong in the `entry` method,returns an error.
+    pub const fn finish(self) -> Result<(), Error> {
+        self.err
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! delegate_write_methods {
+    (
+        shared_attrs $shared_attrs:tt
+        $(
+            $(#[$attrs:meta])*
+            fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
+            length = $len:expr $(, chars = |$len_var:ident| $chars:expr)?;
+        )*
+    ) => (
+        impl Formatter<'_>{
+            $(
+                delegate_write_methods!{
+                    @inner
+                    shared_attrs $shared_attrs
+                    $(#[$attrs])*
+                    fn $method($($arg: $arg_ty ),* )
+                    length = $len $(, chars = |$len_var| $chars)?;
+                }
+            )*
+        }
+    );
+    (
+        @inner
+        shared_attrs (
+            $( #[$shared_attrs:meta] )*
+        )
+        $(#[$attrs:meta])*
+        fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
+        length = $len:expr $(, chars = |$len_var:ident| $chars:expr)?;
+    ) => (
+        $( #[$shared_attrs] )*
+        $(#[$attrs])*
+        pub const fn $method(&mut self, $($arg: $arg_ty ),*  ) -> Result<(), Error> {
+            if self.muted {
+                return Ok(());
+            }
+            match &mut self.writer {
+                WriterBackend::Length(fmt_len)=>{
+                    let len = $len;
+                    #[allow(unused_variables)]
+                    let chars = len;
+                    $(
+                        let $len_var = len;
+                        let chars = $chars;
+                    )?
+                    fmt_len.add_len_chars(len, chars);
+                    Ok(())
+                }
                 WriterBackend::Str(writer)=>{
                     writer.$method($($arg,)*)
                 }
+                WriterBackend::Fmt(writer)=>{
+                    writer.$method($($arg,)*)
+                }
             }
         }
     )
@@ -1071,7 +1880,8 @@ delegate_write_methods! {
     /// ```
     ///
     fn write_str_range(string: &str, range: Range<usize>)
-    length = calculate_display_len(string.as_bytes(), &range);
+    length = calculate_display_len(string.as_bytes(), &range),
+    chars = |_len| calculate_display_chars(string.as_bytes(), &range);
 
     /// Writes `string` into this Formatter.
     ///
@@ -1091,7 +1901,8 @@ delegate_write_methods! {
     /// ```
     ///
     fn write_str(string: &str)
-    length = string.len();
+    length = string.len(),
+    chars = |_len| count_chars(string.as_bytes(), 0..string.len());
 
     /// Writes `&ascii[range]` into this formatter.
     ///
@@ -1158,6 +1969,28 @@ delegate_write_methods! {
     fn write_ascii_repeated(character: u8,repeated: usize)
     length = repeated;
 
+    /// Writes `character` into this formatter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_char('A');
+    /// let _ = fmt.write_char('ñ');
+    ///
+    /// assert_eq!(writer.as_str(), "Añ");
+    ///
+    /// ```
+    ///
+    fn write_char(character: char)
+    length = EncodedChar::new(character).len,
+    chars = |_len| 1;
+
     /// Writes `string` into this formatter, with debug formatting.
     ///
     /// This is a workaround for being unable to do `&foo[start..end]` at compile time.
@@ -1178,7 +2011,8 @@ delegate_write_methods! {
     /// ```
     ///
     fn write_str_range_debug(string: &str, range: Range<usize>)
-    length = calculate_display_len_debug_range(string.as_bytes(), &range);
+    length = calculate_display_len_debug_range(string.as_bytes(), &range),
+    chars = |_len| calculate_debug_chars(string.as_bytes(), &range);
 
     /// Writes `string` into this formatter, with debug formatting.
     ///
@@ -1198,7 +2032,8 @@ delegate_write_methods! {
     /// ```
     ///
     fn write_str_debug(string: &str)
-    length = PWrapper(string.as_bytes()).compute_utf8_debug_len();
+    length = PWrapper(string.as_bytes()).compute_utf8_debug_len(),
+    chars = |_len| calculate_debug_chars(string.as_bytes(), &(0..string.len()));
 
     /// Writes `&ascii[range]` into this formatter, with debug formatting.
     ///
@@ -1242,77 +2077,61 @@ delegate_write_methods! {
     fn write_ascii_debug(ascii: AsciiStr<'_>)
     length = PWrapper(ascii.as_bytes()).compute_utf8_debug_len();
 
-
-    /// Write `n` with display formatting.
+    /// Writes `character` into this formatter, with debug formatting.
     ///
     /// # Example
     ///
     /// ```rust
     ///
-    /// use const_format::{Formatter, FormattingFlags, StrWriter, ascii_str};
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
     ///
     /// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
     /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
     ///
-    /// let _ = fmt.write_u8_display(13);
-    /// let _ = fmt.write_u8_display(21);
-    /// let _ = fmt.write_u8_display(34);
+    /// let _ = fmt.write_char_debug('A');
+    /// let _ = fmt.write_char_debug('\t');
+    /// let _ = fmt.write_char_debug('ñ');
     ///
-    /// assert_eq!(writer.as_str(), "132134");
+    /// assert_eq!(writer.as_str(), r#"'A''\t''ñ'"#);
     ///
     /// ```
     ///
-    fn write_u8_display(n: u8)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-}
-
-delegate_write_methods! {
-    shared_attrs(
-        /// Writes `n` with display formatting
-        ///
-        /// For an example,
-        /// you can look at the one for the [`write_u8_display`] method.
-        ///
-        /// [`write_u8_display`]: #method.write_u8_display
-    )
-
-    fn write_u16_display(n: u16)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_u32_display(n: u32)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_u64_display(n: u64)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_u128_display(n: u128)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_usize_display(n: usize)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_i8_display(n: i8)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_i16_display(n: i16)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_i32_display(n: i32)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
-
-    fn write_i64_display(n: i64)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
+    fn write_char_debug(character: char)
+    length = char_debug(character).len,
+    chars = |_len| {
+        let debug = char_debug(character);
+        count_chars(&debug.array, 0..debug.len)
+    };
 
-    fn write_i128_display(n: i128)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
+    /// Writes `bytes` into this formatter, as a debug formatted byte string literal.
+    ///
+    /// This escapes `\t`, `\r`, `\n`, `\\`, `'`, and `"` with a backslash,
+    /// and every byte that isn't printable ascii as `\xYY`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_bytes_debug(b"GET /\r\n\x00");
+    ///
+    /// assert_eq!(writer.as_str(), r#"b"GET /\r\n\x00""#);
+    ///
+    /// ```
+    ///
+    fn write_bytes_debug(bytes: &[u8])
+    length = PWrapper(bytes).compute_byte_str_debug_len();
 
-    fn write_isize_display(n: isize)
-    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
 }
 
-macro_rules! delegate_integer_debug_methods {
+macro_rules! delegate_integer_methods {
     (
         shared_attrs $shared_attrs:tt
+        $kind:ident;
         $(
             $(#[$attrs:meta])*
             fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
@@ -1321,9 +2140,10 @@ macro_rules! delegate_integer_debug_methods {
     ) => (
         impl Formatter<'_>{
             $(
-                delegate_integer_debug_methods!{
+                delegate_integer_methods!{
                     @inner
                     shared_attrs $shared_attrs
+                    $kind;
                     $(#[$attrs])*
                     fn $method($($arg: $arg_ty ),*)
                     length = |$flags| $len;
@@ -1336,6 +2156,7 @@ macro_rules! delegate_integer_debug_methods {
         shared_attrs (
             $( #[$shared_attrs:meta] )*
         )
+        $kind:ident;
         $(#[$attrs:meta])*
         fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
         length = |$flags:ident| $len:expr;
@@ -1343,23 +2164,128 @@ macro_rules! delegate_integer_debug_methods {
         $( #[$shared_attrs] )*
         $(#[$attrs])*
         pub const fn $method(&mut self, $($arg: $arg_ty ),*  ) -> Result<(), Error> {
+            if self.muted {
+                return Ok(());
+            }
             let $flags = self.flags;
 
             match &mut self.writer {
                 WriterBackend::Length(fmt_len)=>{
-                    fmt_len.add_len($len);
+                    let is_negative = PConvWrapper($($arg)*).to_integer().is_negative;
+                    let len = $flags.__integer_unpadded_len(
+                        delegate_integer_methods!(@formatting $kind),
+                        $len,
+                        is_negative,
+                    );
+                    let padding = $flags.__integer_padding(len);
+                    fmt_len.add_len_chars(
+                        len + padding.len(),
+                        len + padding.before + padding.after,
+                    );
                     Ok(())
                 }
                 WriterBackend::Str(writer)=>{
-                    writer.$method($($arg,)* $flags)
+                    delegate_integer_methods!(@write $kind, writer.$method($($arg),*), $flags)
+                }
+                WriterBackend::Fmt(writer)=>{
+                    let mut buffer = [0u8; NUMBER_BUFFER_LEN];
+                    let mut len = 0;
+                    let mut number = StrWriterMut::from_custom(&mut buffer, &mut len);
+                    let unpadded = $flags.set_width(0);
+                    try_!(delegate_integer_methods!(
+                        @write $kind, number.$method($($arg),*), unpadded
+                    ));
+                    writer.write_number(number.as_bytes_alt(), $flags)
                 }
             }
         }
+    );
+    (@formatting display) => (Formatting::Display);
+    (@formatting debug) => (Formatting::Debug);
+    (@write display, $writer:ident.$method:ident($($arg:ident),*), $flags:ident) => ({
+        let start = $writer.len();
+        try_!($writer.$method($($arg),*));
+        $writer.pad_integer_since(start, $flags)
+    });
+    (@write debug, $writer:ident.$method:ident($($arg:ident),*), $flags:ident) => (
+        $writer.$method($($arg,)* $flags)
+    );
+}
+
+delegate_integer_methods! {
+    shared_attrs()
+    display;
+    /// Write `n` with display formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter, ascii_str};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_u8_display(13);
+    /// let _ = fmt.write_u8_display(21);
+    /// let _ = fmt.write_u8_display(34);
+    ///
+    /// assert_eq!(writer.as_str(), "132134");
+    ///
+    /// ```
+    ///
+    fn write_u8_display(n: u8)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+}
+
+delegate_integer_methods! {
+    shared_attrs(
+        /// Writes `n` with display formatting
+        ///
+        /// For an example,
+        /// you can look at the one for the [`write_u8_display`] method.
+        ///
+        /// [`write_u8_display`]: #method.write_u8_display
     )
+    display;
+
+    fn write_u16_display(n: u16)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_u32_display(n: u32)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_u64_display(n: u64)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_u128_display(n: u128)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_usize_display(n: usize)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_i8_display(n: i8)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_i16_display(n: i16)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_i32_display(n: i32)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_i64_display(n: i64)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_i128_display(n: i128)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    fn write_isize_display(n: isize)
+    length = |flags| PWrapper(n).compute_display_len(flags);
 }
 
-delegate_integer_debug_methods! {
+delegate_integer_methods! {
     shared_attrs()
+    debug;
 
     /// Writes `n` with debug formatting.
     ///
@@ -1381,12 +2307,16 @@ delegate_integer_debug_methods! {
     ///
     /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
     ///
-    /// assert_eq!(debug_fmt(writer, reg_flag),                   "63"     );
-    /// assert_eq!(debug_fmt(writer, reg_flag.set_hexadecimal()), "3F"     );
-    /// assert_eq!(debug_fmt(writer, reg_flag.set_binary()),      "111111" );
-    /// assert_eq!(debug_fmt(writer, alt_flag),                   "63"     );
-    /// assert_eq!(debug_fmt(writer, alt_flag.set_hexadecimal()), "0x3F"   );
-    /// assert_eq!(debug_fmt(writer, alt_flag.set_binary()),      "0b111111");
+    /// assert_eq!(debug_fmt(writer, reg_flag),                          "63"      );
+    /// assert_eq!(debug_fmt(writer, reg_flag.set_hexadecimal()),        "3F"      );
+    /// assert_eq!(debug_fmt(writer, reg_flag.set_lower_hexadecimal()),  "3f"      );
+    /// assert_eq!(debug_fmt(writer, reg_flag.set_octal()),              "77"      );
+    /// assert_eq!(debug_fmt(writer, reg_flag.set_binary()),             "111111"  );
+    /// assert_eq!(debug_fmt(writer, alt_flag),                          "63"      );
+    /// assert_eq!(debug_fmt(writer, alt_flag.set_hexadecimal()),        "0x3F"    );
+    /// assert_eq!(debug_fmt(writer, alt_flag.set_lower_hexadecimal()),  "0x3f"    );
+    /// assert_eq!(debug_fmt(writer, alt_flag.set_octal()),              "0o77"    );
+    /// assert_eq!(debug_fmt(writer, alt_flag.set_binary()),             "0b111111");
     ///
     /// ```
     ///
@@ -1394,7 +2324,7 @@ delegate_integer_debug_methods! {
     length = |flags| PWrapper(n).compute_debug_len(flags);
 }
 
-delegate_integer_debug_methods! {
+delegate_integer_methods! {
     shared_attrs(
         /// Writes `n` with debug formatting.
         ///
@@ -1403,6 +2333,7 @@ delegate_integer_debug_methods! {
         ///
         /// [`write_u8_debug`]: #method.write_u8_debug
     )
+    debug;
 
     fn write_u16_debug(n: u16)
     length = |flags| PWrapper(n).compute_debug_len(flags);
@@ -1438,12 +2369,137 @@ delegate_integer_debug_methods! {
     length = |flags| PWrapper(n).compute_debug_len(flags);
 }
 
+#[cfg(feature = "float")]
+macro_rules! delegate_float_methods {
+    (
+        $(
+            $(#[$attrs:meta])*
+            fn $method:ident($arg:ident: $arg_ty:ty)
+            length = |$flags:ident| $len:expr;
+        )*
+    ) => (
+        impl Formatter<'_>{
+            $(
+                $(#[$attrs])*
+                pub const fn $method(&mut self, $arg: $arg_ty) -> Result<(), Error> {
+                    if self.muted {
+                        return Ok(());
+                    }
+                    let $flags = self.flags;
+
+                    match &mut self.writer {
+                        WriterBackend::Length(fmt_len)=>{
+                            let len = $len;
+                            let padding = $flags.__integer_padding(len);
+                            fmt_len.add_len_chars(
+                                len + padding.len(),
+                                len + padding.before + padding.after,
+                            );
+                            Ok(())
+                        }
+                        WriterBackend::Str(writer)=>{
+                            writer.$method($arg, $flags)
+                        }
+                        WriterBackend::Fmt(writer)=>{
+                            let mut buffer = [0u8; NUMBER_BUFFER_LEN];
+                            let mut len = 0;
+                            let mut number = StrWriterMut::from_custom(&mut buffer, &mut len);
+                            try_!(number.$method($arg, $flags.set_width(0)));
+                            writer.write_number(number.as_bytes_alt(), $flags)
+                        }
+                    }
+                }
+            )*
+        }
+    );
+}
+
+#[cfg(feature = "float")]
+delegate_float_methods! {
+    /// Writes `n` with display formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_f64_display(0.5);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_display(-3.0);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_display(f64::INFINITY);
+    ///
+    /// assert_eq!(writer.as_str(), "0.5 -3 inf");
+    ///
+    /// ```
+    ///
+    fn write_f64_display(n: f64)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    /// Writes `n` with debug formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_f64_debug(0.5);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_debug(-3.0);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_debug(1e-7);
+    ///
+    /// assert_eq!(writer.as_str(), "0.5 -3.0 1e-7");
+    ///
+    /// ```
+    ///
+    fn write_f64_debug(n: f64)
+    length = |flags| PWrapper(n).compute_debug_len(flags);
+
+    /// Writes `n` with display formatting
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_display`] method.
+    ///
+    /// [`write_f64_display`]: #method.write_f64_display
+    fn write_f32_display(n: f32)
+    length = |flags| PWrapper(n).compute_display_len(flags);
+
+    /// Writes `n` with debug formatting
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_debug`] method.
+    ///
+    /// [`write_f64_debug`]: #method.write_f64_debug
+    fn write_f32_debug(n: f32)
+    length = |flags| PWrapper(n).compute_debug_len(flags);
+}
+
 #[inline(always)]
 const fn calculate_display_len(b: &[u8], range: &Range<usize>) -> usize {
     let Range { start, end } = saturate_range(b, range);
     end - start
 }
 
+#[inline(always)]
+const fn calculate_display_chars(b: &[u8], range: &Range<usize>) -> usize {
+    count_chars(b, saturate_range(b, range))
+}
+
+/// Computes the amount of chars in the debug formatted `b[range]`.
+#[inline(always)]
+const fn calculate_debug_chars(b: &[u8], range: &Range<usize>) -> usize {
+    count_debug_chars(b, saturate_range(b, range))
+}
+
 #[inline(always)]
 const fn calculate_display_len_debug_range(b: &[u8], range: &Range<usize>) -> usize {
     let Range { start, end } = saturate_range(b, range);
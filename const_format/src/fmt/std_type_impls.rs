@@ -2,17 +2,37 @@
 
 use crate::{
     fmt::{Error, Formatter},
+    formatting::count_chars,
     marker_traits::IsStdKind,
     wrapper_types::PWrapper,
 };
 
+#[cfg(not(feature = "const_trait_impl"))]
+use crate::fmt::ComputeStrLength;
+
+// Debug formatting of slices, `Option`s, `Result`s, and tuples of any type,
+// requires the `const_trait_impl` nightly feature.
+#[cfg(feature = "const_trait_impl")]
+mod generic_impls;
+
 mod ranges;
 
 ////////////////////////////////////////////////////////////////////////////////
 
 impl PWrapper<&str> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_str(self.0)
+        let bytes = self.0.as_bytes();
+        let len = f.flags().__str_precision_len(bytes);
+        let start = if f.__pads_ahead() {
+            match f.padding_start_for(count_chars(bytes, 0..len)) {
+                Ok(x) => x,
+                Err(e) => return Err(e),
+            }
+        } else {
+            f.padding_start()
+        };
+        try_!(f.write_str_range(self.0, 0..len));
+        f.pad_since(start)
     }
 
     pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
@@ -20,9 +40,28 @@ impl PWrapper<&str> {
     }
 }
 
+impl PWrapper<char> {
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let chars = f.flags().__char_precision_len(1);
+        let start = match f.padding_start_for(chars) {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+        if chars != 0 {
+            try_!(f.write_char(self.0));
+        }
+        f.pad_since(start)
+    }
+
+    #[inline(always)]
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_char_debug(self.0)
+    }
+}
+
 impl PWrapper<bool> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_str(if self.0 { "true" } else { "false" })
+        PWrapper(if self.0 { "true" } else { "false" }).const_display_fmt(f)
     }
 
     #[inline(always)]
@@ -31,11 +70,49 @@ impl PWrapper<bool> {
     }
 }
 
+#[cfg(feature = "float")]
+impl PWrapper<f32> {
+    #[inline(always)]
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_f32_display(self.0)
+    }
+
+    #[inline(always)]
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_f32_debug(self.0)
+    }
+}
+
+#[cfg(feature = "float")]
+impl PWrapper<f64> {
+    #[inline(always)]
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_f64_display(self.0)
+    }
+
+    #[inline(always)]
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_f64_debug(self.0)
+    }
+}
+
+// Without the "const_trait_impl" feature,
+// only slices of std types can be debug formatted.
+#[cfg(not(feature = "const_trait_impl"))]
 macro_rules! slice_of_std_impl {($($elem:ty),* $(,)?) => (
     $(
-
         impl PWrapper<&[$elem]> {
             pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                let flags = f.flags();
+                if flags.__measures_compact() {
+                    let flags = flags.__single_line();
+                    let mut str_len = ComputeStrLength::new();
+                    try_!(self.const_debug_fmt(&mut f.__measuring_formatter(&mut str_len, flags)));
+                    if str_len.len() <= flags.compact_width() {
+                        return self.const_debug_fmt(&mut f.make_formatter(flags));
+                    }
+                }
+
                 let mut f = f.debug_list();
                 __for_range!{i in 0..self.0.len() =>
                     try_!(PWrapper(self.0[i]).const_debug_fmt(f.entry()));
@@ -46,6 +123,7 @@ macro_rules! slice_of_std_impl {($($elem:ty),* $(,)?) => (
     )*
 )}
 
+#[cfg(not(feature = "const_trait_impl"))]
 slice_of_std_impl! {
     &str,
     u8, i8,
@@ -54,6 +132,12 @@ slice_of_std_impl! {
     u64, i64,
     u128, i128,
     usize, isize,
+    bool, char,
+}
+
+#[cfg(all(feature = "float", not(feature = "const_trait_impl")))]
+slice_of_std_impl! {
+    f32, f64,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -67,47 +151,71 @@ use core::{
     ptr::NonNull,
 };
 
-impl_fmt! {
-    is_std_type;
+// Allows formatting `Option`s of std types by value with `PWrapper`,
+// the formatting macros format `Option`s by reference,
+// of any type with the "const_trait_impl" feature, otherwise of std types.
+macro_rules! option_impls {
+    ($( impl[$($impl_:tt)*] $payload:ty; )*) => (
+        $(
+            #[cfg(not(feature = "const_trait_impl"))]
+            impl<$($impl_)*> PWrapper<&Option<$payload>> {
+                #[inline(always)]
+                pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(*self.0).const_debug_fmt(f)
+                }
+            }
+
+            impl<$($impl_)*> PWrapper<Option<$payload>> {
+                pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    match self.0 {
+                        Some(x) => {
+                            let mut f = f.debug_tuple("Some");
+                            try_!(PWrapper(x).const_debug_fmt(f.field()));
+                            f.finish()
+                        },
+                        None => f.write_str("None"),
+                    }
+                }
+            }
+        )*
+    )
+}
 
-    impl[T,] Option<NonNull<T>>;
-    impl[] Option<NonZeroU8>;
-    impl[] Option<NonZeroI8>;
-    impl[] Option<NonZeroU16>;
-    impl[] Option<NonZeroI16>;
-    impl[] Option<NonZeroU32>;
-    impl[] Option<NonZeroI32>;
-    impl[] Option<NonZeroU64>;
-    impl[] Option<NonZeroI64>;
-    impl[] Option<NonZeroU128>;
-    impl[] Option<NonZeroI128>;
-    impl[] Option<NonZeroUsize>;
-    impl[] Option<NonZeroIsize>;
-    impl[] Option<u8>;
-    impl[] Option<i8>;
-    impl[] Option<u16>;
-    impl[] Option<i16>;
-    impl[] Option<u32>;
-    impl[] Option<i32>;
-    impl[] Option<u64>;
-    impl[] Option<i64>;
-    impl[] Option<u128>;
-    impl[] Option<i128>;
-    impl[] Option<usize>;
-    impl[] Option<isize>;
-    impl[] Option<bool>;
-    impl['a,] Option<&'a str>;
+option_impls! {
+    impl[T,] NonNull<T>;
+    impl[] NonZeroU8;
+    impl[] NonZeroI8;
+    impl[] NonZeroU16;
+    impl[] NonZeroI16;
+    impl[] NonZeroU32;
+    impl[] NonZeroI32;
+    impl[] NonZeroU64;
+    impl[] NonZeroI64;
+    impl[] NonZeroU128;
+    impl[] NonZeroI128;
+    impl[] NonZeroUsize;
+    impl[] NonZeroIsize;
+    impl[] u8;
+    impl[] i8;
+    impl[] u16;
+    impl[] i16;
+    impl[] u32;
+    impl[] i32;
+    impl[] u64;
+    impl[] i64;
+    impl[] u128;
+    impl[] i128;
+    impl[] usize;
+    impl[] isize;
+    impl[] bool;
+    impl[] char;
+    impl['a,] &'a str;
+}
 
-    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        match self.0 {
-            Some(x) => {
-                let mut f = f.debug_tuple("Some");
-                try_!(PWrapper(x).const_debug_fmt(f.field()));
-                f.finish()
-            },
-            None => f.write_str("None"),
-        }
-    }
+#[cfg(feature = "float")]
+option_impls! {
+    impl[] f32;
+    impl[] f64;
 }
 
 macro_rules! non_zero_impls {
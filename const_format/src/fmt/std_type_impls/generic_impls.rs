@@ -0,0 +1,118 @@
+use crate::{
+    fmt::{ComputeStrLength, Error, FmtDebug, Formatter},
+    marker_traits::{FormatMarker, IsAFormatMarker, IsStdKind},
+    wrapper_types::PWrapper,
+};
+
+impl<T> PWrapper<&[T]> {
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+    where
+        T: [const] FmtDebug,
+    {
+        let flags = f.flags();
+        if flags.__measures_compact() {
+            let flags = flags.__single_line();
+            let mut str_len = ComputeStrLength::new();
+            try_!(self.const_debug_fmt(&mut f.__measuring_formatter(&mut str_len, flags)));
+            if str_len.len() <= flags.compact_width() {
+                return self.const_debug_fmt(&mut f.make_formatter(flags));
+            }
+        }
+
+        let mut f = f.debug_list();
+        __for_range! {i in 0..self.0.len() =>
+            try_!(self.0[i].fmt_debug(f.entry()));
+        }
+        f.finish()
+    }
+}
+
+impl<T> PWrapper<&Option<T>> {
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+    where
+        T: [const] FmtDebug,
+    {
+        match self.0 {
+            Some(x) => {
+                let mut f = f.debug_tuple("Some");
+                try_!(x.fmt_debug(f.field()));
+                f.finish()
+            }
+            None => f.write_str("None"),
+        }
+    }
+}
+
+impl<T, E> PWrapper<&Result<T, E>> {
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+    where
+        T: [const] FmtDebug,
+        E: [const] FmtDebug,
+    {
+        match self.0 {
+            Ok(x) => {
+                let mut f = f.debug_tuple("Ok");
+                try_!(x.fmt_debug(f.field()));
+                f.finish()
+            }
+            Err(e) => {
+                let mut f = f.debug_tuple("Err");
+                try_!(e.fmt_debug(f.field()));
+                f.finish()
+            }
+        }
+    }
+}
+
+// Tuples are formatted by reference, each element with its `FmtDebug` impl.
+macro_rules! tuple_impls {
+    ($( ($($elem:ident = $index:tt),+) )*) => (
+        $(
+            impl<$($elem,)+> FormatMarker for ($($elem,)+) {
+                type Kind = IsStdKind;
+                type This = Self;
+            }
+
+            impl<$($elem,)+ R: ?Sized> IsAFormatMarker<IsStdKind, ($($elem,)+), R> {
+                /// Wraps `reference` in a `PWrapper`.
+                #[inline(always)]
+                pub const fn coerce(self, reference: &($($elem,)+)) -> PWrapper<&($($elem,)+)> {
+                    PWrapper(reference)
+                }
+            }
+
+            impl<$($elem,)+> PWrapper<&($($elem,)+)> {
+                pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+                where
+                    $($elem: [const] FmtDebug,)+
+                {
+                    let mut f = f.debug_tuple("");
+                    $( try_!(self.0.$index.fmt_debug(f.field())); )+
+                    f.finish_tuple()
+                }
+            }
+
+            impl<$($elem: [const] FmtDebug,)+> const FmtDebug for ($($elem,)+) {
+                #[inline]
+                fn fmt_debug(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(self).const_debug_fmt(f)
+                }
+            }
+        )*
+    )
+}
+
+tuple_impls! {
+    (A = 0)
+    (A = 0, B = 1)
+    (A = 0, B = 1, C = 2)
+    (A = 0, B = 1, C = 2, D = 3)
+    (A = 0, B = 1, C = 2, D = 3, E = 4)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5, G = 6)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5, G = 6, H = 7)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5, G = 6, H = 7, I = 8)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5, G = 6, H = 7, I = 8, J = 9)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5, G = 6, H = 7, I = 8, J = 9, K = 10)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5, G = 6, H = 7, I = 8, J = 9, K = 10, L = 11)
+}
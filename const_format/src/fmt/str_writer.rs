@@ -400,6 +400,7 @@ impl StrWriter {
         StrWriterMut {
             len: &mut self.len,
             buffer: &mut self.buffer,
+            truncation: None,
             _encoding: PhantomData,
         }
     }
@@ -447,6 +448,7 @@ impl StrWriter {
             StrWriterMut::<Utf8Encoding> {
                 len: &mut self.len,
                 buffer: &mut self.buffer,
+                truncation: None,
                 _encoding: PhantomData,
             },
             flags,
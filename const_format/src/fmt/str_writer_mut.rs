@@ -1,5 +1,15 @@
+#[cfg(feature = "float")]
 use crate::{
-    formatting::{hex_as_ascii, ForEscaping, FormattingFlags, NumberFormatting, FOR_ESCAPING},
+    float::{Float, FmtFloat},
+    formatting::Formatting,
+};
+
+use crate::{
+    formatting::{
+        byte_escape, char_debug, count_chars, debug_escape, decode_utf8, hex_as_ascii,
+        integer_pad_position, lower_hex_as_ascii, EncodedChar, FormattingFlags, NumberFormatting,
+        Padding,
+    },
     utils::{min_usize, saturate_range, Constructor},
     wrapper_types::{AsciiStr, PWrapper},
 };
@@ -34,6 +44,9 @@ use core::{marker::PhantomData, ops::Range};
 /// Every single `write_*` method returns an `Error::NotEnoughSpace` if
 /// there is not enough space to write the argument, leaving the string itself unmodified.
 ///
+/// A `StrWriterMut` made [`truncating`] instead writes as much as fits,
+/// followed by a truncation marker, without returning an error.
+///
 /// # Encoding type parameter
 ///
 /// The `E` type parameter represents the encoding of the buffer that this
@@ -68,6 +81,7 @@ use core::{marker::PhantomData, ops::Range};
 ///
 /// [`from_custom_cleared`]: #method.from_custom_cleared
 /// [`from_custom`]: #method.from_custom
+/// [`truncating`]: #method.truncating
 ///
 /// [`Utf8Encoding`]: ./enum.Utf8Encoding.html
 /// [`NoEncoding`]: ./enum.NoEncoding.html
@@ -75,6 +89,7 @@ use core::{marker::PhantomData, ops::Range};
 pub struct StrWriterMut<'w, E = Utf8Encoding> {
     pub(super) len: &'w mut usize,
     pub(super) buffer: &'w mut [u8],
+    pub(super) truncation: Option<&'w mut Truncation>,
     pub(super) _encoding: PhantomData<Constructor<E>>,
 }
 
@@ -85,6 +100,15 @@ macro_rules! borrow_fields {
     };
 }
 
+macro_rules! reborrow_truncation {
+    ($self:ident) => {
+        match &mut $self.truncation {
+            Some(truncation) => Some(&mut **truncation),
+            None => None,
+        }
+    };
+}
+
 /// Marker type indicating that the [`StrWriterMut`] is valid utf8,
 /// enabling the `as_str` method.
 ///
@@ -97,6 +121,80 @@ pub enum Utf8Encoding {}
 /// [`StrWriterMut`]: ./struct.StrWriterMut.html
 pub enum NoEncoding {}
 
+/// Makes a [`StrWriterMut`] truncate text that doesn't fit,
+/// instead of returning an `Error::NotEnoughSpace`.
+///
+/// A `StrWriterMut` uses this with the [`truncating`] method.
+///
+/// When a write doesn't fit, the writer writes as much of it as fits
+/// (on a character boundary, for the methods that write strings),
+/// leaving room for the `marker`, then writes the `marker`.
+/// Every later write does nothing, and returns `Ok(())`.
+///
+/// Integers and floats are either written whole, or not at all.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{StrWriter, writec};
+/// use const_format::fmt::Truncation;
+///
+/// let mut truncation = Truncation::new("...");
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+///
+/// writec!(writer.as_mut().truncating(&mut truncation), "{:?}", ["hello", "world"])?;
+///
+/// assert_eq!(writer.as_str(), r#"["hello", "wo..."#);
+/// assert!(truncation.is_truncated());
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
+/// [`StrWriterMut`]: ./struct.StrWriterMut.html
+/// [`truncating`]: ./struct.StrWriterMut.html#method.truncating
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Truncation {
+    marker: &'static str,
+    truncated: bool,
+}
+
+impl Truncation {
+    /// Constructs a `Truncation` that writes `marker` at the end of truncated text.
+    pub const fn new(marker: &'static str) -> Self {
+        Self {
+            marker,
+            truncated: false,
+        }
+    }
+
+    /// Gets the marker written at the end of truncated text.
+    pub const fn marker(&self) -> &'static str {
+        self.marker
+    }
+
+    /// Whether the writer truncated any text.
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+/// The state of a [`StrWriterMut`] at some point,
+/// used to undo everything written after it.
+///
+/// This is constructed with [`StrWriterMut::checkpoint`],
+/// and passed to [`StrWriterMut::rollback`].
+///
+/// [`StrWriterMut`]: ./struct.StrWriterMut.html
+/// [`StrWriterMut::checkpoint`]: ./struct.StrWriterMut.html#method.checkpoint
+/// [`StrWriterMut::rollback`]: ./struct.StrWriterMut.html#method.rollback
+#[derive(Debug, Copy, Clone)]
+pub struct StrWriterCheckpoint {
+    pub(super) len: usize,
+    pub(super) truncated: bool,
+}
+
 impl<'w> StrWriterMut<'w, Utf8Encoding> {
     /// Constructs a `StrWriterMut` from a mutable reference to a `StrWriter`
     ///
@@ -119,6 +217,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
         Self {
             len: &mut writer.len,
             buffer: &mut writer.buffer,
+            truncation: None,
             _encoding: PhantomData,
         }
     }
@@ -153,6 +252,7 @@ impl<'w> StrWriterMut<'w, NoEncoding> {
         Self {
             len: length,
             buffer,
+            truncation: None,
             _encoding: PhantomData,
         }
     }
@@ -185,6 +285,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
         Self {
             len: length,
             buffer,
+            truncation: None,
             _encoding: PhantomData,
         }
     }
@@ -353,6 +454,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
             }
 
             *self.len = length;
+            self.reset_truncation();
         }
         Ok(())
     }
@@ -390,11 +492,166 @@ impl<'w> StrWriterMut<'w, NoEncoding> {
     pub const fn truncate(&mut self, length: usize) {
         if length < *self.len {
             *self.len = length;
+            self.reset_truncation();
         }
     }
 }
 
 impl<'w, E> StrWriterMut<'w, E> {
+    /// Makes this `StrWriterMut` truncate text that doesn't fit,
+    /// writing the marker of `truncation` at the end,
+    /// instead of returning an `Error::NotEnoughSpace`.
+    ///
+    /// `truncation` records whether any text was truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::StrWriterMut;
+    /// use const_format::fmt::Truncation;
+    ///
+    /// let mut truncation = Truncation::new("…");
+    /// let mut len = 0;
+    /// let mut buffer = [0; 12];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len)
+    ///     .truncating(&mut truncation);
+    ///
+    /// writer.write_str("Hello, ")?;
+    /// writer.write_str("ñandú")?;
+    /// writer.write_str("!!!")?;
+    ///
+    /// assert_eq!(writer.as_str(), "Hello, ñ…");
+    /// assert!(writer.is_truncated());
+    ///
+    /// # Ok::<(), const_format::Error>(())
+    /// ```
+    pub const fn truncating(mut self, truncation: &'w mut Truncation) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+
+    /// Whether this `StrWriterMut` truncated any text,
+    /// always false if it wasn't made [`truncating`].
+    ///
+    /// [`truncating`]: #method.truncating
+    pub const fn is_truncated(&self) -> bool {
+        match &self.truncation {
+            Some(truncation) => truncation.truncated,
+            None => false,
+        }
+    }
+
+    /// Whether writing `additional` bytes must go through [`overflowed`].
+    ///
+    /// [`overflowed`]: #method.overflowed
+    #[inline(always)]
+    const fn overflows(&self, additional: usize) -> bool {
+        *self.len + additional > self.buffer.len() || self.is_truncated()
+    }
+
+    /// How many bytes of an overflowing write can be written before the truncation marker.
+    const fn room_before_marker(&self) -> usize {
+        match &self.truncation {
+            Some(truncation) if !truncation.truncated => self
+                .buffer
+                .len()
+                .saturating_sub(truncation.marker.len())
+                .saturating_sub(*self.len),
+            _ => 0,
+        }
+    }
+
+    /// Allows writing again after clearing or truncating a truncated writer.
+    const fn reset_truncation(&mut self) {
+        if let Some(truncation) = &mut self.truncation {
+            truncation.truncated = false;
+        }
+    }
+
+    /// Handles a write that doesn't fit.
+    ///
+    /// This returns an `Error::NotEnoughSpace` if this isn't truncating,
+    /// otherwise it writes the truncation marker (the first time it's called),
+    /// moving back to the last character boundary that leaves room for it.
+    const fn overflowed(&mut self) -> Result<(), Error> {
+        let truncation = match &mut self.truncation {
+            Some(truncation) => &mut **truncation,
+            None => return Err(Error::NotEnoughSpace),
+        };
+        if truncation.truncated {
+            return Ok(());
+        }
+        truncation.truncated = true;
+
+        let marker = truncation.marker.as_bytes();
+        let capacity = self.buffer.len();
+
+        let mut marker_len = marker.len();
+        if marker_len > capacity {
+            marker_len = capacity;
+            while !is_valid_str_index(marker, marker_len) {
+                marker_len -= 1;
+            }
+        }
+
+        let mut cut = min_usize(*self.len, capacity - marker_len);
+        while cut != *self.len && !is_valid_str_index(self.buffer, cut) {
+            cut -= 1;
+        }
+
+        let mut i = 0;
+        while i < marker_len {
+            self.buffer[cut + i] = marker[i];
+            i += 1;
+        }
+        *self.len = cut + marker_len;
+
+        Ok(())
+    }
+}
+
+impl<'w, E> StrWriterMut<'w, E> {
+    /// Gets the current state of this `StrWriterMut`,
+    /// to pass it to [`rollback`](#method.rollback) if writing fails.
+    #[inline]
+    pub const fn checkpoint(&self) -> StrWriterCheckpoint {
+        StrWriterCheckpoint {
+            len: *self.len,
+            truncated: self.is_truncated(),
+        }
+    }
+
+    /// Undoes everything written since `checkpoint` was taken,
+    /// leaving the `StrWriterMut` as it was then.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Error, StrWriter, StrWriterMut};
+    ///
+    /// let mut buffer = StrWriter::new([0; 12]);
+    /// let mut writer = StrWriterMut::new(&mut buffer);
+    ///
+    /// writer.write_str("foo")?;
+    ///
+    /// let checkpoint = writer.checkpoint();
+    /// writer.write_str(", bar")?;
+    /// assert_eq!(writer.write_str(", baz"), Err(Error::NotEnoughSpace));
+    /// writer.rollback(checkpoint);
+    ///
+    /// assert_eq!(writer.as_str(), "foo");
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub const fn rollback(&mut self, checkpoint: StrWriterCheckpoint) {
+        if checkpoint.len <= *self.len {
+            *self.len = checkpoint.len;
+        }
+        if let Some(truncation) = &mut self.truncation {
+            truncation.truncated = checkpoint.truncated;
+        }
+    }
+
     /// Truncates this `StrWriterMut` to length 0.
     ///
     /// # Example
@@ -421,6 +678,7 @@ impl<'w, E> StrWriterMut<'w, E> {
     #[inline]
     pub const fn clear(&mut self) {
         *self.len = 0;
+        self.reset_truncation();
     }
 
     /// Gets the written part of this `StrWriterMut` as a `&[u8]`
@@ -570,6 +828,7 @@ impl<'w, E> StrWriterMut<'w, E> {
             StrWriterMut::<NoEncoding> {
                 len: self.len,
                 buffer: self.buffer,
+                truncation: reborrow_truncation!(self),
                 _encoding: PhantomData,
             },
             flags,
@@ -589,6 +848,7 @@ impl<'w, E> StrWriterMut<'w, E> {
     ///
     /// ```rust
     /// #![feature(const_mut_refs)]
+    /// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
     ///
     /// use const_format::{Error, FormattingFlags, StrWriter, StrWriterMut, call_debug_fmt};
     ///
@@ -621,6 +881,7 @@ impl<'w, E> StrWriterMut<'w, E> {
         StrWriterMut {
             len: self.len,
             buffer: self.buffer,
+            truncation: reborrow_truncation!(self),
             _encoding: PhantomData,
         }
     }
@@ -630,6 +891,7 @@ impl<'w, E> StrWriterMut<'w, E> {
         StrWriterMut {
             len: self.len,
             buffer: self.buffer,
+            truncation: self.truncation,
             _encoding: PhantomData,
         }
     }
@@ -695,17 +957,17 @@ macro_rules! write_integer_fn {
     )=>{
         $(#[$display_attrs])*
         pub const fn $display_fn(&mut self, number: $ty) -> Result<(), Error> {
-            borrow_fields!(self, this_len, this_buffer);
-
             let n = PWrapper(number);
             let len = n.compute_display_len(FormattingFlags::DEFAULT);
 
-            let mut cursor = *this_len + len;
-
-            if cursor > this_buffer.len() {
-                return Err(Error::NotEnoughSpace);
+            if self.overflows(len) {
+                return self.overflowed();
             }
 
+            borrow_fields!(self, this_len, this_buffer);
+
+            let mut cursor = *this_len + len;
+
             write_integer_fn!(@unsigned_abs $sign, n);
 
             loop {
@@ -733,17 +995,18 @@ macro_rules! write_integer_fn {
                 n: $ty,
                 f: FormattingFlags,
             ) -> Result<(), Error> {
-                borrow_fields!(this, this_len, this_buffer);
-
                 let is_alternate = f.is_alternate();
                 let len = PWrapper(n).hexadecimal_len(f);
+                let is_upper = !matches!(f.num_fmt(), NumberFormatting::LowerHexadecimal);
 
-                let mut cursor = *this_len + len;
-
-                if cursor > this_buffer.len() {
-                    return Err(Error::NotEnoughSpace);
+                if this.overflows(len) {
+                    return this.overflowed();
                 }
 
+                borrow_fields!(this, this_len, this_buffer);
+
+                let mut cursor = *this_len + len;
+
                 if is_alternate {
                     this_buffer[*this_len] = b'0';
                     this_buffer[*this_len + 1] = b'x';
@@ -754,7 +1017,11 @@ macro_rules! write_integer_fn {
                 loop {
                     cursor-=1;
                     let digit = (n & 0b1111) as u8;
-                    this_buffer[cursor] = hex_as_ascii(digit);
+                    this_buffer[cursor] = if is_upper {
+                        hex_as_ascii(digit)
+                    } else {
+                        lower_hex_as_ascii(digit)
+                    };
                     n = n >> 4;
                     if n == 0 { break }
                 }
@@ -763,22 +1030,57 @@ macro_rules! write_integer_fn {
                 Ok(())
             }
 
-            const fn binary<E>(
+            const fn octal<E>(
                 this: &mut StrWriterMut<'_, E>,
                 n: $ty,
                 f: FormattingFlags,
             ) -> Result<(), Error> {
+                let is_alternate = f.is_alternate();
+                let len = PWrapper(n).octal_len(f);
+
+                if this.overflows(len) {
+                    return this.overflowed();
+                }
+
                 borrow_fields!(this, this_len, this_buffer);
 
+                let mut cursor = *this_len + len;
+
+                if is_alternate {
+                    this_buffer[*this_len] = b'0';
+                    this_buffer[*this_len + 1] = b'o';
+                }
+
+                write_integer_fn!(@as_unsigned $sign, n, $Unsigned);
+
+                loop {
+                    cursor-=1;
+                    let digit = (n & 0o7) as u8;
+                    this_buffer[cursor] = b'0' + digit;
+                    n = n >> 3;
+                    if n == 0 { break }
+                }
+
+                *this_len+=len;
+                Ok(())
+            }
+
+            const fn binary<E>(
+                this: &mut StrWriterMut<'_, E>,
+                n: $ty,
+                f: FormattingFlags,
+            ) -> Result<(), Error> {
                 let is_alternate = f.is_alternate();
                 let len = PWrapper(n).binary_len(f);
 
-                let mut cursor = *this_len + len;
-
-                if cursor > this_buffer.len() {
-                    return Err(Error::NotEnoughSpace);
+                if this.overflows(len) {
+                    return this.overflowed();
                 }
 
+                borrow_fields!(this, this_len, this_buffer);
+
+                let mut cursor = *this_len + len;
+
                 if is_alternate {
                     this_buffer[*this_len] = b'0';
                     this_buffer[*this_len + 1] = b'b';
@@ -798,11 +1100,17 @@ macro_rules! write_integer_fn {
                 Ok(())
             }
 
-            match flags.num_fmt() {
+            let start = *self.len;
+
+            try_!(match flags.num_fmt() {
                 NumberFormatting::Decimal=>self.$display_fn(number),
-                NumberFormatting::Hexadecimal=>hex(self, number, flags),
+                NumberFormatting::Hexadecimal
+                | NumberFormatting::LowerHexadecimal=>hex(self, number, flags),
+                NumberFormatting::Octal=>octal(self, number, flags),
                 NumberFormatting::Binary=>binary(self, number, flags),
-            }
+            });
+
+            self.pad_integer_since(start, flags)
         }
     };
     (@unsigned_abs signed, $n:ident) => (
@@ -828,7 +1136,7 @@ macro_rules! write_integer_fn {
 /// Checks that a range is valid for indexing a string,
 /// assuming that the range is in-bounds, and start <= end.
 #[inline]
-const fn is_valid_str_range(s: &[u8], Range { start, end }: Range<usize>) -> bool {
+pub(super) const fn is_valid_str_range(s: &[u8], Range { start, end }: Range<usize>) -> bool {
     let len = s.len();
 
     (end == len || ((s[end] as i8) >= -0x40)) && (start == len || ((s[start] as i8) >= -0x40))
@@ -986,47 +1294,272 @@ impl<'w, E> StrWriterMut<'w, E> {
         mut character: u8,
         repeated: usize,
     ) -> Result<(), Error> {
-        borrow_fields!(self, self_len, self_buffer);
-
         // Truncating non-ascii u8s
         character = character & 0b111_1111;
 
-        let end = *self_len + repeated;
-
-        if end > self_buffer.len() {
-            return Err(Error::NotEnoughSpace);
+        if self.overflows(repeated) {
+            let fitting = min_usize(self.room_before_marker(), repeated);
+            self.push_repeated(character, fitting);
+            return self.overflowed();
         }
 
-        while *self_len < end {
-            self_buffer[*self_len] = character;
-            *self_len += 1;
-        }
+        self.push_repeated(character, repeated);
 
         Ok(())
     }
 
+    /// Writes `character` with Display formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{FormattingFlags, StrWriterMut};
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_char('A');
+    /// let _ = writer.write_char('ñ');
+    /// let _ = writer.write_char('🧡');
+    ///
+    /// assert_eq!(writer.as_str(), "Añ🧡");
+    ///
+    /// ```
+    ///
+    pub const fn write_char(&mut self, character: char) -> Result<(), Error> {
+        let encoded = EncodedChar::new(character);
+        self.write_str_inner(&encoded.encoded, 0, encoded.len)
+    }
+
     #[inline(always)]
     const fn write_str_inner(
         &mut self,
         bytes: &[u8],
-        mut start: usize,
+        start: usize,
         end: usize,
     ) -> Result<(), Error> {
-        borrow_fields!(self, self_len, self_buffer);
-
         let len = end - start;
 
-        if *self_len + len > self_buffer.len() {
-            return Err(Error::NotEnoughSpace);
+        if self.overflows(len) {
+            let mut fitting_end = start + min_usize(self.room_before_marker(), len);
+            while fitting_end != end && !is_valid_str_index(bytes, fitting_end) {
+                fitting_end -= 1;
+            }
+            self.push_bytes(bytes, start, fitting_end);
+            return self.overflowed();
         }
 
+        self.push_bytes(bytes, start, end);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    const fn push_bytes(&mut self, bytes: &[u8], mut start: usize, end: usize) {
+        borrow_fields!(self, self_len, self_buffer);
+
         while start < end {
             self_buffer[*self_len] = bytes[start];
             *self_len += 1;
             start += 1;
         }
+    }
 
-        Ok(())
+    /// Gets the length up to which a write of `len` bytes can be done piece by piece,
+    /// before calling [`overflowed`](#method.overflowed) for the piece that doesn't fit.
+    const fn write_limit(&self, len: usize) -> usize {
+        if self.overflows(len) {
+            *self.len + self.room_before_marker()
+        } else {
+            self.buffer.len()
+        }
+    }
+
+    #[inline(always)]
+    const fn push_repeated(&mut self, character: u8, repeated: usize) {
+        borrow_fields!(self, self_len, self_buffer);
+
+        let end = *self_len + repeated;
+
+        while *self_len < end {
+            self_buffer[*self_len] = character;
+            *self_len += 1;
+        }
+    }
+}
+
+/// Padding
+impl<'w, E> StrWriterMut<'w, E> {
+    /// Pads the text written since `start` (a previous value of `self.len()`),
+    /// using the width, fill, and alignment of `flags`.
+    ///
+    /// The text is left aligned if `flags` doesn't specify an alignment.
+    ///
+    /// If the padded text doesn't fit in a [`truncating`] writer,
+    /// this writes as much of it as fits, followed by the truncation marker.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{FormattingFlags, StrWriterMut};
+    /// use const_format::fmt::Alignment;
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let flags = FormattingFlags::NEW.set_width(9).set_fill('-');
+    ///
+    /// let start = writer.len();
+    /// let _ = writer.write_str("foo");
+    /// let _ = writer.pad_since(start, flags.set_alignment(Alignment::Center));
+    ///
+    /// let start = writer.len();
+    /// let _ = writer.write_str("bar");
+    /// let _ = writer.pad_since(start, flags);
+    ///
+    /// assert_eq!(writer.as_str(), "---foo---bar------");
+    ///
+    /// ```
+    ///
+    /// [`truncating`]: #method.truncating
+    pub const fn pad_since(&mut self, start: usize, flags: FormattingFlags) -> Result<(), Error> {
+        if flags.width() == 0 {
+            return Ok(());
+        }
+        let start = min_usize(start, *self.len);
+        let chars = count_chars(self.buffer, start..*self.len);
+        self.write_padding(start, start, flags.__padding(chars), usize::MAX)
+    }
+
+    /// Pads the integer written since `start`,
+    /// treating a leading sign and a `0x`/`0o`/`0b` prefix as not being part of the digits
+    /// when padding with zeroes.
+    ///
+    /// This also adds the `+` sign and the leading zeroes required by
+    /// the precision of `flags`.
+    pub(crate) const fn pad_integer_since(
+        &mut self,
+        start: usize,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        if self.is_truncated() {
+            return Ok(());
+        }
+        if flags.sign_plus() {
+            let has_minus = start < *self.len && self.buffer[start] == b'-';
+            try_!(self.write_padding(start, start, flags.__plus_sign_padding(has_minus), start));
+        }
+
+        let digits_at = integer_pad_position(self.buffer, start..*self.len);
+
+        if flags.precision().is_some() {
+            let zeroes = flags.__precision_padding(*self.len - digits_at);
+            try_!(self.write_padding(start, digits_at, zeroes, start));
+        }
+
+        self.pad_number_since(start, flags)
+    }
+
+    /// Pads the number written since `start`,
+    /// treating a leading sign and a `0x`/`0o`/`0b` prefix as not being part of the digits
+    /// when padding with zeroes.
+    const fn pad_number_since(
+        &mut self,
+        start: usize,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        if self.is_truncated() {
+            return Ok(());
+        }
+        let padding = flags.__integer_padding(*self.len - start);
+        // The zeroes that pad numbers are written whole, like the numbers themselves
+        let (pad_at, whole_until) = if flags.sign_aware_zero_pad() {
+            (integer_pad_position(self.buffer, start..*self.len), start)
+        } else {
+            (start, start + padding.before * padding.fill.len)
+        };
+
+        self.write_padding(start, pad_at, padding, whole_until)
+    }
+
+    /// Writes `padding` around the text from `pad_at` to the end,
+    /// moving that text forward.
+    ///
+    /// If there isn't enough space, this truncates the writer to `start`
+    /// and returns an error, unless the writer is [`truncating`].
+    ///
+    /// Truncating writers write as much of the padded text as fits before the marker,
+    /// cutting it at or before `whole_until` if the text before the padding after it
+    /// doesn't fit (used to write numbers whole or not at all).
+    ///
+    /// [`truncating`]: #method.truncating
+    const fn write_padding(
+        &mut self,
+        start: usize,
+        pad_at: usize,
+        padding: Padding,
+        whole_until: usize,
+    ) -> Result<(), Error> {
+        if padding.is_empty() {
+            return Ok(());
+        }
+        let padding_len = padding.len();
+        let overflowed = self.overflows(padding_len);
+        if overflowed {
+            if self.truncation.is_none() {
+                *self.len = start;
+                return self.overflowed();
+            } else if self.is_truncated() {
+                return Ok(());
+            }
+        }
+
+        let fill = padding.fill;
+        let before = padding.before * fill.len;
+        let full_end = *self.len + padding_len;
+        let end = min_usize(full_end, self.buffer.len());
+
+        borrow_fields!(self, self_len, self_buffer);
+
+        let mut from = *self_len;
+        while from > pad_at {
+            from -= 1;
+            if from + before < end {
+                self_buffer[from + before] = self_buffer[from];
+            }
+        }
+
+        let mut written = 0;
+        while written < before && pad_at + written < end {
+            self_buffer[pad_at + written] = fill.encoded[written % fill.len];
+            written += 1;
+        }
+
+        let after_start = *self_len + before;
+        let mut written_after = 0;
+        while after_start + written_after < end {
+            self_buffer[after_start + written_after] = fill.encoded[written_after % fill.len];
+            written_after += 1;
+        }
+
+        if !overflowed {
+            *self_len = end;
+            return Ok(());
+        }
+
+        let mut cut = *self_len + self.room_before_marker();
+        if after_start > cut {
+            cut = min_usize(cut, whole_until);
+        }
+        while !is_valid_str_index(self.buffer, cut) {
+            cut -= 1;
+        }
+        *self.len = cut;
+        self.overflowed()
     }
 }
 
@@ -1154,6 +1687,103 @@ impl<'w, E> StrWriterMut<'w, E> {
         self.write_str_debug_inner(bytes, 0, bytes.len())
     }
 
+    /// Writes `character` with Debug-like formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{FormattingFlags, StrWriterMut};
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_char_debug('A');
+    /// let _ = writer.write_char_debug('\n');
+    /// let _ = writer.write_char_debug('"');
+    ///
+    /// assert_eq!(writer.as_str(), r#"'A''\n''"'"#);
+    ///
+    /// ```
+    ///
+    pub const fn write_char_debug(&mut self, character: char) -> Result<(), Error> {
+        let debug = char_debug(character);
+        self.write_str_inner(&debug.array, 0, debug.len)
+    }
+
+    /// Writes `bytes` as a Debug-like formatted byte string literal.
+    ///
+    /// This escapes `\t`, `\r`, `\n`, `\\`, `'`, and `"` with a backslash,
+    /// and every byte that isn't printable ascii as `\xYY`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{FormattingFlags, StrWriterMut};
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_bytes_debug(b"GET /\r\n\x00\xFF");
+    ///
+    /// assert_eq!(writer.as_str(), r#"b"GET /\r\n\x00\xff""#);
+    ///
+    /// ```
+    ///
+    pub const fn write_bytes_debug(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let len = PWrapper(bytes).compute_byte_str_debug_len();
+
+        let limit = self.write_limit(len);
+
+        borrow_fields!(self, self_len, self_buffer);
+
+        let mut written = *self_len;
+
+        if written + 2 > limit {
+            return self.overflowed();
+        }
+        self_buffer[written] = b'b';
+        self_buffer[written + 1] = b'"';
+        written += 2;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let escape = byte_escape(bytes[i]);
+            let piece_len = if escape.len == 0 { 1 } else { escape.len };
+
+            if written + piece_len > limit {
+                *self_len = written;
+                return self.overflowed();
+            } else if escape.len == 0 {
+                self_buffer[written] = bytes[i];
+                written += 1;
+            } else {
+                let mut j = 0;
+                while j < escape.len {
+                    self_buffer[written] = escape.array[j];
+                    written += 1;
+                    j += 1;
+                }
+            }
+
+            i += 1;
+        }
+
+        if written == limit {
+            *self_len = written;
+            return self.overflowed();
+        }
+        self_buffer[written] = b'"';
+        written += 1;
+
+        *self_len = written;
+
+        Ok(())
+    }
+
     #[inline(always)]
     const fn write_str_debug_inner(
         &mut self,
@@ -1161,62 +1791,55 @@ impl<'w, E> StrWriterMut<'w, E> {
         mut start: usize,
         end: usize,
     ) -> Result<(), Error> {
-        borrow_fields!(self, self_len, self_buffer);
+        let len = PWrapper(bytes).compute_utf8_debug_len_in_range(start..end);
 
-        let len = end - start;
+        let limit = self.write_limit(len);
 
-        // + 2 for the quote characters around the string.
-        if *self_len + len + 2 > self_buffer.len() {
-            return Err(Error::NotEnoughSpace);
-        }
+        borrow_fields!(self, self_len, self_buffer);
 
-        // The amount of bytes available for escapes,
-        // not counting the `writte_c`.
-        let mut remaining_for_escapes = (self_buffer.len() - 2 - len - *self_len) as isize;
         let mut written = *self_len;
 
+        if written == limit {
+            return self.overflowed();
+        }
         self_buffer[written] = b'"';
         written += 1;
 
         while start != end {
-            let c = bytes[start];
-            let mut written_c = c;
-
-            let mut shifted = 0;
-
-            if c < 128
-                && ({
-                    shifted = 1 << c;
-                    (FOR_ESCAPING.is_escaped & shifted) != 0
-                })
-            {
-                self_buffer[written] = b'\\';
-                written += 1;
-
-                if (FOR_ESCAPING.is_backslash_escaped & shifted) != 0 {
-                    remaining_for_escapes -= 1;
-                    if remaining_for_escapes < 0 {
-                        return Err(Error::NotEnoughSpace);
-                    }
-                    written_c = ForEscaping::get_backslash_escape(c);
-                } else {
-                    remaining_for_escapes -= 3;
-                    if remaining_for_escapes < 0 {
-                        return Err(Error::NotEnoughSpace);
-                    }
-                    self_buffer[written] = b'x';
+            let (u, char_len) = decode_utf8(bytes, start, end);
+            let escape = debug_escape(u, b'"');
+            let piece_len = if escape.len == 0 {
+                char_len
+            } else {
+                escape.len
+            };
+
+            if written + piece_len > limit {
+                *self_len = written;
+                return self.overflowed();
+            } else if escape.len == 0 {
+                let mut i = 0;
+                while i < char_len {
+                    self_buffer[written] = bytes[start + i];
                     written += 1;
-                    self_buffer[written] = hex_as_ascii(c >> 4);
+                    i += 1;
+                }
+            } else {
+                let mut i = 0;
+                while i < escape.len {
+                    self_buffer[written] = escape.array[i];
                     written += 1;
-                    written_c = hex_as_ascii(c & 0xF);
-                };
+                    i += 1;
+                }
             }
 
-            self_buffer[written] = written_c;
-            written += 1;
-            start += 1;
+            start += char_len;
         }
 
+        if written == limit {
+            *self_len = written;
+            return self.overflowed();
+        }
         self_buffer[written] = b'"';
         written += 1;
 
@@ -1269,12 +1892,16 @@ write_integer_fn! {
         /// let mut buffer = [0; 64];
         /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
         ///
-        /// assert_eq!(debug_fmt(&mut writer, reg_flag),                   "63"     );
-        /// assert_eq!(debug_fmt(&mut writer, reg_flag.set_hexadecimal()), "3F"     );
-        /// assert_eq!(debug_fmt(&mut writer, reg_flag.set_binary()),      "111111" );
-        /// assert_eq!(debug_fmt(&mut writer, alt_flag),                   "63"     );
-        /// assert_eq!(debug_fmt(&mut writer, alt_flag.set_hexadecimal()), "0x3F"   );
-        /// assert_eq!(debug_fmt(&mut writer, alt_flag.set_binary()),      "0b111111");
+        /// assert_eq!(debug_fmt(&mut writer, reg_flag),                          "63"      );
+        /// assert_eq!(debug_fmt(&mut writer, reg_flag.set_hexadecimal()),        "3F"      );
+        /// assert_eq!(debug_fmt(&mut writer, reg_flag.set_lower_hexadecimal()),  "3f"      );
+        /// assert_eq!(debug_fmt(&mut writer, reg_flag.set_octal()),              "77"      );
+        /// assert_eq!(debug_fmt(&mut writer, reg_flag.set_binary()),             "111111"  );
+        /// assert_eq!(debug_fmt(&mut writer, alt_flag),                          "63"      );
+        /// assert_eq!(debug_fmt(&mut writer, alt_flag.set_hexadecimal()),        "0x3F"    );
+        /// assert_eq!(debug_fmt(&mut writer, alt_flag.set_lower_hexadecimal()),  "0x3f"    );
+        /// assert_eq!(debug_fmt(&mut writer, alt_flag.set_octal()),              "0o77"    );
+        /// assert_eq!(debug_fmt(&mut writer, alt_flag.set_binary()),             "0b111111");
         ///
         /// ```
         ///
@@ -1311,3 +1938,135 @@ write_integer_fn! {
     (write_i128_display, write_i128_debug, signed, i128, u128)
     (write_isize_display, write_isize_debug, signed, isize, usize)
 }
+
+/// Floats
+#[cfg(feature = "float")]
+impl<'w, E> StrWriterMut<'w, E> {
+    /// Writes `number` with display formatting,
+    /// using the width, fill, alignment, sign, and precision of `flags`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{FormattingFlags, StrWriterMut};
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let flags = FormattingFlags::NEW;
+    ///
+    /// let _ = writer.write_f64_display(1.0, flags);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_display(-0.25, flags);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_display(1e20, flags);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_display(2.0 / 3.0, flags.set_precision(3));
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_display(f64::NAN, flags);
+    ///
+    /// assert_eq!(writer.as_str(), "1 -0.25 100000000000000000000 0.667 NaN");
+    ///
+    /// ```
+    ///
+    pub const fn write_f64_display(
+        &mut self,
+        number: f64,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        self.write_float(Float::from_f64(number), Formatting::Display, flags)
+    }
+
+    /// Writes `number` with debug formatting,
+    /// using the width, fill, alignment, sign, and precision of `flags`.
+    ///
+    /// Like with `std`, very large and very small numbers are written in exponential notation
+    /// when there is no precision.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{FormattingFlags, StrWriterMut};
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let flags = FormattingFlags::NEW;
+    ///
+    /// let _ = writer.write_f64_debug(1.0, flags);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_debug(-0.25, flags);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_debug(1e20, flags);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_debug(1.5, flags.set_width(6).set_sign_aware_zero_pad(true));
+    ///
+    /// assert_eq!(writer.as_str(), "1.0 -0.25 1e20 0001.5");
+    ///
+    /// ```
+    ///
+    pub const fn write_f64_debug(
+        &mut self,
+        number: f64,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        self.write_float(Float::from_f64(number), Formatting::Debug, flags)
+    }
+
+    /// Writes `number` with display formatting,
+    /// using the width, fill, alignment, sign, and precision of `flags`.
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_display`] method.
+    ///
+    /// [`write_f64_display`]: #method.write_f64_display
+    pub const fn write_f32_display(
+        &mut self,
+        number: f32,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        self.write_float(Float::from_f32(number), Formatting::Display, flags)
+    }
+
+    /// Writes `number` with debug formatting,
+    /// using the width, fill, alignment, sign, and precision of `flags`.
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_debug`] method.
+    ///
+    /// [`write_f64_debug`]: #method.write_f64_debug
+    pub const fn write_f32_debug(
+        &mut self,
+        number: f32,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        self.write_float(Float::from_f32(number), Formatting::Debug, flags)
+    }
+
+    const fn write_float(
+        &mut self,
+        float: Float,
+        fmt: Formatting,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        let float = FmtFloat::new(float, fmt, flags);
+        let start = *self.len;
+
+        if self.overflows(float.len) {
+            return self.overflowed();
+        }
+
+        let mut i = 0;
+        while i < float.len {
+            self.buffer[start + i] = float.byte_at(i);
+            i += 1;
+        }
+        *self.len += float.len;
+
+        self.pad_number_since(start, flags)
+    }
+}
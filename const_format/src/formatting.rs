@@ -1,3 +1,7 @@
+use core::ops::Range;
+
+mod unicode;
+
 #[doc(hidden)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Formatting {
@@ -15,7 +19,7 @@ impl Formatting {
 
 /// How numbers are formatted in debug formatters.
 ///
-/// Hexadecimal or binary formatting in the formatting string from this crate imply
+/// Hexadecimal, octal, or binary formatting in the formatting string from this crate imply
 /// debug formatting.
 ///
 ///
@@ -23,8 +27,12 @@ impl Formatting {
 pub enum NumberFormatting {
     /// Formats numbers as decimal
     Decimal,
-    /// Formats numbers as hexadecimal
+    /// Formats numbers as capitalized hexadecimal
     Hexadecimal,
+    /// Formats numbers as lowercase hexadecimal
+    LowerHexadecimal,
+    /// Formats numbers as octal
+    Octal,
     /// Formats numbers as binary
     Binary,
 }
@@ -32,13 +40,29 @@ pub enum NumberFormatting {
 impl NumberFormatting {
     #[cfg(test)]
     #[cfg(feature = "fmt")]
-    pub(crate) const ALL: &'static [Self; 3] = &[
+    pub(crate) const ALL: &'static [Self; 5] = &[
         NumberFormatting::Decimal,
         NumberFormatting::Hexadecimal,
+        NumberFormatting::LowerHexadecimal,
+        NumberFormatting::Octal,
         NumberFormatting::Binary,
     ];
 }
 
+/// How formatted text is aligned inside the padding added when a width is specified.
+///
+/// The default alignment depends on the type being formatted,
+/// numbers are right aligned, while everything else is left aligned.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Alignment {
+    /// Left aligns the text (eg: `formatc!("{:<8}", FOO)`).
+    Left,
+    /// Right aligns the text (eg: `formatc!("{:>8}", FOO)`).
+    Right,
+    /// Center aligns the text (eg: `formatc!("{:^8}", FOO)`).
+    Center,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// This type bundles configuration for how to format data into strings, including.
@@ -54,12 +78,19 @@ impl NumberFormatting {
 /// formats numbers as decimal.
 ///
 /// - `NumberFormatting::Hexadecimal`  (eg: `formatc!("{:x}", FOO)`):
-/// formats numbers as hexadecimal.
+/// formats numbers as capitalized hexadecimal.
+///
+/// - `NumberFormatting::LowerHexadecimal`
+/// (only available with [`FormattingFlags::set_lower_hexadecimal`]):
+/// formats numbers as lowercase hexadecimal.
+///
+/// - `NumberFormatting::Octal`  (eg: `formatc!("{:o}", FOO)`):
+/// formats numbers as octal.
 ///
 /// - `NumberFormatting::Binary` (eg: `formatc!("{:b}", FOO)`):
 /// formats numbers as binary.
 ///
-/// Hexadecimal or binary formatting in the formatting string from this crate imply
+/// Hexadecimal, octal, or binary formatting in the formatting string from this crate imply
 /// debug formatting,
 /// and can be used to for example print an array of binary numbers.
 ///
@@ -73,37 +104,187 @@ impl NumberFormatting {
 /// - The Debug formater (eg: `formatc!("{:#?}", FOO)`):
 /// pretty print structs and enums.
 ///
-/// - The hexadecimal formater (eg: `formatc!("{:#x}", FOO)`):
+/// - The hexadecimal formaters (eg: `formatc!("{:#x}", FOO)`, `formatc!("{:#X}", FOO)`):
 /// prefixes numbers with `0x`.
 ///
+/// - The octal formater (eg: `formatc!("{:#o}", FOO)`):
+/// prefixes numbers with `0o`.
+///
 /// - The binary formater (eg: `formatc!("{:#b}", FOO)`):
 /// prefixes numbers with `0b`.`
 ///
+/// # Width, fill, and alignment
+///
+/// The minimum amount of characters that a value is formatted with,
+/// set with the `set_width` method.
+/// If the value is shorter than the width, it's padded with the fill character
+/// (a space by default, set with the `set_fill` method),
+/// positioned with the [`Alignment`] set with the `set_alignment` method.
+///
+/// Numbers are right aligned by default, and everything else is left aligned.
+///
+/// - `formatc!("{:8}", FOO)`: pads to 8 characters with the default alignment.
+///
+/// - `formatc!("{:>8}", FOO)`: right aligned.
+///
+/// - `formatc!("{:*^8}", FOO)`: center aligned, padded with `*`.
+///
+/// - `formatc!("{:08}", FOO)`: pads numbers with zeroes after the sign ("sign aware zero pad").
+///
+/// # Sign
+///
+/// Whether non-negative numbers are written with a leading `+`,
+/// set with the `set_sign_plus` method.
+///
+/// - `formatc!("{:+}", 5)`: writes `"+5"`.
+///
+/// - `formatc!("{:+#06x}", 10)`: writes `"+0x00A"`.
+///
+/// Numbers written as hexadecimal, octal, or binary never have a `-`,
+/// so they always get the `+` sign.
+///
+/// # Precision
+///
+/// Set with the `set_precision` method,
+/// the maximum amount of characters of Display formatted strings,
+/// and the minimum amount of digits of integers.
+///
+/// - `formatc!("{:.3}", "hello")`: truncates the string to `"hel"`.
+///
+/// - `formatc!("{:.4}", 5)`: writes the number with at least 4 digits, `"0005"`.
+///
+/// The standard library's formatting macros ignore the precision for integers.
+///
+/// # Pretty printing
+///
+/// These flags configure how the alternate Debug formatter (eg: `formatc!("{:#?}", FOO)`)
+/// lays out structs, tuples, lists, sets, and maps.
+/// They aren't settable from formatting strings,
+/// so they're set on the `FormattingFlags` passed to the [`Formatter`] constructors.
+///
+/// - Indentation: each level of nesting is indented by 4 spaces by default,
+/// the amount of spaces is set with the `set_indent_width` method,
+/// and `set_tab_indentation(true)` indents each level with a tab instead.
+///
+/// - Trailing commas: the last field/entry is followed by a comma by default,
+/// this is disabled with `set_trailing_commas(false)`.
+///
+/// - Compact collections: arrays and slices formatted by this crate
+/// (those of standard library types, and the fields of types that derive [`ConstDebug`])
+/// are written on a single line when the single line form of the array is
+/// at most `compact_width` bytes long, set with the `set_compact_width` method.
+/// The indentation and the text before the array (eg: a field name) aren't counted.
+/// The default width is `0`, which disables this.
+///
+#[cfg_attr(feature = "fmt", doc = "```rust")]
+#[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{Formatter, FormattingFlags, StrWriter};
+/// use const_format::call_debug_fmt;
+///
+/// let flags = FormattingFlags::NEW
+///     .set_alternate(true)
+///     .set_indent_width(2)
+///     .set_trailing_commas(false)
+///     .set_compact_width(16);
+///
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; 128]);
+///
+/// let list: &[&[u8]] = &[&[3, 5], &[8, 13, 21, 34, 55, 89]];
+/// call_debug_fmt!(slice, list, Formatter::from_sw(writer, flags));
+///
+/// assert_eq!(
+///     writer.as_str(),
+///     "[\n  [3, 5],\n  [\n    8,\n    13,\n    21,\n    34,\n    55,\n    89\n  ]\n]",
+/// );
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
+/// # Limits
+///
+/// These flags bound how much of a value the Debug formatter writes,
+/// writing `..` in place of the fields/entries that it skips.
+///
+/// - Maximum depth: how many levels of nested structs, tuples, and collections
+/// are written with their contents,
+/// set with the `set_max_depth` method.
+/// With a maximum depth of `1`, the fields of a struct are written,
+/// but a struct in a field is written as `Foo { .. }`,
+/// and a list in a field is written as `[..]`.
+///
+/// - Maximum entries: how many fields/entries each struct, tuple, or collection
+/// writes before skipping the rest,
+/// set with the `set_max_entries` method.
+/// With a maximum of `2` entries, `[3, 5, 8, 13]` is written as `[3, 5, ..]`.
+///
+#[cfg_attr(feature = "fmt", doc = "```rust")]
+#[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{Formatter, FormattingFlags, StrWriter};
+/// use const_format::call_debug_fmt;
+///
+/// let flags = FormattingFlags::NEW.set_max_depth(1).set_max_entries(2);
+///
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+///
+/// let list: &[&[u8]] = &[&[3, 5], &[8, 13, 21, 34], &[55, 89, 144]];
+/// call_debug_fmt!(slice, list, Formatter::from_sw(writer, flags));
+///
+/// assert_eq!(writer.as_str(), "[[..], [..], ..]");
+///
+/// writer.clear();
+/// let flags = FormattingFlags::NEW.set_max_entries(2);
+/// call_debug_fmt!(slice, list, Formatter::from_sw(writer, flags));
+///
+/// assert_eq!(writer.as_str(), "[[3, 5], [8, 13, ..], ..]");
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
 /// [`Formatter`]: ./struct.Formatter.html
+/// [`Alignment`]: ./enum.Alignment.html
+/// [`ConstDebug`]: ./derive.ConstDebug.html
 ///
 #[must_use]
 #[derive(Debug, Copy, Clone)]
 pub struct FormattingFlags {
     num_fmt: NumberFormatting,
     is_alternate: bool,
+    width: usize,
+    fill: char,
+    alignment: Option<Alignment>,
+    sign_aware_zero_pad: bool,
+    sign_plus: bool,
+    precision: Option<usize>,
+    indent_width: u8,
+    tab_indentation: bool,
+    trailing_commas: bool,
+    compact_width: usize,
+    max_depth: Option<usize>,
+    max_entries: Option<usize>,
+    // Whether the alternate flag only affects number prefixes,
+    // set when a collection is compacted into a single line.
+    single_line: bool,
 }
 
 #[doc(hidden)]
 impl FormattingFlags {
     pub const __REG: Self = Self::NEW.set_alternate(false).set_decimal();
     pub const __HEX: Self = Self::NEW.set_alternate(false).set_hexadecimal();
+    pub const __OCT: Self = Self::NEW.set_alternate(false).set_octal();
     pub const __BIN: Self = Self::NEW.set_alternate(false).set_binary();
 
     pub const __A_REG: Self = Self::NEW.set_alternate(true).set_decimal();
     pub const __A_HEX: Self = Self::NEW.set_alternate(true).set_hexadecimal();
+    pub const __A_OCT: Self = Self::NEW.set_alternate(true).set_octal();
     pub const __A_BIN: Self = Self::NEW.set_alternate(true).set_binary();
 }
 impl FormattingFlags {
     #[doc(hidden)]
-    pub const DEFAULT: Self = Self {
-        num_fmt: NumberFormatting::Decimal,
-        is_alternate: false,
-    };
+    pub const DEFAULT: Self = Self::NEW;
 
     /// Constructs a `FormattingFlags` with these values:
     ///
@@ -111,9 +292,46 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - width: 0
+    ///
+    /// - fill: `' '`
+    ///
+    /// - alignment: None
+    ///
+    /// - sign aware zero pad: false
+    ///
+    /// - sign plus: false
+    ///
+    /// - precision: None
+    ///
+    /// - indent width: 4
+    ///
+    /// - tab indentation: false
+    ///
+    /// - trailing commas: true
+    ///
+    /// - compact width: 0
+    ///
+    /// - max depth: None
+    ///
+    /// - max entries: None
+    ///
     pub const NEW: Self = Self {
         num_fmt: NumberFormatting::Decimal,
         is_alternate: false,
+        width: 0,
+        fill: ' ',
+        alignment: None,
+        sign_aware_zero_pad: false,
+        sign_plus: false,
+        precision: None,
+        indent_width: 4,
+        tab_indentation: false,
+        trailing_commas: true,
+        compact_width: 0,
+        max_depth: None,
+        max_entries: None,
+        single_line: false,
     };
 
     /// Constructs a `FormattingFlags` with these values:
@@ -122,6 +340,30 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - width: 0
+    ///
+    /// - fill: `' '`
+    ///
+    /// - alignment: None
+    ///
+    /// - sign aware zero pad: false
+    ///
+    /// - sign plus: false
+    ///
+    /// - precision: None
+    ///
+    /// - indent width: 4
+    ///
+    /// - tab indentation: false
+    ///
+    /// - trailing commas: true
+    ///
+    /// - compact width: 0
+    ///
+    /// - max depth: None
+    ///
+    /// - max entries: None
+    ///
     #[inline]
     pub const fn new() -> Self {
         Self::NEW
@@ -147,13 +389,31 @@ impl FormattingFlags {
 
     /// Sets the formatting num_fmt to `NumberFormatting::Hexadecimal`.
     ///
-    /// This means that numbers are written as hexadecimal.
+    /// This means that numbers are written as capitalized hexadecimal.
     #[inline]
     pub const fn set_hexadecimal(mut self) -> Self {
         self.num_fmt = NumberFormatting::Hexadecimal;
         self
     }
 
+    /// Sets the formatting num_fmt to `NumberFormatting::LowerHexadecimal`.
+    ///
+    /// This means that numbers are written as lowercase hexadecimal.
+    #[inline]
+    pub const fn set_lower_hexadecimal(mut self) -> Self {
+        self.num_fmt = NumberFormatting::LowerHexadecimal;
+        self
+    }
+
+    /// Sets the formatting num_fmt to `NumberFormatting::Octal`.
+    ///
+    /// This means that numbers are written as octal.
+    #[inline]
+    pub const fn set_octal(mut self) -> Self {
+        self.num_fmt = NumberFormatting::Octal;
+        self
+    }
+
     /// Sets the formatting num_fmt to `NumberFormatting::Binary`.
     ///
     /// This means that numbers are written as binary.
@@ -181,6 +441,634 @@ impl FormattingFlags {
     pub const fn is_alternate(self) -> bool {
         self.is_alternate
     }
+
+    /// Sets the minimum amount of characters that values are formatted with.
+    ///
+    /// A width of `0` means that no padding is added.
+    #[inline]
+    pub const fn set_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the character used to pad values shorter than the width.
+    #[inline]
+    pub const fn set_fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Sets how values are aligned inside the padding.
+    #[inline]
+    pub const fn set_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets whether numbers are padded with zeroes after the sign,
+    /// instead of with the fill character.
+    #[inline]
+    pub const fn set_sign_aware_zero_pad(mut self, sign_aware_zero_pad: bool) -> Self {
+        self.sign_aware_zero_pad = sign_aware_zero_pad;
+        self
+    }
+
+    /// Gets the minimum amount of characters that values are formatted with.
+    #[inline]
+    pub const fn width(self) -> usize {
+        self.width
+    }
+
+    /// Gets the character used to pad values shorter than the width.
+    #[inline]
+    pub const fn fill(self) -> char {
+        self.fill
+    }
+
+    /// Gets the alignment of values inside the padding,
+    /// `None` if the type being formatted decides.
+    #[inline]
+    pub const fn alignment(self) -> Option<Alignment> {
+        self.alignment
+    }
+
+    /// Gets whether numbers are padded with zeroes after the sign.
+    #[inline]
+    pub const fn sign_aware_zero_pad(self) -> bool {
+        self.sign_aware_zero_pad
+    }
+
+    /// Sets whether non-negative numbers are written with a leading `+`.
+    #[inline]
+    pub const fn set_sign_plus(mut self, sign_plus: bool) -> Self {
+        self.sign_plus = sign_plus;
+        self
+    }
+
+    /// Gets whether non-negative numbers are written with a leading `+`.
+    #[inline]
+    pub const fn sign_plus(self) -> bool {
+        self.sign_plus
+    }
+
+    /// Sets the precision,
+    /// the maximum amount of characters that Display formatted strings are written with,
+    /// and the minimum amount of digits that integers are written with.
+    #[inline]
+    pub const fn set_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Removes the precision.
+    #[inline]
+    pub const fn unset_precision(mut self) -> Self {
+        self.precision = None;
+        self
+    }
+
+    /// Gets the precision, `None` if it wasn't set.
+    #[inline]
+    pub const fn precision(self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Sets how many spaces each level of nesting is indented with when pretty printing.
+    #[inline]
+    pub const fn set_indent_width(mut self, indent_width: u8) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    /// Gets how many spaces each level of nesting is indented with when pretty printing.
+    #[inline]
+    pub const fn indent_width(self) -> u8 {
+        self.indent_width
+    }
+
+    /// Sets whether each level of nesting is indented with a tab when pretty printing,
+    /// instead of `indent_width` spaces.
+    #[inline]
+    pub const fn set_tab_indentation(mut self, tab_indentation: bool) -> Self {
+        self.tab_indentation = tab_indentation;
+        self
+    }
+
+    /// Gets whether each level of nesting is indented with a tab when pretty printing.
+    #[inline]
+    pub const fn tab_indentation(self) -> bool {
+        self.tab_indentation
+    }
+
+    /// Sets whether the last field/entry is followed by a comma when pretty printing.
+    #[inline]
+    pub const fn set_trailing_commas(mut self, trailing_commas: bool) -> Self {
+        self.trailing_commas = trailing_commas;
+        self
+    }
+
+    /// Gets whether the last field/entry is followed by a comma when pretty printing.
+    #[inline]
+    pub const fn trailing_commas(self) -> bool {
+        self.trailing_commas
+    }
+
+    /// Sets the maximum length (in bytes) of arrays and slices that are
+    /// written on a single line when pretty printing.
+    ///
+    /// Only the length of the array/slice itself is compared to this width,
+    /// not counting the indentation and the text before it on the same line.
+    ///
+    /// A width of `0` means that arrays and slices are always written on multiple lines.
+    #[inline]
+    pub const fn set_compact_width(mut self, compact_width: usize) -> Self {
+        self.compact_width = compact_width;
+        self
+    }
+
+    /// Gets the maximum length (in bytes) of arrays and slices that are
+    /// written on a single line when pretty printing.
+    #[inline]
+    pub const fn compact_width(self) -> usize {
+        self.compact_width
+    }
+
+    /// Sets how many levels of nested structs, tuples, and collections
+    /// are written with their contents,
+    /// the contents of more deeply nested ones are replaced with `..`.
+    #[inline]
+    pub const fn set_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Removes the maximum depth.
+    #[inline]
+    pub const fn unset_max_depth(mut self) -> Self {
+        self.max_depth = None;
+        self
+    }
+
+    /// Gets the maximum depth, `None` if it wasn't set.
+    #[inline]
+    pub const fn max_depth(self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Sets how many fields/entries structs, tuples, and collections write,
+    /// the remaining ones are replaced with `..`.
+    #[inline]
+    pub const fn set_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Removes the maximum amount of entries.
+    #[inline]
+    pub const fn unset_max_entries(mut self) -> Self {
+        self.max_entries = None;
+        self
+    }
+
+    /// Gets the maximum amount of entries, `None` if it wasn't set.
+    #[inline]
+    pub const fn max_entries(self) -> Option<usize> {
+        self.max_entries
+    }
+}
+
+#[doc(hidden)]
+impl FormattingFlags {
+    /// Makes the alternate flag only affect number prefixes,
+    /// so that structs and collections are written on a single line.
+    #[inline]
+    pub const fn __single_line(mut self) -> Self {
+        self.single_line = true;
+        self
+    }
+
+    /// Whether structs and collections are pretty printed on multiple lines.
+    #[inline]
+    pub const fn __is_pretty(self) -> bool {
+        self.is_alternate && !self.single_line
+    }
+
+    /// Whether arrays and slices have to be measured to check if they fit
+    /// in the compact width.
+    #[inline]
+    pub const fn __measures_compact(self) -> bool {
+        self.__is_pretty() && self.compact_width != 0
+    }
+
+    /// How many indentation characters each level of nesting adds.
+    #[inline]
+    pub const fn __indent_step(self) -> u16 {
+        if self.tab_indentation {
+            1
+        } else {
+            self.indent_width as u16
+        }
+    }
+
+    /// The character that pretty printed output is indented with.
+    #[inline]
+    pub const fn __indent_byte(self) -> u8 {
+        if self.tab_indentation {
+            b'\t'
+        } else {
+            b' '
+        }
+    }
+
+    /// The separator written after the last field/entry when pretty printing.
+    #[inline]
+    pub const fn __pretty_close_sep(self) -> &'static str {
+        if self.trailing_commas {
+            ",\n"
+        } else {
+            "\n"
+        }
+    }
+
+    /// Sets the alignment to `alignment` if it wasn't set.
+    #[inline]
+    pub const fn __or_alignment(mut self, alignment: Alignment) -> Self {
+        if self.alignment.is_none() {
+            self.alignment = Some(alignment);
+        }
+        self
+    }
+
+    /// Computes how many padding characters go before and after `chars` characters.
+    pub const fn __padding(self, chars: usize) -> Padding {
+        let count = self.width.saturating_sub(chars);
+        let (before, after) = match self.alignment {
+            None | Some(Alignment::Left) => (0, count),
+            Some(Alignment::Right) => (count, 0),
+            Some(Alignment::Center) => (count / 2, count - count / 2),
+        };
+        Padding {
+            before,
+            after,
+            fill: EncodedChar::new(self.fill),
+        }
+    }
+
+    /// Computes the padding of an integer with `chars` characters.
+    ///
+    /// Integers are right aligned by default,
+    /// and are padded with zeroes after the sign if `sign_aware_zero_pad` is enabled.
+    pub const fn __integer_padding(self, chars: usize) -> Padding {
+        if self.sign_aware_zero_pad {
+            self.set_fill('0')
+                .set_alignment(Alignment::Right)
+                .__padding(chars)
+        } else {
+            self.__or_alignment(Alignment::Right).__padding(chars)
+        }
+    }
+
+    /// Computes the zeroes that the precision adds before an integer with `digits` digits.
+    pub const fn __precision_padding(self, digits: usize) -> Padding {
+        let before = match self.precision {
+            Some(precision) => precision.saturating_sub(digits),
+            None => 0,
+        };
+        Padding {
+            before,
+            after: 0,
+            fill: EncodedChar::new('0'),
+        }
+    }
+
+    /// Computes the `+` sign written before an integer,
+    /// `has_minus` is whether the integer was written with a `-`.
+    pub const fn __plus_sign_padding(self, has_minus: bool) -> Padding {
+        Padding {
+            before: (self.sign_plus && !has_minus) as usize,
+            after: 0,
+            fill: EncodedChar::new('+'),
+        }
+    }
+
+    /// Computes the length of an integer formatted with `fmt`,
+    /// that is `len` bytes long before the sign and precision are applied.
+    pub const fn __integer_unpadded_len(
+        self,
+        fmt: Formatting,
+        len: usize,
+        is_negative: bool,
+    ) -> usize {
+        let (has_minus, prefix_len) = match (fmt, self.num_fmt) {
+            (Formatting::Display, _) | (_, NumberFormatting::Decimal) => {
+                (is_negative, is_negative as usize)
+            }
+            _ => (false, (self.is_alternate as usize) * 2),
+        };
+        let digits = len - prefix_len;
+        len + self.__plus_sign_padding(has_minus).len() + self.__precision_padding(digits).len()
+    }
+
+    /// Computes the length of an integer formatted with `fmt`,
+    /// that is `len` bytes long before the sign, precision, and padding are applied.
+    pub const fn __integer_len(self, fmt: Formatting, len: usize, is_negative: bool) -> usize {
+        let len = self.__integer_unpadded_len(fmt, len, is_negative);
+        len + self.__integer_padding(len).len()
+    }
+
+    /// Gets the length of the prefix of the utf8 encoded `bytes`
+    /// that Display formatting writes with this precision.
+    pub const fn __str_precision_len(self, bytes: &[u8]) -> usize {
+        match self.precision {
+            Some(precision) => char_boundary_after(bytes, precision),
+            None => bytes.len(),
+        }
+    }
+
+    /// Gets the length that Display formatting writes a `char`
+    /// that is `len` bytes long with, which is `0` for a `0` precision.
+    pub const fn __char_precision_len(self, len: usize) -> usize {
+        match self.precision {
+            Some(0) => 0,
+            _ => len,
+        }
+    }
+
+    /// Computes the length of `len` bytes with `chars` characters,
+    /// after being padded.
+    #[inline]
+    pub const fn __padded_len(self, len: usize, chars: usize) -> usize {
+        let padding = self.__padding(chars);
+        len + padding.len()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[doc(hidden)]
+/// How much padding is written around a formatted value.
+#[derive(Debug, Copy, Clone)]
+pub struct Padding {
+    /// The amount of fill characters before the value
+    pub before: usize,
+    /// The amount of fill characters after the value
+    pub after: usize,
+    pub fill: EncodedChar,
+}
+
+impl Padding {
+    /// The length in bytes of all the padding.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        (self.before + self.after) * self.fill.len
+    }
+
+    /// Whether there is no padding.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.before + self.after == 0
+    }
+}
+
+#[doc(hidden)]
+/// A utf8 encoded `char`
+#[derive(Debug, Copy, Clone)]
+pub struct EncodedChar {
+    pub encoded: [u8; 4],
+    pub len: usize,
+}
+
+impl EncodedChar {
+    pub const fn new(c: char) -> Self {
+        let u = c as u32;
+        let (encoded, len) = if u < 0x80 {
+            ([u as u8, 0, 0, 0], 1)
+        } else if u < 0x800 {
+            ([0xC0 | (u >> 6) as u8, 0x80 | (u & 0x3F) as u8, 0, 0], 2)
+        } else if u < 0x10000 {
+            (
+                [
+                    0xE0 | (u >> 12) as u8,
+                    0x80 | ((u >> 6) & 0x3F) as u8,
+                    0x80 | (u & 0x3F) as u8,
+                    0,
+                ],
+                3,
+            )
+        } else {
+            (
+                [
+                    0xF0 | (u >> 18) as u8,
+                    0x80 | ((u >> 12) & 0x3F) as u8,
+                    0x80 | ((u >> 6) & 0x3F) as u8,
+                    0x80 | (u & 0x3F) as u8,
+                ],
+                4,
+            )
+        };
+        Self { encoded, len }
+    }
+}
+
+#[doc(hidden)]
+/// Debug formats `c`, including the single quotes around it.
+///
+/// This escapes the same characters as string Debug formatting does,
+/// except that `'` is escaped instead of `"`.
+pub const fn char_debug(c: char) -> LenAndArray<[u8; 12]> {
+    let mut array = [b'\''; 12];
+    let mut len = 1;
+
+    let escape = debug_escape(c as u32, b'\'');
+    if escape.len == 0 {
+        let encoded = EncodedChar::new(c);
+        while len <= encoded.len {
+            array[len] = encoded.encoded[len - 1];
+            len += 1;
+        }
+    } else {
+        while len <= escape.len {
+            array[len] = escape.array[len - 1];
+            len += 1;
+        }
+    }
+
+    LenAndArray {
+        len: len + 1,
+        array,
+    }
+}
+
+#[doc(hidden)]
+/// Gets the escape sequence that Debug formatting writes for the `u` code point,
+/// which has a `len` of 0 if the code point is written as is.
+///
+/// This escapes the same code points as the standard library does:
+/// - `\0`, `\t`, `\r`, `\n`, `\\`, and the `quote` character are escaped with a backslash.
+/// - control characters, grapheme extend characters,
+/// and other non-printable characters are escaped as `\u{...}`.
+pub const fn debug_escape(u: u32, quote: u8) -> LenAndArray<[u8; 10]> {
+    let mut array = [0u8; 10];
+
+    if u < 128 {
+        let shifted = 1u128 << u;
+        let is_other_quote = (u == b'"' as u32 || u == b'\'' as u32) && u != quote as u32;
+
+        if (FOR_ESCAPING.is_escaped & shifted) == 0 || is_other_quote {
+            return LenAndArray { len: 0, array };
+        } else if (FOR_ESCAPING.is_backslash_escaped & shifted) != 0 {
+            array[0] = b'\\';
+            array[1] = ForEscaping::get_backslash_escape(u as u8);
+            return LenAndArray { len: 2, array };
+        }
+    } else if !unicode::is_escaped_non_ascii(u) {
+        return LenAndArray { len: 0, array };
+    }
+
+    let mut digits = 1;
+    while (u >> (digits * 4)) != 0 {
+        digits += 1;
+    }
+
+    array[0] = b'\\';
+    array[1] = b'u';
+    array[2] = b'{';
+    let mut i = 0;
+    while i < digits {
+        array[3 + i] = lower_hex_as_ascii(((u >> ((digits - 1 - i) * 4)) & 0xF) as u8);
+        i += 1;
+    }
+    array[3 + digits] = b'}';
+
+    LenAndArray {
+        len: digits + 4,
+        array,
+    }
+}
+
+#[doc(hidden)]
+/// Decodes the utf8 encoded character that starts at `bytes[start]`,
+/// returning its code point and how many bytes it takes.
+///
+/// Bytes that don't start a character in `bytes[start..end]`
+/// are returned as a code point with that value.
+pub const fn decode_utf8(bytes: &[u8], start: usize, end: usize) -> (u32, usize) {
+    let b = bytes[start];
+    let len = if b < 0xC0 {
+        return (b as u32, 1);
+    } else if b < 0xE0 {
+        2
+    } else if b < 0xF0 {
+        3
+    } else {
+        4
+    };
+
+    if start + len > end {
+        return (b as u32, 1);
+    }
+
+    // Removing the length bits from the leading byte
+    let mut code = (b & (0x7F >> len)) as u32;
+    let mut i = 1;
+    while i < len {
+        code = (code << 6) | (bytes[start + i] & 0x3F) as u32;
+        i += 1;
+    }
+    (code, len)
+}
+
+#[doc(hidden)]
+/// Counts the characters in the Debug formatted utf8 encoded `bytes[range]`,
+/// including the double quotes around it.
+pub const fn count_debug_chars(bytes: &[u8], range: Range<usize>) -> usize {
+    let Range { mut start, end } = range;
+    let mut count = 2;
+    while start < end {
+        let (u, char_len) = decode_utf8(bytes, start, end);
+        let escape_len = debug_escape(u, b'"').len;
+        count += if escape_len == 0 { 1 } else { escape_len };
+        start += char_len;
+    }
+    count
+}
+
+#[doc(hidden)]
+/// Gets the escape sequence that byte string Debug formatting writes for `b`,
+/// which has a `len` of 0 if the byte is written as is.
+///
+/// This escapes the same bytes as `<[u8]>::escape_ascii` does:
+/// - `\t`, `\r`, `\n`, `\\`, `'`, and `"` are escaped with a backslash.
+/// - bytes that aren't printable ascii are escaped as `\xYY`,
+/// where `YY` is the lowercase hexadecimal value of the byte.
+pub const fn byte_escape(b: u8) -> LenAndArray<[u8; 4]> {
+    let mut array = [b'\\', 0, 0, 0];
+
+    let len = match b {
+        b'\t' | b'\r' | b'\n' | b'\\' | b'\'' | b'"' => {
+            array[1] = match b {
+                b'\t' => b't',
+                b'\r' => b'r',
+                b'\n' => b'n',
+                _ => b,
+            };
+            2
+        }
+        0x20..=0x7E => 0,
+        _ => {
+            array[1] = b'x';
+            array[2] = lower_hex_as_ascii(b >> 4);
+            array[3] = lower_hex_as_ascii(b & 0xF);
+            4
+        }
+    };
+
+    LenAndArray { len, array }
+}
+
+#[doc(hidden)]
+/// Gets the index in `bytes` where zeroes are inserted to pad the integer in `bytes[range]`,
+/// which is after the sign and the `0x`/`0o`/`0b` prefix.
+pub const fn integer_pad_position(bytes: &[u8], range: Range<usize>) -> usize {
+    let Range { mut start, end } = range;
+    if start < end && (bytes[start] == b'-' || bytes[start] == b'+') {
+        start += 1;
+    }
+    if start + 1 < end && bytes[start] == b'0' && matches!(bytes[start + 1], b'x' | b'o' | b'b') {
+        start += 2;
+    }
+    start
+}
+
+#[doc(hidden)]
+/// Gets the index in the utf8 encoded `bytes` after the first `chars` characters,
+/// `bytes.len()` if it has fewer characters.
+pub const fn char_boundary_after(bytes: &[u8], mut chars: usize) -> usize {
+    let mut i = 0;
+    while i < bytes.len() {
+        // Not counting continuation bytes
+        if (bytes[i] as i8) >= -0x40 {
+            if chars == 0 {
+                return i;
+            }
+            chars -= 1;
+        }
+        i += 1;
+    }
+    i
+}
+
+#[doc(hidden)]
+/// Counts the characters in the utf8 encoded `bytes[range]`.
+pub const fn count_chars(bytes: &[u8], range: Range<usize>) -> usize {
+    let Range { mut start, end } = range;
+    let mut count = 0;
+    while start < end {
+        // Not counting continuation bytes
+        count += ((bytes[start] as i8) >= -0x40) as usize;
+        start += 1;
+    }
+    count
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -229,11 +1117,23 @@ pub const fn hex_as_ascii(n: u8) -> u8 {
     }
 }
 
+#[doc(hidden)]
+/// Converts 0..=0xF to its ascii representation of '0'..='9' and 'a'..='f'
+#[inline(always)]
+pub const fn lower_hex_as_ascii(n: u8) -> u8 {
+    if n < 10 {
+        n + b'0'
+    } else {
+        n - 10 + b'a'
+    }
+}
+
 #[doc(hidden)]
 pub const FOR_ESCAPING: &ForEscaping = {
     let mut is_backslash_escaped = 0;
 
     let escaped = [
+        (b'\0', b'0'),
         (b'\t', b't'),
         (b'\n', b'n'),
         (b'\r', b'r'),
@@ -256,8 +1156,8 @@ pub const FOR_ESCAPING: &ForEscaping = {
         escape_char[ei] = escape;
     }
 
-    // Setting all the control characters as being escaped.
-    let is_escaped = is_backslash_escaped | 0xFFFF_FFFF;
+    // Setting all the control characters (including DEL) as being escaped.
+    let is_escaped = is_backslash_escaped | 0xFFFF_FFFF | (1 << 127);
 
     &ForEscaping {
         escape_char,
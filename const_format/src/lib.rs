@@ -13,12 +13,30 @@
 //! These macros are the only things available in Rust 1.46.0:
 //!
 //! - [`concatcp`]:
-//! Concatenates `integers`, `bool`, and `&str` constants into a `&'static str` constant.
+//! Concatenates `integers`, `bool`, `char`, `&str`,
+//! and (with the "float" feature) `f32`/`f64` constants into a `&'static str` constant.
 //!
 //! - [`formatcp`]:
-//! [`format`]-like formatting which takes `integers`, `bool`, and `&str` constants,
+//! [`format`]-like formatting which takes `integers`, `bool`, `char`, `&str`,
+//! and (with the "float" feature) `f32`/`f64` constants,
 //! and emits a `&'static str` constant.
 //!
+//! - [`str_replace`]:
+//! Replaces all the instances of a `&str` or `char` pattern in a `&'static str` constant.
+//!
+//! - [`str_repeat`]:
+//! Repeats a `&'static str` constant a constant number of times.
+//!
+//! - [`str_index`]/[`str_get`]:
+//! Indexes a `&'static str` constant by a range of bytes.
+//!
+//! - [`str_join`]:
+//! Joins an array or slice of `&'static str` constants with a separator.
+//!
+//! - [`str_split`]/[`str_split_whitespace`]/[`str_lines`]:
+//! Splits a `&'static str` constant into an array of `&'static str`,
+//! requires the "rust_1_64" feature.
+//!
 //! ### Rust nightly
 //!
 //! By enabling the "fmt" feature, you can use a [`std::fmt`]-like API.
@@ -26,6 +44,12 @@
 //! This requires the nightly compiler because it uses mutable references in const fn,
 //! which have not been stabilized as of writing these docs.
 //!
+//! The "const_trait_impl" feature allows Debug formatting arrays, slices, tuples,
+//! `Option`s, and `Result`s of any type that implements [`FmtDebug`],
+//! including user-defined types.
+//! Crates that format those types, or use the [`impl_fmt`] and [`ConstDebug`] macros,
+//! need to enable the `const_trait_impl` nightly feature when this feature is enabled.
+//!
 //! All the other features of this crate are implemented on top of the [`const_format::fmt`] API:
 //!
 //! - [`formatc`]:
@@ -35,6 +59,9 @@
 //! [`write`]-like macro that can format many standard library and user defined types
 //! into a type that implements [`WriteMarker`].
 //!
+//! - [`formatted_len`]/[`formatc_writer`]:
+//! For storing the formatted text in an exactly sized [`StrWriter`].
+//!
 //!
 //!
 //! The "derive" feature enables the [`ConstDebug`] macro,
@@ -84,6 +111,7 @@
 #![cfg_attr(feature = "derive", doc = "```rust")]
 #![cfg_attr(not(feature = "derive"), doc = "```ignore")]
 //! #![feature(const_mut_refs)]
+//! # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 //!
 //! use const_format::{ConstDebug, formatc};
 //!
@@ -239,10 +267,24 @@
 //! requires Rust nightly because it uses mutable references in const fn.<br>
 //! This feature includes the `formatc`/`writec` formatting macros.
 //!
+//! - "float": Enables formatting `f32` and `f64` in the `concatcp`/`formatcp` macros,
+//! and (with the "fmt" feature) in the `formatc`/`writec` macros,
+//! requires Rust 1.83.0 because it gets the bits of floats in const fn.
+//!
+//! - "rust_1_64": Enables the `str_split`/`str_split_whitespace`/`str_lines` macros,
+//! requires Rust 1.64.0 because they get subslices of strings in const fn.
+//!
 //! - "derive": implies the "fmt" feature,
 //! provides the `ConstDebug` derive macro to format user-defined types at compile-time.<br>
 //! This implicitly uses the `syn` crate, so clean compiles take a bit longer than without the feature.
 //!
+//! - "const_trait_impl": implies the "fmt" feature.
+//! Enables the [`FmtDebug`] const trait, used to Debug format arrays, slices, tuples,
+//! `Option`s, and `Result`s of any type that implements it.
+//! Crates that use the [`impl_fmt`] and [`ConstDebug`] macros,
+//! or Debug format those generic std types,
+//! need to enable the `const_trait_impl` nightly feature when this feature is enabled.
+//!
 //! - "constant_time_as_str": implies the "fmt" feature.
 //! An optimization that requires a few additional nightly features,
 //! allowing the `as_bytes_alt` methods and `slice_up_to_len_alt` methods to run
@@ -264,6 +306,22 @@
 //!
 //! [`formatcp`]: ./macro.formatcp.html
 //!
+//! [`str_replace`]: ./macro.str_replace.html
+//!
+//! [`str_repeat`]: ./macro.str_repeat.html
+//!
+//! [`str_index`]: ./macro.str_index.html
+//!
+//! [`str_get`]: ./macro.str_get.html
+//!
+//! [`str_join`]: ./macro.str_join.html
+//!
+//! [`str_split`]: ./macro.str_split.html
+//!
+//! [`str_split_whitespace`]: ./macro.str_split_whitespace.html
+//!
+//! [`str_lines`]: ./macro.str_lines.html
+//!
 //! [`format`]: https://doc.rust-lang.org/std/macro.format.html
 //!
 //! [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
@@ -274,6 +332,10 @@
 //!
 //! [`writec`]: ./macro.writec.html
 //!
+//! [`formatted_len`]: ./macro.formatted_len.html
+//!
+//! [`formatc_writer`]: ./macro.formatc_writer.html
+//!
 //! [`write`]: https://doc.rust-lang.org/std/macro.write.html
 //!
 //! [`Formatter`]: ./fmt/struct.Formatter.html
@@ -282,12 +344,17 @@
 //!
 //! [`ConstDebug`]: ./derive.ConstDebug.html
 //!
+//! [`impl_fmt`]: ./macro.impl_fmt.html
+//!
+//! [`FmtDebug`]: ./fmt/trait.FmtDebug.html
+//!
 //! [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
 //!
 //! [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
 //!
 #![no_std]
 #![cfg_attr(feature = "fmt", feature(const_mut_refs))]
+#![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 #![cfg_attr(
     feature = "constant_time_as_str",
     feature(
@@ -311,10 +378,15 @@ include! {"const_debug_derive.rs"}
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "float")]
+mod float;
+
 mod formatting;
 
 mod pargument;
 
+mod str_methods;
+
 #[cfg(feature = "fmt")]
 pub mod utils;
 
@@ -349,6 +421,9 @@ pub use crate::fmt::{Error, Formatter, FormattingFlags, StrWriter, StrWriterMut}
 #[cfg(feature = "fmt")]
 pub use crate::wrapper_types::ascii_str::AsciiStr;
 
+#[cfg(feature = "fmt")]
+pub use crate::wrapper_types::byte_str::ByteStr;
+
 #[cfg(feature = "fmt")]
 pub use crate::wrapper_types::sliced::Sliced;
 
@@ -360,7 +435,9 @@ pub mod pmr {
     pub use const_format_proc_macros::{__concatcp_impl, __formatcp_impl};
 
     #[cfg(feature = "fmt")]
-    pub use const_format_proc_macros::{__formatc_impl, __writec_impl};
+    pub use const_format_proc_macros::{
+        __formatc_impl, __formatc_writer_impl, __formatted_len_impl, __writec_impl,
+    };
 
     pub use core::{
         cmp::Reverse,
@@ -368,10 +445,13 @@ pub mod pmr {
         mem::transmute,
         num::Wrapping,
         ops::Range,
-        option::Option::{None, Some},
+        option::Option::{self, None, Some},
         result::Result::{self, Err, Ok},
     };
 
+    #[cfg(feature = "rust_1_64")]
+    pub use crate::str_methods::{SplitKind, StrSplitArgs};
+
     #[cfg(feature = "fmt")]
     pub use crate::{
         fmt::{ComputeStrLength, Error, Formatter, StrWriter, StrWriterMut},
@@ -380,12 +460,17 @@ pub mod pmr {
         },
     };
 
+    #[cfg(feature = "float")]
+    pub use crate::float::{Float, FmtFloat};
+
     pub use crate::{
         formatting::{
-            hex_as_ascii, ForEscaping, Formatting, FormattingFlags, LenAndArray, NumberFormatting,
-            StartAndArray, FOR_ESCAPING,
+            char_debug, count_chars, debug_escape, decode_utf8, hex_as_ascii, integer_pad_position,
+            Alignment, EncodedChar, ForEscaping, Formatting, FormattingFlags, LenAndArray,
+            NumberFormatting, Padding, StartAndArray, FOR_ESCAPING,
         },
         pargument::{PArgument, PConvWrapper, PVariant},
+        str_methods::{str_join_len, PatternConv, StrIndexArgs, StrIndexArgsConv, StrReplaceArgs},
         wrapper_types::PWrapper,
     };
 }
@@ -15,6 +15,9 @@ mod fmt_macros;
 #[cfg(feature = "fmt")]
 mod impl_fmt;
 
+#[macro_use]
+mod str_methods;
+
 /// For returning early on an error, otherwise evaluating to `()`.
 ///
 /// # Example
@@ -145,6 +148,7 @@ macro_rules! unwrap_or_else {
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{
 ///     for_examples::Unit,
@@ -223,6 +227,7 @@ macro_rules! coerce_to_fmt {
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::StrWriter;
 /// use const_format::{strwriter_as_str, unwrap, writec};
@@ -12,6 +12,8 @@
 ///
 /// - `Option`: to format an `Option` of *any debug type.
 ///
+/// - `Result`: to format a `Result` of *any debug types.
+///
 /// - `newtype`: to format a single field tuple struct (eg: `struct Foo(Bar);`)
 /// which wraps *any debug type.
 ///
@@ -51,6 +53,7 @@
 ///     call_debug_fmt!(array, [Unit, Unit], fmt.field("array") );
 ///     call_debug_fmt!(slice, [0u8, 1], fmt.field("slice") );
 ///     call_debug_fmt!(Option, Some(point), fmt.field("option") );
+///     call_debug_fmt!(Result, Err::<u8, _>(Unit), fmt.field("result") );
 ///     call_debug_fmt!(newtype NumWrapping, Wrapping(255u16), fmt.field("newtype") );
 ///     call_debug_fmt!(std, false, fmt.field("std_") );
 ///     call_debug_fmt!(other, point, fmt.field("other") );
@@ -69,6 +72,7 @@
 ///         array: [Unit, Unit], \
 ///         slice: [0, 1], \
 ///         option: Some(Point3 { x: 5, y: 8, z: 13 }), \
+///         result: Err(Unit), \
 ///         newtype: NumWrapping(255), \
 ///         std_: false, \
 ///         other: Point3 { x: 5, y: 8, z: 13 } \
@@ -84,14 +88,21 @@ macro_rules! call_debug_fmt {
     (array, $expr:expr, $formatter:expr $(,)* ) => {{
         match (&$expr, $formatter.borrow_mutably()) {
             (expr, formatter) => {
-                let mut n = 0;
-                let len = expr.len();
-                let mut f = formatter.debug_list();
-                while n != len {
-                    $crate::__call_debug_fmt_dispatch!(&expr[n], f.entry());
-                    n += 1;
-                }
-                $crate::try_!(f.finish());
+                // Writes the array on one line if it fits in the compact width.
+                let flags = formatter.flags();
+                let flags = if flags.__measures_compact() && {
+                    let mut str_len = $crate::pmr::ComputeStrLength::new();
+                    let f =
+                        &mut formatter.__measuring_formatter(&mut str_len, flags.__single_line());
+                    $crate::__call_debug_fmt_list!(expr, f);
+                    str_len.len() <= flags.compact_width()
+                } {
+                    flags.__single_line()
+                } else {
+                    flags
+                };
+                let f = &mut formatter.make_formatter(flags);
+                $crate::__call_debug_fmt_list!(expr, f);
             }
         }
     }};
@@ -110,6 +121,22 @@ macro_rules! call_debug_fmt {
             }),
         }
     }};
+    (Result, $expr:expr, $formatter:expr $(,)*) => {{
+        match $formatter.borrow_mutably() {
+            formatter => $crate::try_!(match &$expr {
+                $crate::pmr::Ok(x) => {
+                    let mut f = formatter.debug_tuple("Ok");
+                    $crate::__call_debug_fmt_dispatch!(x, f.field());
+                    f.finish()
+                }
+                $crate::pmr::Err(x) => {
+                    let mut f = formatter.debug_tuple("Err");
+                    $crate::__call_debug_fmt_dispatch!(x, f.field());
+                    f.finish()
+                }
+            }),
+        }
+    }};
     (newtype $name:ident, $expr:expr, $formatter:expr $(,)*) => {
         match (&$expr, $formatter.borrow_mutably()) {
             (newtype_, formatter) => {
@@ -131,6 +158,21 @@ macro_rules! call_debug_fmt {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __call_debug_fmt_list {
+    ($expr:expr, $formatter:expr) => {{
+        let mut n = 0;
+        let len = $expr.len();
+        let mut f = $formatter.debug_list();
+        while n != len {
+            $crate::__call_debug_fmt_dispatch!(&$expr[n], f.entry());
+            n += 1;
+        }
+        $crate::try_!(f.finish());
+    }};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __call_debug_fmt_dispatch {
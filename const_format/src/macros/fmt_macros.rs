@@ -16,6 +16,10 @@
 ///
 /// - `bool`
 ///
+/// - `char`
+///
+/// - `f32`/`f64` (requires the "float" feature)
+///
 /// This macro also shares
 /// [the limitations described in here](./index.html#macro-limitations)
 /// as well.
@@ -28,7 +32,7 @@
 /// ```rust
 /// use const_format::concatcp;
 ///
-/// const MSG: &str = concatcp!(2u8, "+", 2u8, "=", 2u8 + 2);
+/// const MSG: &str = concatcp!(2u8, '+', 2u8, "=", 2u8 + 2);
 ///
 /// assert_eq!(MSG, "2+2=4");
 ///
@@ -71,7 +75,7 @@ macro_rules! __concatcp_inner {
         const ARR_LEN: usize = $variables.0;
 
         const CONCAT_ARR: &$crate::pmr::LenAndArray<[u8; ARR_LEN]> = {
-            use $crate::{__write_pvariant, pmr::PVariant};
+            use $crate::{__write_padding, pmr::PVariant};
 
             let mut out = $crate::pmr::LenAndArray {
                 len: 0,
@@ -82,10 +86,36 @@ macro_rules! __concatcp_inner {
 
             $crate::__for_range! { outer_i in 0..input.len() =>
                 let current = &input[outer_i];
+                let start = out.len;
+
+                $crate::__write_pargument!(current => out);
 
-                match current.elem {
-                    PVariant::Str(s) => __write_pvariant!(str, current, s => out),
-                    PVariant::Int(int) => __write_pvariant!(int, current, int => out),
+                let flags = current.fmt_flags;
+                if let (PVariant::Int(_), true) = (&current.elem, flags.sign_plus()) {
+                    let has_minus = out.array[start] == b'-';
+                    __write_padding!(flags.__plus_sign_padding(has_minus), start => out);
+                }
+                if let (PVariant::Int(_), Some(_)) = (&current.elem, flags.precision()) {
+                    let pad_at = $crate::pmr::integer_pad_position(&out.array, start..out.len);
+                    __write_padding!(flags.__precision_padding(out.len - pad_at), pad_at => out);
+                }
+                if flags.width() != 0 {
+                    let (padding, pad_at) = match current.elem {
+                        PVariant::Str(_) | PVariant::Char(_) => (
+                            flags.__padding($crate::pmr::count_chars(&out.array, start..out.len)),
+                            start,
+                        ),
+                        // integers and floats
+                        _ => (
+                            flags.__integer_padding(out.len - start),
+                            if flags.sign_aware_zero_pad() {
+                                $crate::pmr::integer_pad_position(&out.array, start..out.len)
+                            } else {
+                                start
+                            },
+                        ),
+                    };
+                    __write_padding!(padding, pad_at => out);
                 }
             }
             &{ out }
@@ -121,17 +151,26 @@ macro_rules! __concatcp_inner {
 ///
 /// - Use Debug-like formatting (eg: `formatcp!("{:?}", "hello" ):
 /// Similar to how Debug formatting in the standard library works,
-/// except that it does not escape unicode characters.`
+/// escaping strings and characters the same way.`
 ///
 /// - Use Hexsadecimal formatting (eg: `formatcp!("{:x}", "hello" )`):
-/// Formats numbers as capitalized hexadecimal,
-/// The alternate version (written as `"{:#x}"`), prefixes the number with `0x`
+/// Formats numbers as capitalized hexadecimal, `"{:X}"` is equivalent.
+/// The alternate version (written as `"{:#x}"`/`"{:#X}"`), prefixes the number with `0x`
+///
+/// - Use Octal formatting (eg: `formatcp!("{:o}", "hello" )`).
+/// The alternate version (written as `"{:#o}"`), prefixes the number with `0o`
 ///
 /// - Use Binary formatting (eg: `formatcp!("{:b}", "hello" )`).
 /// The alternate version (written as `"{:#b}"`), prefixes the number with `0b`
 ///
 /// - Use Display formatting: `formatcp!("{}", "hello" )`
 ///
+/// - Pad arguments to a minimum width (eg: `formatcp!("{:>8}", "hello" )`),
+/// with the same `[[fill]align][+][#][0][width]` syntax as the standard library.
+///
+/// - Truncate strings, or write integers with a minimum amount of digits
+/// (eg: `formatcp!("{:.3}", "hello" )`).
+///
 ///
 /// # Limitations
 ///
@@ -143,6 +182,10 @@ macro_rules! __concatcp_inner {
 ///
 /// - `bool`
 ///
+/// - `char`
+///
+/// - `f32`/`f64` (requires the "float" feature)
+///
 /// This macro also shares
 /// [the limitations described in here](./index.html#macro-limitations)
 /// as well.
@@ -155,21 +198,105 @@ macro_rules! __concatcp_inner {
 ///
 /// For `&'static str` it does these things:
 /// - Prepend and append the double quote character (`"`).
-/// - Escape the `'\t'`,`'\n'`,`'\r'`,`'\\'`, `'\''`, and`'\"'` characters.
-/// - Escape control characters with `\xYY`,
-/// where `YY` is the hexadecimal value of the control character.
+/// - Escape the `'\0'`, `'\t'`,`'\n'`,`'\r'`,`'\\'`, and`'\"'` characters with a backslash.
+/// - Escape control characters, grapheme extend characters
+/// (eg: combining accents), and other non-printable characters with `\u{YY}`,
+/// where `YY` is the lowercase hexadecimal value of the character.
+///
+/// This escapes the same characters as the standard library does.
 ///
 /// Example:
 /// ```
 /// use const_format::formatcp;
 ///
-/// assert_eq!(formatcp!("{:?}", r#" \ " ó "#), r#"" \\ \" ó ""#);
+/// assert_eq!(formatcp!("{:?}", " \\ \" ó \u{301}\x7F "), r#"" \\ \" ó \u{301}\u{7f} ""#);
+/// ```
+///
+/// For `char` it writes the character between single quotes (`'`),
+/// escaping it the same way as strings, except that `'\''` is escaped instead of `'"'`.
+///
+/// Example:
 /// ```
+/// use const_format::formatcp;
+///
+/// assert_eq!(formatcp!("{:?}{:?}{:?}", 'ñ', '\'', '"'), r#"'ñ''\'''"'"#);
+/// ```
+///
+/// For `f32` and `f64` it works the same as Debug,
+/// writing numbers smaller than `1e-4` or at least `1e16` in exponential notation (eg: `1e20`).
 ///
 /// ### Display
 ///
 /// The `{}`/`{:}` formatter works the same as in [`format`].
 ///
+/// Floats are written with the shortest amount of digits that round trip,
+/// and are never written in exponential notation.
+///
+/// ### Width
+///
+/// A width (eg: `{:8}`) pads the argument with spaces up to that many chars,
+/// right aligning numbers and left aligning everything else.
+///
+/// The fill character and the alignment can be specified before the width,
+/// using `<` for left alignment, `^` for center alignment, and `>` for right alignment.
+///
+/// The `0` flag (eg: `{:08}`) pads numbers with zeroes after the sign.
+///
+/// The `+` flag (eg: `{:+}`, `{:+#010x}`) writes a `+` before non-negative numbers.
+///
+/// Like the standard library, Debug formatted strings and chars aren't padded.
+///
+/// Example:
+/// ```
+/// use const_format::formatcp;
+///
+/// assert_eq!(
+///     formatcp!("[{:8}][{:>8}][{:*^7}][{:04}]", "foo", "bar", 10u8, -3i8),
+///     "[foo     ][     bar][**10***][-003]",
+/// );
+/// ```
+///
+/// ### Precision
+///
+/// A precision (eg: `{:.3}`) truncates Display formatted strings to that many chars,
+/// and makes integers be written with at least that many digits,
+/// padding them with leading zeroes after the sign (and the `0x`/`0o`/`0b` prefix).
+///
+/// This differs from [`format`], where integers ignore the precision.
+///
+/// For floats, the precision is the amount of digits after the decimal point,
+/// the same as in [`format`].
+///
+/// Example:
+/// ```
+/// use const_format::formatcp;
+///
+/// assert_eq!(
+///     formatcp!("[{:.3}][{:>6.2}][{:.4}][{:#.4x}]", "hello", "ñóö", -7i8, 10u8),
+///     "[hel][    ñó][-0007][0x000A]",
+/// );
+/// ```
+///
+/// ### Width and precision arguments
+///
+/// The width and precision can be taken from `usize` arguments,
+/// with the same syntax as [`format`]:
+/// `{:1$}` and `{:.1$}` use a positional argument,
+/// `{:w$}` and `{:.w$}` use a named argument (or a constant from scope),
+/// and `{:.*}` uses the next positional argument as the precision.
+///
+/// Example:
+/// ```
+/// use const_format::formatcp;
+///
+/// const WIDTH: usize = 6;
+///
+/// assert_eq!(
+///     formatcp!("[{:>WIDTH$}][{:^1$}][{:.*}]", "foo", 5usize, 2, "bar"),
+///     "[   foo][  5  ][ba]",
+/// );
+/// ```
+///
 ///
 /// # Examples
 ///
@@ -248,6 +375,7 @@ macro_rules! formatcp {
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::for_examples::Point3;
 /// use const_format::formatc;
@@ -288,6 +416,113 @@ macro_rules! formatc {
     });
 }
 
+/// Computes the length of the string that [`formatc`] outputs for the same arguments,
+/// as a `usize` constant.
+///
+/// This is mostly useful for writing the type of the [`StrWriter`]
+/// returned by [`formatc_writer`].
+///
+/// # Syntax
+///
+/// This macro uses the syntax described in
+/// [the const_format::fmt module](./fmt/index.html#fmtsyntax)
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::for_examples::Point3;
+/// use const_format::formatted_len;
+///
+/// const POINT: Point3 = Point3{x: 8, y: 13, z: 21};
+///
+/// const LEN: usize = formatted_len!("{:?}", POINT);
+///
+/// assert_eq!(LEN, "Point3 { x: 8, y: 13, z: 21 }".len());
+///
+/// let array = [0u8; formatted_len!("{0},{0:x},{0:b}", 10u8)];
+/// assert_eq!(array.len(), "10,a,1010".len());
+///
+/// ```
+///
+/// [`formatc`]: ./macro.formatc.html
+/// [`formatc_writer`]: ./macro.formatc_writer.html
+/// [`StrWriter`]: ./fmt/struct.StrWriter.html
+#[macro_export]
+#[cfg(feature = "fmt")]
+macro_rules! formatted_len {
+    ($format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => (
+        $crate::formatted_len!(
+            @inner
+            (($crate))
+            $format_string
+            $(, $(($expr),)+)?
+        )
+    );
+    (@inner (($path:path)) $($everything:tt)*  ) => ({
+        $crate::pmr::__formatted_len_impl!{
+            (($path))
+            $($everything)*
+        }
+    });
+}
+
+/// Formats constants of standard library and/or user-defined types into a
+/// `StrWriter<[u8; N]>`, where `N` is the exact length of the formatted string.
+///
+/// This allows storing the formatted string in a `static` without guessing its capacity,
+/// using [`formatted_len`] to write the length in its type.
+///
+/// # Syntax
+///
+/// This macro uses the syntax described in
+/// [the const_format::fmt module](./fmt/index.html#fmtsyntax)
+///
+/// # Limitations
+///
+/// This macro has [the limitations described in here](./index.html#macro-limitations).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
+///
+/// use const_format::{StrWriter, formatc_writer, formatted_len};
+///
+/// const PRIMES: &[u16] = &[2, 3, 5, 7, 11];
+///
+/// static MSG: StrWriter<[u8; formatted_len!("primes: {PRIMES:?}")]> =
+///     formatc_writer!("primes: {PRIMES:?}");
+///
+/// let msg: &StrWriter = &MSG;
+///
+/// assert_eq!(msg.as_str(), "primes: [2, 3, 5, 7, 11]");
+/// assert_eq!(msg.capacity(), msg.len());
+///
+/// ```
+///
+/// [`formatted_len`]: ./macro.formatted_len.html
+#[macro_export]
+#[cfg(feature = "fmt")]
+macro_rules! formatc_writer {
+    ($format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => (
+        $crate::formatc_writer!(
+            @inner
+            (($crate))
+            $format_string
+            $(, $(($expr),)+)?
+        )
+    );
+    (@inner (($path:path)) $($everything:tt)*  ) => ({
+        $crate::pmr::__formatc_writer_impl!{
+            (($path))
+            $($everything)*
+        }
+    });
+}
+
 /// Writes some formatted standard library and/or user-defined types into a buffer.
 ///
 /// This macro evaluates to a `Result<(), const_format::Error>` which must be handled.
@@ -322,6 +557,34 @@ macro_rules! formatc {
 /// [This example](#custom-writable-example) below shows how to use this macro
 /// with a custom type.
 ///
+/// # Rolling back
+///
+/// By default, if writing any argument fails,
+/// the text written before it is kept in the writer.
+///
+/// Passing `@rollback` before the writer (eg: `writec!(@rollback, writer, "{}", foo)`)
+/// makes the writer return to the state it was in before the macro was invoked
+/// when an error happens,
+/// using the [`Formatter::checkpoint`] and [`Formatter::rollback`] methods.
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{Error, StrWriter, writec};
+///
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+///
+/// writec!(writer, "{}", "hello")?;
+///
+/// assert_eq!(writec!(@rollback, writer, " {:?}", [3u8, 5, 8, 13]), Err(Error::NotEnoughSpace));
+/// assert_eq!(writer.as_str(), "hello");
+///
+/// assert_eq!(writec!(writer, " {:?}", [3u8, 5, 8, 13]), Err(Error::NotEnoughSpace));
+/// assert_eq!(writer.as_str(), "hello [3, 5, 8, ");
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
 /// # Limitations
 ///
 /// Integer arguments must have a type inferrable from context,
@@ -412,6 +675,8 @@ macro_rules! formatc {
 ///
 ///
 /// [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
+/// [`Formatter::checkpoint`]: ./fmt/struct.Formatter.html#method.checkpoint
+/// [`Formatter::rollback`]: ./fmt/struct.Formatter.html#method.rollback
 ///
 ///
 ///
@@ -419,6 +684,20 @@ macro_rules! formatc {
 #[macro_export]
 #[cfg(feature = "fmt")]
 macro_rules! writec {
+    (
+        @rollback,
+        $writer:expr,
+        $format_string:literal $( $(, $expr:expr )+ )? $(,)?
+    ) => (
+        $crate::writec!(
+            @inner
+            (($crate))
+            ($writer)
+            rollback
+            $format_string
+            $(, $(($expr),)+)?
+        )
+    );
     ( $writer:expr, $format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => (
         $crate::writec!(
             @inner
@@ -19,6 +19,7 @@ macro_rules! __write_pvariant {
         let debug_display;
         let bin;
         let hex;
+        let oct;
 
         let sa: &$crate::pmr::StartAndArray<[_]> = match $parg.fmt {
             $crate::pmr::Formatting::Display => {
@@ -34,10 +35,15 @@ macro_rules! __write_pvariant {
                     bin = wrapper.to_start_array_binary($parg.fmt_flags);
                     &bin
                 }
-                $crate::pmr::NumberFormatting::Hexadecimal => {
+                $crate::pmr::NumberFormatting::Hexadecimal
+                | $crate::pmr::NumberFormatting::LowerHexadecimal => {
                     hex = wrapper.to_start_array_hexadecimal($parg.fmt_flags);
                     &hex
                 }
+                $crate::pmr::NumberFormatting::Octal => {
+                    oct = wrapper.to_start_array_octal($parg.fmt_flags);
+                    &oct
+                }
             },
         };
 
@@ -53,7 +59,8 @@ macro_rules! __write_pvariant {
         let is_display = $parg.fmt.is_display();
         let mut i = 0;
         if is_display {
-            while i < str.len() {
+            let end = $parg.fmt_flags.__str_precision_len(str);
+            while i < end {
                 $out.array[$out.len] = str[i];
                 $out.len += 1;
                 i += 1;
@@ -62,32 +69,115 @@ macro_rules! __write_pvariant {
             $out.array[$out.len] = b'"';
             $out.len += 1;
             while i < str.len() {
-                use $crate::pmr::{hex_as_ascii, ForEscaping, FOR_ESCAPING};
+                let (u, char_len) = $crate::pmr::decode_utf8(str, i, str.len());
+                let escape = $crate::pmr::debug_escape(u, b'"');
 
-                let c = str[i];
-                let mut written_c = c;
-                if c < 128 {
-                    let shifted = 1 << c;
+                let (bytes, start, len): (&[u8], usize, usize) = if escape.len == 0 {
+                    (str, i, char_len)
+                } else {
+                    (&escape.array, 0, escape.len)
+                };
 
-                    if (FOR_ESCAPING.is_escaped & shifted) != 0 {
-                        $out.array[$out.len] = b'\\';
-                        $out.len += 1;
-                        if (FOR_ESCAPING.is_backslash_escaped & shifted) == 0 {
-                            $out.array[$out.len] = b'x';
-                            $out.array[$out.len + 1] = hex_as_ascii(c >> 4);
-                            $out.len += 2;
-                            written_c = hex_as_ascii(c & 0b1111);
-                        } else {
-                            written_c = ForEscaping::get_backslash_escape(c);
-                        };
-                    }
+                let mut j = 0;
+                while j < len {
+                    $out.array[$out.len] = bytes[start + j];
+                    $out.len += 1;
+                    j += 1;
                 }
-                $out.array[$out.len] = written_c;
-                $out.len += 1;
-                i += 1;
+                i += char_len;
             }
             $out.array[$out.len] = b'"';
             $out.len += 1;
         }
     }};
+    (char, $parg:expr, $elem:ident => $out:ident) => {{
+        let encoded;
+        let debug;
+
+        let (bytes, len): (&[u8], usize) = if $parg.fmt.is_display() {
+            encoded = $crate::pmr::EncodedChar::new($elem);
+            let len = $parg.fmt_flags.__char_precision_len(encoded.len);
+            (&encoded.encoded, len)
+        } else {
+            debug = $crate::pmr::char_debug($elem);
+            (&debug.array, debug.len)
+        };
+
+        let mut i = 0;
+        while i < len {
+            $out.array[$out.len] = bytes[i];
+            $out.len += 1;
+            i += 1;
+        }
+    }};
+    (float, $parg:expr, $elem:ident => $out:ident) => {{
+        let float = $crate::pmr::FmtFloat::new($elem, $parg.fmt, $parg.fmt_flags);
+
+        let mut i = 0;
+        while i < float.len {
+            $out.array[$out.len] = float.byte_at(i);
+            $out.len += 1;
+            i += 1;
+        }
+    }};
+}
+
+// The `PVariant::Float` variant only exists with the "float" feature,
+// so the macro that matches on it is defined for each configuration.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "float")]
+macro_rules! __write_pargument {
+    ($parg:ident => $out:ident) => {
+        match $parg.elem {
+            $crate::pmr::PVariant::Str(s) => $crate::__write_pvariant!(str, $parg, s => $out),
+            $crate::pmr::PVariant::Int(int) => $crate::__write_pvariant!(int, $parg, int => $out),
+            $crate::pmr::PVariant::Char(c) => $crate::__write_pvariant!(char, $parg, c => $out),
+            $crate::pmr::PVariant::Float(f) => $crate::__write_pvariant!(float, $parg, f => $out),
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "float"))]
+macro_rules! __write_pargument {
+    ($parg:ident => $out:ident) => {
+        match $parg.elem {
+            $crate::pmr::PVariant::Str(s) => $crate::__write_pvariant!(str, $parg, s => $out),
+            $crate::pmr::PVariant::Int(int) => $crate::__write_pvariant!(int, $parg, int => $out),
+            $crate::pmr::PVariant::Char(c) => $crate::__write_pvariant!(char, $parg, c => $out),
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __write_padding {
+    ($padding:expr, $pad_at:expr => $out:ident) => {{
+        let padding: $crate::pmr::Padding = $padding;
+        let pad_at: usize = $pad_at;
+        let fill = padding.fill;
+        let before = padding.before * fill.len;
+
+        let mut from = $out.len;
+        while from > pad_at {
+            from -= 1;
+            $out.array[from + before] = $out.array[from];
+        }
+
+        let mut written = 0;
+        while written < before {
+            $out.array[pad_at + written] = fill.encoded[written % fill.len];
+            written += 1;
+        }
+        $out.len += before;
+
+        let mut written_after = 0;
+        while written_after < padding.after * fill.len {
+            $out.array[$out.len] = fill.encoded[written_after % fill.len];
+            $out.len += 1;
+            written_after += 1;
+        }
+    }};
 }
@@ -5,6 +5,17 @@
 /// This macro implements [`FormatMarker`] for all the `impl`d types,
 /// and outputs the methods/associated constants in each of the listed impls.
 ///
+/// For the `const_display_fmt` methods that take `&self` and a `&mut Formatter<'_>`,
+/// this also implements the [`FmtDisplay`] trait,
+/// so that the types can be formatted at runtime by wrapping them in [`ConstFmt`].
+///
+/// With the "const_trait_impl" feature,
+/// this also implements the [`FmtDebug`] trait for the `const_debug_fmt` methods,
+/// so that the types can be debug formatted inside of arrays, `Option`s, and `Result`s.
+/// Because [`FmtDebug`] is a const trait,
+/// crates that use this macro need to enable the `const_trait_impl` nightly feature
+/// when the "const_trait_impl" cargo feature is enabled.
+///
 /// # Example
 ///
 /// ### Generic type
@@ -13,6 +24,7 @@
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{Error, Formatter, PWrapper, StrWriter};
 /// use const_format::{formatc, impl_fmt, try_, strwriter_as_str};
@@ -57,6 +69,7 @@
 ///
 /// ```rust
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{Error, Formatter, PWrapper, StrWriter};
 /// use const_format::{formatc, impl_fmt, try_, strwriter_as_str};
@@ -108,6 +121,9 @@
 /// ```
 ///
 /// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
+/// [`FmtDebug`]: ./fmt/trait.FmtDebug.html
+/// [`FmtDisplay`]: ./fmt/trait.FmtDisplay.html
+/// [`ConstFmt`]: ./fmt/struct.ConstFmt.html
 ///
 #[macro_export]
 macro_rules! impl_fmt {
@@ -295,6 +311,103 @@ macro_rules! __impl_fmt_inner {
         {
             $($everything)*
         }
+
+        $crate::__impl_fmt_inner!{
+            @runtime_impls
+            is_std_type = $is_std_type;
+            (
+                $(#[$impl_attr])*
+                impl[$($impl_)*] $type
+                where[ $($where)* ];
+            )
+            ($($everything)*)
+        }
+    };
+
+    (@runtime_impls
+        is_std_type = $is_std_type:ident;
+        (
+            $(#[$impl_attr:meta])*
+            impl[$($impl_:tt)*] $type:ty
+            where[ $($where:tt)* ];
+        )
+        (fn const_debug_fmt (&self, $($param:ident)+ : &mut $($args:tt)*) $($rem:tt)*)
+    )=>{
+        $crate::__impl_fmt_debug_trait!{
+            is_std_type = $is_std_type;
+            $(#[$impl_attr])*
+            impl[$($impl_)*] $type
+            where[ $($where)* ];
+        }
+
+        $crate::__impl_fmt_inner!{
+            @runtime_impls
+            is_std_type = $is_std_type;
+            (
+                $(#[$impl_attr])*
+                impl[$($impl_)*] $type
+                where[ $($where)* ];
+            )
+            ($($rem)*)
+        }
+    };
+    (@runtime_impls
+        is_std_type = $is_std_type:ident;
+        (
+            $(#[$impl_attr:meta])*
+            impl[$($impl_:tt)*] $type:ty
+            where[ $($where:tt)* ];
+        )
+        (fn const_display_fmt (&self, $($param:ident)+ : &mut $($args:tt)*) $($rem:tt)*)
+    )=>{
+        $(#[$impl_attr])*
+        impl<$($impl_)*> $crate::fmt::FmtDisplay for $type
+        where
+            $($where)*
+        {
+            #[inline]
+            fn fmt_display(
+                &self,
+                f: &mut $crate::pmr::Formatter<'_>,
+            ) -> $crate::pmr::Result<(), $crate::pmr::Error> {
+                $crate::__impl_fmt_inner!(@self_expr self, $is_std_type).const_display_fmt(f)
+            }
+        }
+
+        $crate::__impl_fmt_inner!{
+            @runtime_impls
+            is_std_type = $is_std_type;
+            (
+                $(#[$impl_attr])*
+                impl[$($impl_)*] $type
+                where[ $($where)* ];
+            )
+            ($($rem)*)
+        }
+    };
+    (@runtime_impls
+        is_std_type = $is_std_type:ident;
+        $an_impl:tt
+        ($skipped:tt $($rem:tt)*)
+    )=>{
+        $crate::__impl_fmt_inner!{
+            @runtime_impls
+            is_std_type = $is_std_type;
+            $an_impl
+            ($($rem)*)
+        }
+    };
+    (@runtime_impls
+        is_std_type = $is_std_type:ident;
+        $an_impl:tt
+        ()
+    )=>{};
+
+    (@self_expr $self:ident, /*is_std_type*/ true )=>{
+        $crate::pmr::PWrapper(*$self)
+    };
+    (@self_expr $self:ident, /*is_std_type*/ false )=>{
+        $self
     };
 
     (@self_ty $self:ty, /*is_std_type*/ true )=>{
@@ -305,3 +418,39 @@ macro_rules! __impl_fmt_inner {
     };
 
 }
+
+// Implements `FmtDebug` for a type formatted with `impl_fmt`,
+// defined depending on whether the "const_trait_impl" feature is enabled
+// because the impl is expanded in the crate that uses `impl_fmt`.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "const_trait_impl")]
+macro_rules! __impl_fmt_debug_trait {
+    (
+        is_std_type = $is_std_type:ident;
+        $(#[$impl_attr:meta])*
+        impl[$($impl_:tt)*] $type:ty
+        where[ $($where:tt)* ];
+    ) => {
+        $(#[$impl_attr])*
+        impl<$($impl_)*> const $crate::fmt::FmtDebug for $type
+        where
+            $($where)*
+        {
+            #[inline]
+            fn fmt_debug(
+                &self,
+                f: &mut $crate::pmr::Formatter<'_>,
+            ) -> $crate::pmr::Result<(), $crate::pmr::Error> {
+                $crate::__impl_fmt_inner!(@self_expr self, $is_std_type).const_debug_fmt(f)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "const_trait_impl"))]
+macro_rules! __impl_fmt_debug_trait {
+    ($($anything:tt)*) => {};
+}
@@ -0,0 +1,544 @@
+/// Replaces all the instances of `$pattern` in `$input`
+/// (a `&'static str` constant) with `$replace_with` (a `&'static str` constant).
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// fn str_replace(
+///     input: &'static str,
+///     pattern: impl Pattern,
+///     replace_with: &'static str,
+/// ) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `pattern` can be any of these types:
+///
+/// - `&'static str`
+///
+/// - `char`
+///
+/// Like [`str::replace`], an empty `pattern` matches before every char
+/// and at the end of `input`.
+///
+/// This macro works in Rust 1.46.0.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_replace;
+///
+/// const CRATE: &str = "my-crate-name";
+///
+/// const IDENT: &str = str_replace!(CRATE, '-', "_");
+/// assert_eq!(IDENT, "my_crate_name");
+///
+/// const PATH: &str = str_replace!("foo::bar::baz", "::", "/");
+/// assert_eq!(PATH, "foo/bar/baz");
+///
+/// const SPACED: &str = str_replace!("abc", "", " ");
+/// assert_eq!(SPACED, " a b c ");
+///
+/// ```
+///
+/// [`str::replace`]: https://doc.rust-lang.org/std/primitive.str.html#method.replace
+#[macro_export]
+macro_rules! str_replace {
+    ($input:expr, $pattern:expr, $replace_with:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::pmr::StrReplaceArgs = $crate::pmr::StrReplaceArgs::new(
+            $input,
+            $crate::pmr::PatternConv($pattern).conv(),
+            $replace_with,
+        );
+
+        {
+            const OB: usize = ARGS_OSRCTFL4A.replaced_len;
+
+            const OUT_ARR: [u8; OB] = {
+                let args = &ARGS_OSRCTFL4A;
+                let input = args.input.as_bytes();
+                let replace_with = args.replace_with.as_bytes();
+
+                let mut out = [0u8; OB];
+                let mut out_i = 0;
+                let mut copied = 0;
+                let mut search = 0;
+
+                loop {
+                    let (copy_up_to, found) = match args.find(search) {
+                        $crate::pmr::Some(found) => (found, true),
+                        $crate::pmr::None => (input.len(), false),
+                    };
+
+                    $crate::__for_range! { i in copied..copy_up_to =>
+                        out[out_i] = input[i];
+                        out_i += 1;
+                    }
+
+                    if !found {
+                        break;
+                    }
+
+                    $crate::__for_range! { i in 0..replace_with.len() =>
+                        out[out_i] = replace_with[i];
+                        out_i += 1;
+                    }
+
+                    copied = copy_up_to + args.pattern_len();
+                    search = args.next_search(copy_up_to);
+                }
+
+                out
+            };
+
+            $crate::__str_from_utf8_arr!(OUT_ARR)
+        }
+    }};
+}
+
+/// Repeats `$input` (a `&'static str` constant) `$times` times
+/// (`$times` is a `usize` constant).
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// fn str_repeat(input: &'static str, times: usize) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// This macro works in Rust 1.46.0.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{concatcp, str_repeat};
+///
+/// const INDENT: &str = str_repeat!("    ", 2);
+/// assert_eq!(INDENT, "        ");
+///
+/// const TITLE: &str = "Results";
+/// const UNDERLINE: &str = str_repeat!("=", TITLE.len());
+/// assert_eq!(UNDERLINE, "=======");
+///
+/// const SEPARATOR: &str = concatcp!("<", str_repeat!("─┼─", 3), ">");
+/// assert_eq!(SEPARATOR, "<─┼──┼──┼─>");
+///
+/// ```
+#[macro_export]
+macro_rules! str_repeat {
+    ($input:expr, $times:expr $(,)*) => {{
+        const INPUT_OSRCTFL4A: &str = $input;
+        const TIMES_OSRCTFL4A: usize = $times;
+
+        {
+            const OB: usize = INPUT_OSRCTFL4A.len() * TIMES_OSRCTFL4A;
+
+            const OUT_ARR: [u8; OB] = {
+                let input = INPUT_OSRCTFL4A.as_bytes();
+
+                let mut out = [0u8; OB];
+                let mut out_i = 0;
+
+                $crate::__for_range! { _repetition in 0..TIMES_OSRCTFL4A =>
+                    $crate::__for_range! { i in 0..input.len() =>
+                        out[out_i] = input[i];
+                        out_i += 1;
+                    }
+                }
+
+                out
+            };
+
+            $crate::__str_from_utf8_arr!(OUT_ARR)
+        }
+    }};
+}
+
+/// Indexes a `&'static str` constant by a range of bytes.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait RangeBounds {}
+/// fn str_index(input: &'static str, range: impl RangeBounds) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `range` can be any range type from [`core::ops`] with `usize` bounds
+/// (eg: `3..8`, `3..`, `..=8`, `..`).
+///
+/// This macro works in Rust 1.46.0.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `range` is out of bounds,
+/// its start is after its end,
+/// or if it doesn't start and end on char boundaries.
+///
+/// For a version of this macro that returns `None` in those cases,
+/// there's the [`str_get`] macro.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_index;
+///
+/// const PATH: &str = "my_crate::foo::bar";
+///
+/// const CRATE: &str = str_index!(PATH, ..8);
+/// assert_eq!(CRATE, "my_crate");
+///
+/// const LAST: &str = str_index!(PATH, PATH.len() - 3..);
+/// assert_eq!(LAST, "bar");
+///
+/// const NANDU: &str = str_index!("¡ñandú!", 2..=8);
+/// assert_eq!(NANDU, "ñandú");
+///
+/// ```
+///
+/// [`core::ops`]: https://doc.rust-lang.org/core/ops/index.html
+/// [`str_get`]: ./macro.str_get.html
+#[macro_export]
+macro_rules! str_index {
+    ($input:expr, $range:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::pmr::StrIndexArgs =
+            $crate::pmr::StrIndexArgsConv($input, $range).conv();
+
+        {
+            const OB: usize = ARGS_OSRCTFL4A.len_or_error();
+
+            $crate::__str_index_inner!(ARGS_OSRCTFL4A, OB)
+        }
+    }};
+}
+
+/// Indexes a `&'static str` constant by a range of bytes,
+/// returning `None` if the range is invalid.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait RangeBounds {}
+/// fn str_get(input: &'static str, range: impl RangeBounds) -> Option<&'static str>
+/// # {None}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `range` can be any range type from [`core::ops`] with `usize` bounds
+/// (eg: `3..8`, `3..`, `..=8`, `..`).
+///
+/// This returns `None` if `range` is out of bounds,
+/// its start is after its end,
+/// or if it doesn't start and end on char boundaries.
+///
+/// This macro works in Rust 1.46.0.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_get;
+///
+/// const PATH: &str = "my_crate::foo::bar";
+///
+/// const CRATE: Option<&str> = str_get!(PATH, ..8);
+/// assert_eq!(CRATE, Some("my_crate"));
+///
+/// const OUT_OF_BOUNDS: Option<&str> = str_get!(PATH, 10..100);
+/// assert_eq!(OUT_OF_BOUNDS, None);
+///
+/// // The 1 index is inside of the `ñ` char.
+/// const INSIDE_CHAR: Option<&str> = str_get!("ñandú", 1..);
+/// assert_eq!(INSIDE_CHAR, None);
+///
+/// ```
+///
+/// [`core::ops`]: https://doc.rust-lang.org/core/ops/index.html
+#[macro_export]
+macro_rules! str_get {
+    ($input:expr, $range:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::pmr::StrIndexArgs =
+            $crate::pmr::StrIndexArgsConv($input, $range).conv();
+
+        {
+            const OB: usize = ARGS_OSRCTFL4A.end - ARGS_OSRCTFL4A.start;
+
+            const OUT_OPT: $crate::pmr::Option<&str> = if ARGS_OSRCTFL4A.is_valid {
+                $crate::pmr::Some($crate::__str_index_inner!(ARGS_OSRCTFL4A, OB))
+            } else {
+                $crate::pmr::None
+            };
+            OUT_OPT
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __str_index_inner {
+    ($args:ident, $out_len:ident) => {{
+        const OUT_ARR: [u8; $out_len] = {
+            let input = $args.input.as_bytes();
+
+            let mut out = [0u8; $out_len];
+
+            $crate::__for_range! { i in 0..$out_len =>
+                out[i] = input[$args.start + i];
+            }
+
+            out
+        };
+
+        $crate::__str_from_utf8_arr!(OUT_ARR)
+    }};
+}
+
+/// Joins `$strings` (an array or slice of `&'static str` constants)
+/// with `$separator` (a `&'static str` constant) between each string.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// fn str_join(strings: &'static [&'static str], separator: &'static str) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// `strings` can also be an array of `&'static str`.
+///
+/// To join a list of types other than `&str`,
+/// you can use the [`Formatter::join`] method in a formatting method,
+/// which requires the "fmt" feature.
+///
+/// This macro works in Rust 1.46.0.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_join;
+///
+/// const NAMES: &[&str] = &["foo", "bar", "baz"];
+///
+/// const JOINED: &str = str_join!(NAMES, ", ");
+/// assert_eq!(JOINED, "foo, bar, baz");
+///
+/// const PATH: &str = str_join!(["my_crate", "foo", "bar"], "::");
+/// assert_eq!(PATH, "my_crate::foo::bar");
+///
+/// const NO_NAMES: &[&str] = &[];
+/// const EMPTY: &str = str_join!(NO_NAMES, ", ");
+/// assert_eq!(EMPTY, "");
+///
+/// ```
+///
+/// [`Formatter::join`]: ./fmt/struct.Formatter.html#method.join
+#[macro_export]
+macro_rules! str_join {
+    ($strings:expr, $separator:expr $(,)*) => {{
+        const STRINGS_OSRCTFL4A: &[&str] = &$strings;
+        const SEPARATOR_OSRCTFL4A: &str = $separator;
+
+        {
+            const OB: usize = $crate::pmr::str_join_len(STRINGS_OSRCTFL4A, SEPARATOR_OSRCTFL4A);
+
+            const OUT_ARR: [u8; OB] = {
+                let strings = STRINGS_OSRCTFL4A;
+                let separator = SEPARATOR_OSRCTFL4A.as_bytes();
+
+                let mut out = [0u8; OB];
+                let mut out_i = 0;
+
+                $crate::__for_range! { outer_i in 0..strings.len() =>
+                    if outer_i != 0 {
+                        $crate::__for_range! { i in 0..separator.len() =>
+                            out[out_i] = separator[i];
+                            out_i += 1;
+                        }
+                    }
+
+                    let string = strings[outer_i].as_bytes();
+                    $crate::__for_range! { i in 0..string.len() =>
+                        out[out_i] = string[i];
+                        out_i += 1;
+                    }
+                }
+
+                out
+            };
+
+            $crate::__str_from_utf8_arr!(OUT_ARR)
+        }
+    }};
+}
+
+/// Splits `$input` (a `&'static str` constant) on every occurrence of `$delimiter`,
+/// evaluating to an array of `&'static str`.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// # const N: usize = 0;
+/// fn str_split(input: &'static str, delimiter: impl Pattern) -> [&'static str; N]
+/// # {[]}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `delimiter` can be any of these types:
+///
+/// - `&'static str`
+///
+/// - `char`
+///
+/// and `N` is the amount of strings that `input` is split into.
+///
+/// This is equivalent to [`str::split`], including for empty delimiters,
+/// which split the string before every char and at its end.
+///
+/// This macro requires the "rust_1_64" feature.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_split;
+///
+/// const FEATURES: &str = "fmt,float,derive";
+///
+/// const LIST: [&str; 3] = str_split!(FEATURES, ',');
+/// assert_eq!(LIST, ["fmt", "float", "derive"]);
+///
+/// const PATH: &[&str] = &str_split!("my_crate::foo::bar", "::");
+/// assert_eq!(PATH, ["my_crate", "foo", "bar"]);
+///
+/// const EMPTY: &[&str] = &str_split!("", "::");
+/// assert_eq!(EMPTY, [""]);
+///
+/// ```
+///
+/// [`str::split`]: https://doc.rust-lang.org/std/primitive.str.html#method.split
+#[cfg(feature = "rust_1_64")]
+#[macro_export]
+macro_rules! str_split {
+    ($input:expr, $delimiter:expr $(,)*) => {
+        $crate::__str_split_inner!(
+            $input,
+            $crate::pmr::SplitKind::Pattern($crate::pmr::PatternConv($delimiter).conv())
+        )
+    };
+}
+
+/// Splits `$input` (a `&'static str` constant) on whitespace,
+/// evaluating to an array of the non-empty `&'static str`s between the whitespace.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # const N: usize = 0;
+/// fn str_split_whitespace(input: &'static str) -> [&'static str; N]
+/// # {[]}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `N` is the amount of strings that `input` is split into.
+///
+/// This is equivalent to [`str::split_whitespace`],
+/// whitespace is any char with the `White_Space` unicode property.
+///
+/// This macro requires the "rust_1_64" feature.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_split_whitespace;
+///
+/// const WORDS: [&str; 3] = str_split_whitespace!("  foo\tbar\n\n baz ");
+/// assert_eq!(WORDS, ["foo", "bar", "baz"]);
+///
+/// ```
+///
+/// [`str::split_whitespace`]:
+/// https://doc.rust-lang.org/std/primitive.str.html#method.split_whitespace
+#[cfg(feature = "rust_1_64")]
+#[macro_export]
+macro_rules! str_split_whitespace {
+    ($input:expr $(,)*) => {
+        $crate::__str_split_inner!($input, $crate::pmr::SplitKind::Whitespace)
+    };
+}
+
+/// Splits `$input` (a `&'static str` constant) into lines,
+/// evaluating to an array of `&'static str`.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # const N: usize = 0;
+/// fn str_lines(input: &'static str) -> [&'static str; N]
+/// # {[]}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `N` is the amount of lines in `input`.
+///
+/// This is equivalent to [`str::lines`],
+/// lines end with either `"\n"` or `"\r\n"`, which aren't included in the lines,
+/// and the final line ending is optional.
+///
+/// This macro requires the "rust_1_64" feature.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_lines;
+///
+/// const LINES: [&str; 3] = str_lines!("foo\r\nbar\n\n");
+/// assert_eq!(LINES, ["foo", "bar", ""]);
+///
+/// ```
+///
+/// [`str::lines`]: https://doc.rust-lang.org/std/primitive.str.html#method.lines
+#[cfg(feature = "rust_1_64")]
+#[macro_export]
+macro_rules! str_lines {
+    ($input:expr $(,)*) => {
+        $crate::__str_split_inner!($input, $crate::pmr::SplitKind::Lines)
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "rust_1_64")]
+#[macro_export]
+macro_rules! __str_split_inner {
+    ($input:expr, $kind:expr) => {{
+        const ARGS_OSRCTFL4A: $crate::pmr::StrSplitArgs =
+            $crate::pmr::StrSplitArgs::new($input, $kind);
+
+        {
+            const OUT: [&str; ARGS_OSRCTFL4A.count] = ARGS_OSRCTFL4A.split_into();
+            OUT
+        }
+    }};
+}
+
+/// Converts a constant array of utf8 encoded bytes to a `&'static str`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __str_from_utf8_arr {
+    ($arr:ident) => {{
+        // `str::from_utf8_unchecked` can't be called in const contexts in Rust 1.46.0
+        #[allow(clippy::transmute_bytes_to_str)]
+        const OUT_STR: &str = unsafe { $crate::pmr::transmute::<&[u8], &str>(&$arr) };
+        OUT_STR
+    }};
+}
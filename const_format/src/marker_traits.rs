@@ -9,6 +9,9 @@ mod write_marker;
 
 #[doc(inline)]
 pub use self::{
-    format_marker::{FormatMarker, IsAFormatMarker, IsArrayKind, IsNotStdKind, IsStdKind},
+    format_marker::{
+        FormatMarker, IsAFormatMarker, IsArrayKind, IsNotStdKind, IsOptionKind, IsResultKind,
+        IsStdKind,
+    },
     write_marker::{IsAStrWriter, IsAWriteMarker, IsNotAStrWriter, WriteMarker},
 };
@@ -95,11 +95,17 @@ pub trait FormatMarker {
     ///
     /// - [`IsArrayKind`]: For slices, and arrays.
     ///
+    /// - [`IsOptionKind`]: For `Option`s.
+    ///
+    /// - [`IsResultKind`]: For `Result`s.
+    ///
     /// - [`IsStdKind`]: Any other standard library type.
     ///
     /// - [`IsNotStdKind`]: Any type that is not from the standard library.
     ///
     /// [`IsArrayKind`]: ./struct.IsArrayKind.html
+    /// [`IsOptionKind`]: ./struct.IsOptionKind.html
+    /// [`IsResultKind`]: ./struct.IsResultKind.html
     /// [`IsStdKind`]: ./struct.IsStdKind.html
     /// [`IsNotStdKind`]: ./struct.IsNotStdKind.html
     type Kind;
@@ -117,6 +123,30 @@ pub trait FormatMarker {
 ///
 pub struct IsArrayKind<T>(PhantomData<T>);
 
+/// Marker type for `Option`s,
+/// used as the [`Kind`] associated type  in [`FormatMarker`].
+///
+/// The formatting macros debug format the `Some` variant of an `Option<T>`
+/// with the [`FmtDebug`] impl of `T`.
+///
+/// [`Kind`]: ./trait.FormatMarker.html#associatedtype.Kind
+/// [`FormatMarker`]: ./trait.FormatMarker.html
+/// [`FmtDebug`]: ../fmt/trait.FmtDebug.html
+///
+pub struct IsOptionKind<T>(PhantomData<T>);
+
+/// Marker type for `Result`s,
+/// used as the [`Kind`] associated type  in [`FormatMarker`].
+///
+/// The formatting macros debug format the `Ok` and `Err` variants of a `Result<T, E>`
+/// with the [`FmtDebug`] impls of `T` and `E`.
+///
+/// [`Kind`]: ./trait.FormatMarker.html#associatedtype.Kind
+/// [`FormatMarker`]: ./trait.FormatMarker.html
+/// [`FmtDebug`]: ../fmt/trait.FmtDebug.html
+///
+pub struct IsResultKind<T, E>(PhantomData<(T, E)>);
+
 /// Marker type for the remaining standard library types,,
 /// used as the [`Kind`] associated type  in [`FormatMarker`].
 ///
@@ -175,11 +205,18 @@ macro_rules! std_kind_impls {
 ///
 /// - [`IsArrayKind`]: the reference is coerced to a slice, and wrapped in a [`PWrapper`].
 ///
-/// - [`IsStdKind`]: the referenced value is copied, and wrapped in a [`PWrapper`].
+/// - [`IsOptionKind`]: the reference is wrapped in a [`PWrapper`].
+///
+/// - [`IsResultKind`]: the reference is wrapped in a [`PWrapper`].
+///
+/// - [`IsStdKind`]: the referenced value is copied, and wrapped in a [`PWrapper`],
+/// except for `str`s and tuples, whose reference is wrapped in a [`PWrapper`].
 ///
 /// - [`IsNotStdKind`]: the reference is simply returned as a `&T`.
 ///
 /// [`IsArrayKind`]: ./struct.IsArrayKind.html
+/// [`IsOptionKind`]: ./struct.IsOptionKind.html
+/// [`IsResultKind`]: ./struct.IsResultKind.html
 /// [`IsStdKind`]: ./struct.IsStdKind.html
 /// [`IsNotStdKind`]: ./struct.IsNotStdKind.html
 ///
@@ -246,19 +283,24 @@ impl<T: ?Sized, R: ?Sized> IsAFormatMarker<IsNotStdKind, T, R> {
     }
 }
 
-/////////////////////////////////////////////////////////////////////////////
+impl<U, T: ?Sized, R: ?Sized> IsAFormatMarker<IsOptionKind<U>, T, R> {
+    /// Wraps the reference to the `Option` in a `PWrapper`
+    #[inline(always)]
+    pub const fn coerce(self, opt: &Option<U>) -> PWrapper<&Option<U>> {
+        PWrapper(opt)
+    }
+}
 
-macro_rules! array_impls {
-    ($($len:literal),* $(,)* ) => (
-        $(
-            impl<T> FormatMarker for [T; $len] {
-                type Kind = IsArrayKind<T>;
-                type This = Self;
-            }
-        )*
-    )
+impl<U, E, T: ?Sized, R: ?Sized> IsAFormatMarker<IsResultKind<U, E>, T, R> {
+    /// Wraps the reference to the `Result` in a `PWrapper`
+    #[inline(always)]
+    pub const fn coerce(self, res: &Result<U, E>) -> PWrapper<&Result<U, E>> {
+        PWrapper(res)
+    }
 }
 
+/////////////////////////////////////////////////////////////////////////////
+
 std_kind_impls! {
     i8, u8,
     i16, u16,
@@ -266,7 +308,12 @@ std_kind_impls! {
     i64, u64,
     i128, u128,
     isize, usize,
-    bool,
+    bool, char,
+}
+
+#[cfg(feature = "float")]
+std_kind_impls! {
+    f32, f64,
 }
 
 impl FormatMarker for str {
@@ -282,11 +329,19 @@ impl<R: ?Sized> IsAFormatMarker<IsStdKind, str, R> {
     }
 }
 
-array_impls! {
-    0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,
-    16,17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,
-    32,33,34,35,36,37,38,39,40,41,42,43,44,45,46,47,
-    48,49,50,51,52,53,54,55,56,57,58,59,60,61,62,63,
+impl<T, const N: usize> FormatMarker for [T; N] {
+    type Kind = IsArrayKind<T>;
+    type This = Self;
+}
+
+impl<T> FormatMarker for Option<T> {
+    type Kind = IsOptionKind<T>;
+    type This = Self;
+}
+
+impl<T, E> FormatMarker for Result<T, E> {
+    type Kind = IsResultKind<T, E>;
+    type This = Self;
 }
 
 impl<T> FormatMarker for [T] {
@@ -83,4 +83,8 @@ type_level_error! {
     NotAscii => input_text_was_not_ascii<>,
 
     NotOnCharBoundary => NotOnCharBoundary<>,
+
+    FmtWrite => cannot_write_into_core_fmt_Write_at_compile_time<>,
+
+    UnmeasuredPadding => cannot_write_the_padding_before_the_text_into_a_FmtWriter<>,
 }
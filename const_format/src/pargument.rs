@@ -1,5 +1,10 @@
+#[cfg(feature = "float")]
+use crate::float::{Float, FmtFloat};
+
 use crate::{
-    formatting::{Formatting, FormattingFlags},
+    formatting::{
+        char_debug, count_chars, count_debug_chars, EncodedChar, Formatting, FormattingFlags,
+    },
     wrapper_types::PWrapper,
 };
 
@@ -16,6 +21,9 @@ pub struct PArgument {
 pub enum PVariant {
     Str(&'static str),
     Int(Integer),
+    Char(char),
+    #[cfg(feature = "float")]
+    Float(Float),
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -73,11 +81,13 @@ macro_rules! pconvwrapper_impls {
             #[doc(hidden)]
             impl PConvWrapper<$Signed> {
                 pub const fn $method(self, fmt_flags: FormattingFlags)->PArgument{
+                    let len = $crate::pmr::PWrapper(self.0).$called(fmt_flags);
+                    let int = self.to_integer();
                     PArgument {
-                        fmt_len: $crate::pmr::PWrapper(self.0).$called(fmt_flags),
+                        fmt_len: fmt_flags.__integer_len($formatting, len, int.is_negative),
                         fmt: $formatting,
                         fmt_flags,
-                        elem: PVariant::Int(self.to_integer()),
+                        elem: PVariant::Int(int),
                     }
                 }
             }
@@ -85,11 +95,13 @@ macro_rules! pconvwrapper_impls {
             #[doc(hidden)]
             impl PConvWrapper<$Unsigned> {
                 pub const fn $method(self, fmt_flags: FormattingFlags)->PArgument{
+                    let len = $crate::pmr::PWrapper(self.0).$called(fmt_flags);
+                    let int = self.to_integer();
                     PArgument {
-                        fmt_len: $crate::pmr::PWrapper(self.0).$called(fmt_flags),
+                        fmt_len: fmt_flags.__integer_len($formatting, len, int.is_negative),
                         fmt: $formatting,
                         fmt_flags,
-                        elem: PVariant::Int(self.to_integer()),
+                        elem: PVariant::Int(int),
                     }
                 }
             }
@@ -109,9 +121,8 @@ pconvwrapper_impls! {
 #[doc(hidden)]
 impl PConvWrapper<bool> {
     #[inline]
-    pub const fn to_pargument_display(self, _: FormattingFlags) -> PArgument {
-        PConvWrapper(if self.0 { "true" } else { "false" })
-            .to_pargument_display(FormattingFlags::DEFAULT)
+    pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+        PConvWrapper(if self.0 { "true" } else { "false" }).to_pargument_display(fmt_flags)
     }
     #[inline]
     pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
@@ -119,12 +130,77 @@ impl PConvWrapper<bool> {
     }
 }
 
+#[doc(hidden)]
+impl PConvWrapper<char> {
+    #[inline]
+    pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+        let len = fmt_flags.__char_precision_len(EncodedChar::new(self.0).len);
+        PArgument {
+            fmt_len: fmt_flags.__padded_len(len, (len != 0) as usize),
+            fmt_flags,
+            fmt: Formatting::Display,
+            elem: PVariant::Char(self.0),
+        }
+    }
+    #[inline]
+    pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
+        // Debug formatted chars aren't padded, like in std.
+        let fmt_flags = fmt_flags.set_width(0);
+        let debug = char_debug(self.0);
+        PArgument {
+            fmt_len: fmt_flags.__padded_len(debug.len, count_chars(&debug.array, 0..debug.len)),
+            fmt_flags,
+            fmt: Formatting::Debug,
+            elem: PVariant::Char(self.0),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+macro_rules! pconvwrapper_float_impls {
+    ($($ty:ident => $constructor:ident),* $(,)*) => (
+        $(
+            #[doc(hidden)]
+            impl PConvWrapper<$ty> {
+                #[inline]
+                pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+                    float_pargument(Float::$constructor(self.0), Formatting::Display, fmt_flags)
+                }
+                #[inline]
+                pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
+                    float_pargument(Float::$constructor(self.0), Formatting::Debug, fmt_flags)
+                }
+            }
+        )*
+    )
+}
+
+#[cfg(feature = "float")]
+pconvwrapper_float_impls! {
+    f32 => from_f32,
+    f64 => from_f64,
+}
+
+#[cfg(feature = "float")]
+const fn float_pargument(float: Float, fmt: Formatting, fmt_flags: FormattingFlags) -> PArgument {
+    let len = FmtFloat::new(float, fmt, fmt_flags).len;
+    PArgument {
+        fmt_len: len + fmt_flags.__integer_padding(len).len(),
+        fmt_flags,
+        fmt,
+        elem: PVariant::Float(float),
+    }
+}
+
 #[doc(hidden)]
 impl PConvWrapper<&'static str> {
     #[inline]
     pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+        let bytes = self.0.as_bytes();
+        let len = fmt_flags.__str_precision_len(bytes);
+        let chars = count_chars(bytes, 0..len);
         PArgument {
-            fmt_len: PWrapper(self.0).compute_display_len(fmt_flags),
+            fmt_len: fmt_flags.__padded_len(len, chars),
             fmt_flags,
             fmt: Formatting::Display,
             elem: PVariant::Str(self.0),
@@ -132,8 +208,13 @@ impl PConvWrapper<&'static str> {
     }
     #[inline]
     pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
+        // Debug formatted strings aren't padded, like in std.
+        let fmt_flags = fmt_flags.set_width(0);
+        let bytes = self.0.as_bytes();
+        let len = PWrapper(self.0).compute_debug_len(fmt_flags);
+        let chars = count_debug_chars(bytes, 0..bytes.len());
         PArgument {
-            fmt_len: PWrapper(self.0).compute_debug_len(fmt_flags),
+            fmt_len: fmt_flags.__padded_len(len, chars),
             fmt_flags,
             fmt: Formatting::Debug,
             elem: PVariant::Str(self.0),
@@ -0,0 +1,29 @@
+//! Implementation details of the macros that operate on `&'static str` constants,
+//! like [`str_replace`].
+//!
+//! The macros evaluate the methods here in `const` items,
+//! and most of them write the output bytes into an exactly sized array,
+//! which is why they don't require `&mut` in `const fn`s.
+//!
+//! [`str_replace`]: ../macro.str_replace.html
+
+mod pattern;
+
+mod str_indexing;
+
+mod str_join;
+
+mod str_replace;
+
+#[cfg(feature = "rust_1_64")]
+mod str_split;
+
+pub use self::{
+    pattern::{Pattern, PatternConv},
+    str_indexing::{StrIndexArgs, StrIndexArgsConv},
+    str_join::str_join_len,
+    str_replace::StrReplaceArgs,
+};
+
+#[cfg(feature = "rust_1_64")]
+pub use self::str_split::{SplitKind, StrSplitArgs};
@@ -0,0 +1,93 @@
+use crate::formatting::EncodedChar;
+
+/// A pattern that can be searched for in a string.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum Pattern {
+    Str(&'static str),
+    Char(EncodedChar),
+}
+
+/// Converts the pattern argument of the string macros into a [`Pattern`],
+/// the pattern can be a `&'static str` or a `char`.
+///
+/// [`Pattern`]: ./enum.Pattern.html
+#[doc(hidden)]
+pub struct PatternConv<P>(pub P);
+
+impl PatternConv<&'static str> {
+    pub const fn conv(self) -> Pattern {
+        Pattern::Str(self.0)
+    }
+}
+
+impl PatternConv<char> {
+    pub const fn conv(self) -> Pattern {
+        Pattern::Char(EncodedChar::new(self.0))
+    }
+}
+
+impl Pattern {
+    /// The pattern as bytes, only the first `self.len()` bytes are part of it.
+    pub const fn as_bytes(&self) -> &[u8] {
+        match self {
+            Pattern::Str(s) => s.as_bytes(),
+            Pattern::Char(c) => &c.encoded,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        match self {
+            Pattern::Str(s) => s.len(),
+            Pattern::Char(c) => c.len,
+        }
+    }
+
+    /// Finds the start of the first occurrence of the pattern in `input`,
+    /// at or after `from`.
+    ///
+    /// Like the pattern methods of `str`, an empty pattern matches at every char boundary,
+    /// including the end of the input.
+    pub const fn find_in(&self, input: &[u8], from: usize) -> Option<usize> {
+        let pattern = self.as_bytes();
+        let pattern_len = self.len();
+
+        if pattern_len == 0 {
+            return if from <= input.len() {
+                Some(from)
+            } else {
+                None
+            };
+        }
+
+        let mut start = from;
+        while start + pattern_len <= input.len() {
+            let mut i = 0;
+            while i < pattern_len && input[start + i] == pattern[i] {
+                i += 1;
+            }
+            if i == pattern_len {
+                return Some(start);
+            }
+            start += 1;
+        }
+        None
+    }
+
+    /// Where to search for the next occurrence of the pattern in `input`,
+    /// after finding one that starts at `found`.
+    pub const fn next_search(&self, input: &[u8], found: usize) -> usize {
+        let pattern_len = self.len();
+        if pattern_len != 0 {
+            return found + pattern_len;
+        }
+
+        // Skipping the char after an empty match,
+        // so that it's not matched again.
+        let mut next = found + 1;
+        while next < input.len() && (input[next] & 0b1100_0000) == 0b1000_0000 {
+            next += 1;
+        }
+        next
+    }
+}
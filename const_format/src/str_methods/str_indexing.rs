@@ -0,0 +1,122 @@
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// The arguments of the `str_index` and `str_get` macros,
+/// with the range converted to a `start..end` range of bytes.
+#[doc(hidden)]
+pub struct StrIndexArgs {
+    pub input: &'static str,
+    pub start: usize,
+    pub end: usize,
+    pub is_valid: bool,
+    pub error: Option<IndexError>,
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IndexError {
+    OutOfBounds,
+    StartAfterEnd,
+    NotOnCharBoundary,
+}
+
+/// Converts the arguments of the `str_index` and `str_get` macros into a [`StrIndexArgs`],
+/// the range can be any of the range types from `core::ops` with `usize` bounds.
+///
+/// [`StrIndexArgs`]: ./struct.StrIndexArgs.html
+#[doc(hidden)]
+pub struct StrIndexArgsConv<R>(pub &'static str, pub R);
+
+impl StrIndexArgsConv<Range<usize>> {
+    pub const fn conv(self) -> StrIndexArgs {
+        StrIndexArgs::new(self.0, self.1.start, Some(self.1.end))
+    }
+}
+
+impl StrIndexArgsConv<RangeFrom<usize>> {
+    pub const fn conv(self) -> StrIndexArgs {
+        StrIndexArgs::new(self.0, self.1.start, Some(self.0.len()))
+    }
+}
+
+impl StrIndexArgsConv<RangeTo<usize>> {
+    pub const fn conv(self) -> StrIndexArgs {
+        StrIndexArgs::new(self.0, 0, Some(self.1.end))
+    }
+}
+
+impl StrIndexArgsConv<RangeFull> {
+    pub const fn conv(self) -> StrIndexArgs {
+        StrIndexArgs::new(self.0, 0, Some(self.0.len()))
+    }
+}
+
+impl StrIndexArgsConv<RangeInclusive<usize>> {
+    pub const fn conv(self) -> StrIndexArgs {
+        StrIndexArgs::new(self.0, *self.1.start(), inclusive_end(*self.1.end()))
+    }
+}
+
+impl StrIndexArgsConv<RangeToInclusive<usize>> {
+    pub const fn conv(self) -> StrIndexArgs {
+        StrIndexArgs::new(self.0, 0, inclusive_end(self.1.end))
+    }
+}
+
+/// Converts an inclusive end to an exclusive one, returning None on overflow.
+const fn inclusive_end(end: usize) -> Option<usize> {
+    if end == usize::MAX {
+        None
+    } else {
+        Some(end + 1)
+    }
+}
+
+const fn is_char_boundary(bytes: &[u8], index: usize) -> bool {
+    index == bytes.len() || (bytes[index] & 0b1100_0000) != 0b1000_0000
+}
+
+impl StrIndexArgs {
+    /// Constructs a `StrIndexArgs`, `end` is `None` if the range has an end of `usize::MAX + 1`.
+    const fn new(input: &'static str, start: usize, end: Option<usize>) -> Self {
+        let bytes = input.as_bytes();
+
+        let error = match end {
+            None => Some(IndexError::OutOfBounds),
+            Some(end) if start > bytes.len() || end > bytes.len() => Some(IndexError::OutOfBounds),
+            Some(end) if start > end => Some(IndexError::StartAfterEnd),
+            Some(end) if !is_char_boundary(bytes, start) || !is_char_boundary(bytes, end) => {
+                Some(IndexError::NotOnCharBoundary)
+            }
+            Some(_) => None,
+        };
+
+        let (start, end, is_valid) = match (error, end) {
+            (None, Some(end)) => (start, end, true),
+            _ => (0, 0, false),
+        };
+
+        Self {
+            input,
+            start,
+            end,
+            is_valid,
+            error,
+        }
+    }
+
+    /// The length of the indexed string,
+    /// causing a compile-time error if the range is invalid.
+    pub const fn len_or_error(&self) -> usize {
+        if let Some(error) = self.error {
+            let i = 1 + error as usize;
+            let _: &str = match error {
+                IndexError::OutOfBounds => ["The range is out of bounds of the string"][i],
+                IndexError::StartAfterEnd => ["The start of the range is after its end"][i],
+                IndexError::NotOnCharBoundary => {
+                    ["The range doesn't start and end on char boundaries"][i]
+                }
+            };
+        }
+        self.end - self.start
+    }
+}
@@ -0,0 +1,15 @@
+/// The length of `strings` joined with `separator` between each string.
+#[doc(hidden)]
+pub const fn str_join_len(strings: &[&str], separator: &str) -> usize {
+    if strings.is_empty() {
+        return 0;
+    }
+
+    let mut len = separator.len() * (strings.len() - 1);
+    let mut i = 0;
+    while i < strings.len() {
+        len += strings[i].len();
+        i += 1;
+    }
+    len
+}
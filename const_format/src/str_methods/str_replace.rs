@@ -0,0 +1,48 @@
+use super::Pattern;
+
+/// The arguments of the `str_replace` macro.
+#[doc(hidden)]
+pub struct StrReplaceArgs {
+    pub input: &'static str,
+    pub pattern: Pattern,
+    pub replace_with: &'static str,
+    /// The length of `input` after all the occurrences of `pattern` were replaced.
+    pub replaced_len: usize,
+}
+
+impl StrReplaceArgs {
+    pub const fn new(input: &'static str, pattern: Pattern, replace_with: &'static str) -> Self {
+        let mut this = Self {
+            input,
+            pattern,
+            replace_with,
+            replaced_len: 0,
+        };
+
+        let mut matches = 0;
+        let mut search = 0;
+        while let Some(found) = this.find(search) {
+            matches += 1;
+            search = this.next_search(found);
+        }
+
+        this.replaced_len =
+            input.len() - matches * this.pattern_len() + matches * replace_with.len();
+        this
+    }
+
+    pub const fn pattern_len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    /// Finds the start of the first occurrence of the pattern at or after `from`.
+    pub const fn find(&self, from: usize) -> Option<usize> {
+        self.pattern.find_in(self.input.as_bytes(), from)
+    }
+
+    /// Where to search for the next occurrence of the pattern,
+    /// after finding one that starts at `found`.
+    pub const fn next_search(&self, found: usize) -> usize {
+        self.pattern.next_search(self.input.as_bytes(), found)
+    }
+}
@@ -0,0 +1,200 @@
+use super::Pattern;
+
+use crate::formatting::decode_utf8;
+
+/// The arguments of the `str_split`, `str_split_whitespace`, and `str_lines` macros.
+#[doc(hidden)]
+pub struct StrSplitArgs {
+    pub input: &'static str,
+    pub kind: SplitKind,
+    /// The amount of strings that `input` is split into.
+    pub count: usize,
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum SplitKind {
+    /// Splits on every occurrence of the pattern, like `str::split`.
+    Pattern(Pattern),
+    /// Splits on whitespace, skipping empty strings, like `str::split_whitespace`.
+    Whitespace,
+    /// Splits on line endings, like `str::lines`.
+    Lines,
+}
+
+/// Where to continue splitting the input.
+#[derive(Copy, Clone)]
+struct SplitState {
+    /// Where the next string starts
+    start: usize,
+    /// Where to start searching for the end of the next string,
+    /// which is only different from `start` after matching an empty pattern.
+    search: usize,
+    finished: bool,
+}
+
+impl StrSplitArgs {
+    pub const fn new(input: &'static str, kind: SplitKind) -> Self {
+        let mut this = Self {
+            input,
+            kind,
+            count: 0,
+        };
+
+        let mut state = SplitState::NEW;
+        while let Some((_, _, next)) = this.next_str(state) {
+            this.count += 1;
+            state = next;
+        }
+
+        this
+    }
+
+    /// Splits the input into an array with the `count` strings.
+    pub const fn split_into<const N: usize>(&self) -> [&'static str; N] {
+        let mut out = [""; N];
+
+        let mut i = 0;
+        let mut state = SplitState::NEW;
+        while let Some((start, end, next)) = self.next_str(state) {
+            out[i] = substr(self.input, start, end);
+            i += 1;
+            state = next;
+        }
+
+        out
+    }
+
+    /// Returns the range of the next string, and the state after it.
+    const fn next_str(&self, state: SplitState) -> Option<(usize, usize, SplitState)> {
+        if state.finished {
+            return None;
+        }
+        match self.kind {
+            SplitKind::Pattern(pattern) => self.next_pattern_str(pattern, state),
+            SplitKind::Whitespace => self.next_non_whitespace_str(state),
+            SplitKind::Lines => self.next_line(state),
+        }
+    }
+
+    const fn next_pattern_str(
+        &self,
+        pattern: Pattern,
+        state: SplitState,
+    ) -> Option<(usize, usize, SplitState)> {
+        let input = self.input.as_bytes();
+
+        Some(match pattern.find_in(input, state.search) {
+            Some(found) => {
+                let next = SplitState {
+                    start: found + pattern.len(),
+                    search: pattern.next_search(input, found),
+                    finished: false,
+                };
+                (state.start, found, next)
+            }
+            None => (state.start, input.len(), SplitState::FINISHED),
+        })
+    }
+
+    const fn next_non_whitespace_str(
+        &self,
+        state: SplitState,
+    ) -> Option<(usize, usize, SplitState)> {
+        let input = self.input.as_bytes();
+
+        let mut start = state.start;
+        while start < input.len() {
+            let (c, len) = decode_utf8(input, start, input.len());
+            if !is_whitespace(c) {
+                break;
+            }
+            start += len;
+        }
+
+        if start == input.len() {
+            return None;
+        }
+
+        let mut end = start;
+        while end < input.len() {
+            let (c, len) = decode_utf8(input, end, input.len());
+            if is_whitespace(c) {
+                break;
+            }
+            end += len;
+        }
+
+        Some((start, end, SplitState::at(end)))
+    }
+
+    const fn next_line(&self, state: SplitState) -> Option<(usize, usize, SplitState)> {
+        let input = self.input.as_bytes();
+        let start = state.start;
+
+        if start == input.len() {
+            return None;
+        }
+
+        let mut end = start;
+        while end < input.len() && input[end] != b'\n' {
+            end += 1;
+        }
+
+        if end == input.len() {
+            return Some((start, end, SplitState::at(end)));
+        }
+
+        let next = SplitState::at(end + 1);
+        if start < end && input[end - 1] == b'\r' {
+            end -= 1;
+        }
+        Some((start, end, next))
+    }
+}
+
+impl SplitState {
+    const NEW: Self = Self::at(0);
+
+    const FINISHED: Self = Self {
+        start: 0,
+        search: 0,
+        finished: true,
+    };
+
+    const fn at(start: usize) -> Self {
+        Self {
+            start,
+            search: start,
+            finished: false,
+        }
+    }
+}
+
+/// A const equivalent of `&s[start..end]`, which requires that the range is valid.
+const fn substr(s: &'static str, start: usize, end: usize) -> &'static str {
+    // Safety: `start..end` is in bounds and on char boundaries,
+    // because it's computed by searching utf8 encoded patterns and chars.
+    unsafe {
+        let bytes = core::slice::from_raw_parts(s.as_ptr().add(start), end - start);
+        core::str::from_utf8_unchecked(bytes)
+    }
+}
+
+/// Whether `c` has the `White_Space` unicode property, like `char::is_whitespace`.
+const fn is_whitespace(c: u32) -> bool {
+    matches!(
+        c,
+        0x09..=0x0D
+            | 0x20
+            | 0x85
+            | 0xA0
+            | 0x1680
+            | 0x2000..=0x200A
+            | 0x2028
+            | 0x2029
+            | 0x202F
+            | 0x205F
+            | 0x3000
+    )
+}
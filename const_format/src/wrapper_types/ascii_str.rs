@@ -1,7 +1,10 @@
 // use crate::fmt::Error;
 
 #[cfg(feature = "fmt")]
-use crate::fmt::{Error, Formatter};
+use crate::{
+    fmt::{Error, Formatter},
+    wrapper_types::ByteStr,
+};
 
 use core::fmt::{self, Display};
 
@@ -156,6 +159,28 @@ impl<'a> AsciiStr<'a> {
     pub fn as_str(self) -> &'a str {
         unsafe { core::str::from_utf8_unchecked(self.0) }
     }
+
+    /// Wraps the bytes of this `AsciiStr` in a [`ByteStr`],
+    /// to Debug format them as a byte string literal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{ascii_str, formatc};
+    ///
+    /// const FMT: &str = formatc!("{:?}", ascii_str!("foo\r\n").as_byte_str());
+    ///
+    /// assert_eq!(FMT, r#"b"foo\r\n""#);
+    /// ```
+    ///
+    /// [`ByteStr`]: ./struct.ByteStr.html
+    #[cfg(feature = "fmt")]
+    #[inline(always)]
+    pub const fn as_byte_str(self) -> ByteStr<'a> {
+        ByteStr(self.0)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -308,7 +333,7 @@ mod tests {
                     foo,
                     writer,
                     flag,
-                    "\"\\x00\\x10hello\\tworld\\n\"",
+                    "\"\\0\\u{10}hello\\tworld\\n\"",
                     foo.as_str(),
                 );
             }
@@ -0,0 +1,102 @@
+use crate::fmt::{Error, Formatter};
+
+/// Wrapper for Debug formatting a byte slice as a byte string literal (eg: `b"foo\n"`),
+/// instead of a list of numbers.
+///
+/// This escapes `\t`, `\r`, `\n`, `\\`, `'`, and `"` with a backslash,
+/// and every byte that isn't printable ascii as `\xYY`,
+/// the same as `<[u8]>::escape_ascii` does.
+///
+/// The bytes of an [`AsciiStr`] can be formatted this way by wrapping them with its
+/// [`as_byte_str`] method.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{ByteStr, ascii_str, formatc};
+///
+/// const REQUEST: &[u8] = b"GET /\r\n\x00";
+///
+/// const FMT: &str = formatc!("{:?}", ByteStr(REQUEST));
+/// assert_eq!(FMT, r#"b"GET /\r\n\x00""#);
+///
+/// const ASCII: &str = formatc!("{:?}", ascii_str!("\"hello\"\n").as_byte_str());
+/// assert_eq!(ASCII, r#"b"\"hello\"\n""#);
+///
+/// ```
+///
+/// [`AsciiStr`]: ./struct.AsciiStr.html
+/// [`as_byte_str`]: ./struct.AsciiStr.html#method.as_byte_str
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ByteStr<'a>(pub &'a [u8]);
+
+impl_fmt! {
+    impl ByteStr<'_>;
+
+    /// Debug formats the wrapped bytes as a byte string literal.
+    #[inline]
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_bytes_debug(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fmt::{ComputeStrLength, FormattingFlags, StrWriter};
+
+    use arrayvec::ArrayString;
+
+    use core::fmt::Write;
+
+    const fn inner(bytes: ByteStr<'_>, writer: &mut StrWriter) -> Result<usize, Error> {
+        try_!(bytes.const_debug_fmt(&mut writer.make_formatter(FormattingFlags::NEW)));
+
+        let mut str_len = ComputeStrLength::new();
+        try_!(bytes.const_debug_fmt(&mut str_len.make_formatter(FormattingFlags::NEW)));
+
+        Ok(str_len.len())
+    }
+
+    #[test]
+    fn formatting() {
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 1024]);
+        let mut expected = ArrayString::<[u8; 1024]>::new();
+
+        let mut all_bytes = [0u8; 256];
+        for (i, b) in all_bytes.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        let cases: &[&[u8]] = &[
+            b"",
+            b"hello",
+            b"GET /\r\n\x00",
+            b"'\"\\\t\x7f\x80\xff",
+            &all_bytes,
+        ];
+
+        for bytes in cases.iter().copied() {
+            writer.clear();
+            let len = inner(ByteStr(bytes), writer).unwrap();
+
+            expected.clear();
+            write!(expected, "b\"{}\"", bytes.escape_ascii()).unwrap();
+            assert_eq!(writer.as_str(), expected.as_str());
+            assert_eq!(writer.len(), len, "{}", writer.as_str());
+        }
+    }
+
+    #[test]
+    fn not_enough_space() {
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 8]);
+        inner(ByteStr(b"\x00\x01"), writer).unwrap_err();
+        assert_eq!(writer.as_str(), "");
+
+        inner(ByteStr(b"\x00"), writer).unwrap();
+        assert_eq!(writer.as_str(), r#"b"\x00""#);
+    }
+}
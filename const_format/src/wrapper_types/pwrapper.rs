@@ -1,8 +1,17 @@
 use crate::{
-    formatting::{FormattingFlags, NumberFormatting, StartAndArray, FOR_ESCAPING},
+    formatting::{
+        byte_escape, char_debug, debug_escape, decode_utf8, EncodedChar, FormattingFlags,
+        NumberFormatting, StartAndArray,
+    },
     pargument::Integer,
 };
 
+#[cfg(feature = "float")]
+use crate::{
+    float::{Float, FmtFloat},
+    formatting::Formatting,
+};
+
 use core::ops::Range;
 
 #[cfg(test)]
@@ -25,8 +34,14 @@ mod tests;
 ///
 /// Note that this type does not implement the formatting methods for all std types,
 /// it can't implement them for std types which wrap non-std types,
-/// and for Options/arrays/slices it only does it with
-/// integer, and `&str` type parameters.
+/// and for references to slices/Options it only does it for those of std types.
+///
+/// With the "const_trait_impl" feature,
+/// references to slices/Options/Results are supported with any
+/// type parameters that implement [`FmtDebug`],
+/// so slices can be nested to any depth (eg: `&[[[u8; 2]; 3]]`),
+/// and their elements can be `Option`s/`Result`s/tuples, or user defined types
+/// that implement [`FmtDebug`] with the [`impl_fmt`] or [`ConstDebug`] macros.
 ///
 /// You can use the [`call_debug_fmt`] macro to format arrays/slices/Options of
 /// any type that can be const debug formatted.
@@ -39,6 +54,7 @@ mod tests;
 #[cfg_attr(feature = "fmt", doc = "```rust")]
 #[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
 /// #![feature(const_mut_refs)]
+/// # #![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 ///
 /// use const_format::{Error, Formatter, PWrapper};
 /// use const_format::{impl_fmt, formatc, try_};
@@ -72,6 +88,9 @@ mod tests;
 /// ```
 ///
 /// [`call_debug_fmt`]: ./macro.call_debug_fmt.html
+/// [`FmtDebug`]: ./fmt/trait.FmtDebug.html
+/// [`impl_fmt`]: ./macro.impl_fmt.html
+/// [`ConstDebug`]: ./derive.ConstDebug.html
 /// [`writec`]: ./macro.writec.html
 ///
 #[derive(Copy, Clone)]
@@ -98,6 +117,17 @@ macro_rules! compute_hex_count {
         }) + with_0x
     }};
 }
+macro_rules! compute_octal_count {
+    ($bits:expr, $int:expr, $with_0o:expr) => {{
+        let with_0o = ($with_0o as usize) << 1;
+        let i = ($bits - $int.leading_zeros()) as usize;
+        (if i == 0 {
+            1
+        } else {
+            (i / 3) + ((i % 3) != 0) as usize
+        }) + with_0o
+    }};
+}
 macro_rules! compute_binary_count {
     ($bits:expr, $int:expr, $with_0b:expr) => {{
         let with_0b = ($with_0b as usize) << 1;
@@ -140,8 +170,10 @@ macro_rules! impl_number_of_digits {
                 match fmt.num_fmt() {
                     NumberFormatting::Decimal=>
                         self.compute_display_len(fmt),
-                    NumberFormatting::Hexadecimal=>
+                    NumberFormatting::Hexadecimal | NumberFormatting::LowerHexadecimal=>
                         compute_hex_count!($bits, self.0, fmt.is_alternate()),
+                    NumberFormatting::Octal=>
+                        compute_octal_count!($bits, self.0, fmt.is_alternate()),
                     NumberFormatting::Binary=>
                         compute_binary_count!($bits, self.0, fmt.is_alternate()),
                 }
@@ -153,6 +185,12 @@ macro_rules! impl_number_of_digits {
                 compute_hex_count!($bits, self.0, fmt.is_alternate())
             }
 
+            /// Computes how long much space is necessary to
+            /// write this integer as an octal literal.
+            pub const fn octal_len(self, fmt: FormattingFlags)-> usize {
+                compute_octal_count!($bits, self.0, fmt.is_alternate())
+            }
+
             /// Computes how long much space is necessary to
             /// write this integer as a binary literal.
             pub const fn binary_len(self, fmt: FormattingFlags)-> usize {
@@ -258,6 +296,13 @@ macro_rules! impl_for_xsize {
                 PWrapper(self.0 as $XWord).hexadecimal_len(fmt)
             }
 
+            /// Computes how long much space is necessary to
+            /// write this integer as an octal literal.
+            #[inline(always)]
+            pub const fn octal_len(self, fmt: FormattingFlags) -> usize {
+                PWrapper(self.0 as $XWord).octal_len(fmt)
+            }
+
             /// Computes how long much space is necessary to
             /// write this integer as a binary literal.
             #[inline(always)]
@@ -345,12 +390,17 @@ impl PWrapper<Integer> {
             array: [0u8; 34],
         };
 
+        let letter_start = match flags.num_fmt() {
+            NumberFormatting::LowerHexadecimal => b'a',
+            _ => b'A',
+        };
+
         loop {
             out.start -= 1;
             let digit = (n & 0xF) as u8;
             out.array[out.start] = match digit {
                 0..=9 => b'0' + digit,
-                _ => b'A' - 10 + digit,
+                _ => letter_start - 10 + digit,
             };
             n = n >> 4;
             if n == 0 {
@@ -368,6 +418,40 @@ impl PWrapper<Integer> {
         out
     }
 
+    pub const fn to_start_array_octal(self, flags: FormattingFlags) -> StartAndArray<[u8; 45]> {
+        let mut n = if self.0.is_negative {
+            self.0.as_negative() as u128
+        } else {
+            self.0.unsigned
+        };
+
+        n &= *self.0.mask;
+
+        let mut out = StartAndArray {
+            start: 45,
+            array: [0u8; 45],
+        };
+
+        loop {
+            out.start -= 1;
+            let digit = (n & 0o7) as u8;
+            out.array[out.start] = b'0' + digit;
+            n = n >> 3;
+            if n == 0 {
+                break;
+            }
+        }
+
+        if flags.is_alternate() {
+            out.start -= 1;
+            out.array[out.start] = b'o';
+            out.start -= 1;
+            out.array[out.start] = b'0';
+        }
+
+        out
+    }
+
     pub const fn to_start_array_display(self) -> StartAndArray<[u8; 40]> {
         let mut out = StartAndArray {
             start: 40,
@@ -410,22 +494,31 @@ impl PWrapper<&[u8]> {
     /// Computes how much space is necessary to write `&self.0[range]` as a utf8 string,
     /// with debug formatting
     pub const fn compute_utf8_debug_len_in_range(self, mut range: Range<usize>) -> usize {
-        let mut sum = range.end - range.start;
+        let mut sum = 2; // The quote characters
         while range.start < range.end {
-            let c = self.0[range.start];
-            if c < 128 {
-                let shifted = 1 << c;
-                if (FOR_ESCAPING.is_escaped & shifted) != 0 {
-                    sum += if (FOR_ESCAPING.is_backslash_escaped & shifted) == 0 {
-                        3 // `\x01` only add 3 characters
-                    } else {
-                        1 // Escaped with a backslash
-                    };
-                }
-            }
-            range.start += 1;
+            let (u, char_len) = decode_utf8(self.0, range.start, range.end);
+            let escape_len = debug_escape(u, b'"').len;
+            sum += if escape_len == 0 {
+                char_len
+            } else {
+                escape_len
+            };
+            range.start += char_len;
         }
-        sum + 2 // The quote characters
+        sum
+    }
+
+    /// Computes how much space is necessary to write the wrapped `&[u8]`
+    /// as a Debug formatted byte string (eg: `b"foo\n"`).
+    pub const fn compute_byte_str_debug_len(self) -> usize {
+        let mut sum = 3; // The `b` prefix and the quote characters
+        let mut i = 0;
+        while i < self.0.len() {
+            let escape_len = byte_escape(self.0[i]).len;
+            sum += if escape_len == 0 { 1 } else { escape_len };
+            i += 1;
+        }
+        sum
     }
 }
 
@@ -445,6 +538,53 @@ impl PWrapper<&str> {
     }
 }
 
+impl PWrapper<char> {
+    /// Computes how much space is necessary to write a `char` with debug formatting
+    #[inline(always)]
+    #[doc(hidden)]
+    pub const fn compute_debug_len(self, _: FormattingFlags) -> usize {
+        char_debug(self.0).len
+    }
+
+    /// Computes how much space is necessary to write a `char` with display formatting
+    #[inline(always)]
+    #[doc(hidden)]
+    pub const fn compute_display_len(self, _: FormattingFlags) -> usize {
+        EncodedChar::new(self.0).len
+    }
+}
+
+#[cfg(feature = "float")]
+macro_rules! float_len_methods {
+    ($($ty:ident => $constructor:ident),* $(,)*) => (
+        $(
+            impl PWrapper<$ty> {
+                /// Computes how much space is necessary to write a float with debug formatting,
+                /// not including the padding.
+                #[inline(always)]
+                #[doc(hidden)]
+                pub const fn compute_debug_len(self, fmt: FormattingFlags) -> usize {
+                    FmtFloat::new(Float::$constructor(self.0), Formatting::Debug, fmt).len
+                }
+
+                /// Computes how much space is necessary to write a float with display formatting,
+                /// not including the padding.
+                #[inline(always)]
+                #[doc(hidden)]
+                pub const fn compute_display_len(self, fmt: FormattingFlags) -> usize {
+                    FmtFloat::new(Float::$constructor(self.0), Formatting::Display, fmt).len
+                }
+            }
+        )*
+    )
+}
+
+#[cfg(feature = "float")]
+float_len_methods! {
+    f32 => from_f32,
+    f64 => from_f64,
+}
+
 #[cfg(feature = "fmt")]
 const _: () = {
     use crate::marker_traits::{FormatMarker, IsNotStdKind};
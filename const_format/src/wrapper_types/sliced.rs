@@ -139,42 +139,37 @@ mod tests {
                 writer,
                 $str_val,
                 2..,
-                "\t3456789\x06\x07;\"\\t3456789\\x06\\x07\""
+                "\t3456789\x06\x07;\"\\t3456789\\u{6}\\u{7}\""
             );
 
             test_case!(
                 writer,
                 $str_val,
                 ..,
-                "\x00\n\t3456789\x06\x07;\"\\x00\\n\\t3456789\\x06\\x07\""
+                "\x00\n\t3456789\x06\x07;\"\\0\\n\\t3456789\\u{6}\\u{7}\""
             );
 
-            test_case!(
-                writer,
-                $str_val,
-                ..9,
-                "\x00\n\t345678;\"\\x00\\n\\t345678\""
-            );
+            test_case!(writer, $str_val, ..9, "\x00\n\t345678;\"\\0\\n\\t345678\"");
 
             test_case!(writer, $str_val, 2..=9, "\t3456789;\"\\t3456789\"");
             test_case!(
                 writer,
                 $str_val,
                 2..=!0,
-                "\t3456789\x06\x07;\"\\t3456789\\x06\\x07\""
+                "\t3456789\x06\x07;\"\\t3456789\\u{6}\\u{7}\""
             );
 
             test_case!(
                 writer,
                 $str_val,
                 ..=9,
-                "\x00\n\t3456789;\"\\x00\\n\\t3456789\""
+                "\x00\n\t3456789;\"\\0\\n\\t3456789\""
             );
             test_case!(
                 writer,
                 $str_val,
                 ..=!0,
-                "\x00\n\t3456789\x06\x07;\"\\x00\\n\\t3456789\\x06\\x07\""
+                "\x00\n\t3456789\x06\x07;\"\\0\\n\\t3456789\\u{6}\\u{7}\""
             );
         };
     }
@@ -0,0 +1,293 @@
+use const_format::{
+    fmt::{Alignment, Error, FmtWriter, Formatter, FormattingFlags, StrWriter},
+    for_examples::Unit,
+    impl_fmt, try_,
+    wrapper_types::PWrapper,
+    writec,
+};
+
+use core::fmt::Write;
+
+struct Foo {
+    x: u32,
+    y: &'static str,
+    z: &'static [u8],
+    w: (i16, char),
+}
+
+impl Foo {
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_struct("Foo");
+        try_!(PWrapper(self.x).const_debug_fmt(f.field("x")));
+        try_!(PWrapper(self.y).const_debug_fmt(f.field("y")));
+        try_!(PWrapper(self.z).const_debug_fmt(f.field("z")));
+        let mut w = f.field("w").debug_tuple("");
+        try_!(PWrapper(self.w.0).const_debug_fmt(w.field()));
+        try_!(PWrapper(self.w.1).const_debug_fmt(w.field()));
+        try_!(w.finish());
+        f.finish()
+    }
+}
+
+const FOO: Foo = Foo {
+    x: 100,
+    y: "hello\n\u{301}world, ñandú \"quoted\" and a string that's longer than 32 bytes",
+    z: b"\x00bytes\xff\"that are longer than 32 bytes\t",
+    w: (-5, '\''),
+};
+
+fn assert_same_output(fmt: impl Fn(&mut Formatter<'_>) -> Result<(), Error>) {
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 1024]);
+
+    let flag_list = [
+        FormattingFlags::NEW,
+        FormattingFlags::NEW.set_alternate(true),
+        FormattingFlags::NEW.set_hexadecimal(),
+        FormattingFlags::NEW.set_alternate(true).set_binary(),
+        FormattingFlags::NEW.set_width(7),
+        FormattingFlags::NEW
+            .set_width(9)
+            .set_alignment(Alignment::Center)
+            .set_fill('ñ'),
+        FormattingFlags::NEW
+            .set_width(6)
+            .set_sign_aware_zero_pad(true),
+        FormattingFlags::NEW.set_sign_plus(true).set_precision(4),
+    ];
+
+    for &flags in &flag_list {
+        writer.clear();
+        fmt(&mut writer.make_formatter(flags)).unwrap();
+
+        let mut string = String::new();
+        let mut fmt_writer = FmtWriter::new(&mut string);
+        fmt_writer.write_with(flags, &fmt).unwrap();
+
+        assert_eq!(fmt_writer.len(), writer.len());
+        assert_eq!(string, writer.as_str(), "flags: {:?}", flags);
+    }
+}
+
+#[test]
+fn same_as_str_writer() {
+    assert_same_output(|f| FOO.const_debug_fmt(f));
+    assert_same_output(|f| PWrapper(FOO.y).const_display_fmt(f));
+    assert_same_output(|f| PWrapper(-1234i32).const_display_fmt(f));
+    assert_same_output(|f| PWrapper(255u8).const_debug_fmt(f));
+    #[cfg(feature = "float")]
+    assert_same_output(|f| PWrapper(1.25f64).const_display_fmt(f));
+    assert_same_output(|f| PWrapper('ñ').const_display_fmt(f));
+    assert_same_output(|f| PWrapper(&[3u8, 5, 8][..]).const_debug_fmt(f));
+    assert_same_output(|f| f.write_ascii_repeated(b'-', 40));
+    assert_same_output(|f| f.write_str_range("foo bar baz", 4..7));
+    assert_same_output(|f| f.write_str_range_debug("foo\nbar baz", 0..7));
+    assert_same_output(|f| f.write_bytes_debug(&[0; 40]));
+}
+
+#[test]
+fn padded_arguments() {
+    let mut string = String::new();
+    let mut writer = FmtWriter::new(&mut string);
+
+    writer
+        .write_with(FormattingFlags::NEW, |f| {
+            writec!(
+                f,
+                "{:>8}|{:^8}|{:<8}|{:*>5?}|{:08.3}|{:+}",
+                "right",
+                "center",
+                "left",
+                (),
+                -15i8,
+                3u8
+            )
+        })
+        .unwrap();
+
+    assert_eq!(string, "   right| center |left    |***()|-0000015|+3");
+
+    string.clear();
+    let mut writer = FmtWriter::new(&mut string);
+    writer
+        .write_with(FormattingFlags::NEW, |f| {
+            writec!(f, "{:>6?}|{:^7?}|{:<6?}", Unit, Unit, Unit)
+        })
+        .unwrap();
+    // Like std's derived Debug impls, `Unit` ignores the width
+    assert_eq!(string, "Unit|Unit|Unit");
+}
+
+struct Pair(u32, u32);
+
+impl_fmt! {
+    impl[] Pair;
+
+    const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        writec!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl Pair {
+    // Pads itself with `f`'s flags, like the formatting macros do.
+    pub const fn fmt_padded(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let start = f.padding_start();
+        try_!(self.const_display_fmt(f));
+        f.pad_since(start)
+    }
+
+    pub const fn fmt_padded_for(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let start = match f.padding_start_for(6) {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+        try_!(self.const_display_fmt(f));
+        f.pad_since(start)
+    }
+}
+
+#[test]
+fn padded_user_types() {
+    const PAIR: Pair = Pair(3, 5);
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    writec!(
+        writer,
+        "{:>10}|{:^11}|{:<10}|{:*^3}",
+        PAIR,
+        PAIR,
+        PAIR,
+        PAIR
+    )
+    .unwrap();
+    assert_eq!(writer.as_str(), "    (3, 5)|  (3, 5)   |(3, 5)    |(3, 5)");
+
+    let mut string = String::new();
+    let mut fmt_writer = FmtWriter::new(&mut string);
+    fmt_writer
+        .write_with(FormattingFlags::NEW, |f| {
+            writec!(f, "{:>10}|{:^11}|{:<10}|{:*^3}", PAIR, PAIR, PAIR, PAIR)
+        })
+        .unwrap();
+    assert_eq!(string, writer.as_str());
+
+    for &alignment in &[Alignment::Left, Alignment::Right, Alignment::Center] {
+        let flags = FormattingFlags::NEW.set_width(11).set_alignment(alignment);
+
+        writer.clear();
+        PAIR.fmt_padded(&mut writer.make_formatter(flags)).unwrap();
+
+        let mut string = String::new();
+        let mut fmt_writer = FmtWriter::new(&mut string);
+        fmt_writer
+            .write_with(flags, |f| PAIR.fmt_padded_for(f))
+            .unwrap();
+        assert_eq!(string, writer.as_str(), "{:?}", alignment);
+
+        // The padding before the text can't be written after the text
+        let mut string = String::new();
+        let mut fmt_writer = FmtWriter::new(&mut string);
+        let res = fmt_writer.write_with(flags, |f| PAIR.fmt_padded(f));
+        if let Alignment::Left = alignment {
+            res.unwrap();
+            assert_eq!(string, writer.as_str());
+        } else {
+            assert_eq!(res, Err(Error::UnmeasuredPadding));
+            assert_eq!(string, "");
+        }
+    }
+}
+
+#[test]
+fn longer_than_a_window() {
+    // 3 byte chars, so that the windows end in the middle of some of them.
+    let long_string = "foo€".repeat(3000);
+    let long_str = long_string.as_str();
+    let long_list = [0xFFFF_FFFFu32; 2000];
+
+    let mut expected = String::new();
+    write!(
+        expected,
+        "{:?}|{:#X?}|{:>20000}",
+        long_str,
+        &long_list[..],
+        long_str
+    )
+    .unwrap();
+
+    let mut string = String::new();
+    let mut writer = FmtWriter::new(&mut string);
+    let mut calls = 0;
+    writer
+        .write_with(FormattingFlags::NEW, |f| {
+            calls += 1;
+            writec!(
+                f,
+                "{:?}|{:#X?}|{:>20000}",
+                long_str,
+                &long_list[..],
+                long_str
+            )
+        })
+        .unwrap();
+
+    assert_eq!(writer.len(), expected.len());
+    assert_eq!(string, expected);
+    assert!(calls > 1, "{}", calls);
+}
+
+#[test]
+fn rollback() {
+    const fn list_or_count(list: &[u32], f: &mut Formatter<'_>) -> Result<(), Error> {
+        let checkpoint = f.checkpoint();
+        try_!(writec!(f, "{:#?}", list));
+        // Rolling back after writing text that doesn't fit in a window
+        if list.len() > 4 {
+            f.rollback(checkpoint);
+            try_!(writec!(f, "<{} elements>", list.len()));
+        }
+        Ok(())
+    }
+
+    let long_list = [3u32; 2000];
+
+    let mut string = String::new();
+    let mut writer = FmtWriter::new(&mut string);
+    writer
+        .write_with(FormattingFlags::NEW, |f| {
+            try_!(list_or_count(&[3, 5], f));
+            try_!(list_or_count(&long_list, f));
+            list_or_count(&[8], f)
+        })
+        .unwrap();
+
+    assert_eq!(string, "[\n    3,\n    5,\n]<2000 elements>[\n    8,\n]",);
+}
+
+#[test]
+fn write_errors() {
+    struct Failing;
+
+    impl Write for Failing {
+        fn write_str(&mut self, _: &str) -> core::fmt::Result {
+            Err(core::fmt::Error)
+        }
+    }
+
+    let mut failing = Failing;
+    let mut writer = FmtWriter::new(&mut failing);
+    let res = writer.write_with(FormattingFlags::NEW, |f| writec!(f, "{}", 10u8));
+    assert_eq!(res, Err(Error::FmtWrite));
+    let res = writer.write_with(FormattingFlags::NEW, |f| writec!(f, "{:?}", "foo"));
+    assert_eq!(res, Err(Error::FmtWrite));
+
+    // Formatting errors are returned before anything is written
+    let mut string = String::new();
+    let mut writer = FmtWriter::new(&mut string);
+    let res = writer.write_with(FormattingFlags::NEW, |f| {
+        try_!(f.write_str("foo"));
+        f.write_str_range("ñ", 0..1)
+    });
+    assert_eq!(res, Err(Error::NotOnCharBoundary));
+    assert!(writer.is_empty());
+    assert_eq!(string, "");
+}
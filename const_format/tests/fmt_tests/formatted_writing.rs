@@ -3,6 +3,7 @@ use const_format::{
     fmt::{ComputeStrLength, Error, Formatter, FormattingFlags, StrWriter},
     try_,
     wrapper_types::PWrapper,
+    writec,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -313,3 +314,150 @@ fn check_set_formatting() {
         ALTERNATE_BINARY,
     );
 }
+
+#[test]
+fn integer_sign_and_padding() {
+    const fn inner(n: i32, writer: &mut StrWriter, flags: FormattingFlags) -> Result<usize, Error> {
+        let mut f = writer.make_formatter(flags);
+        try_!(f.write_i32_display(n));
+        try_!(f.write_str(";"));
+        try_!(f.write_i32_debug(n));
+        try_!(f.write_str(";"));
+        try_!(f.write_u8_debug(n as u8));
+
+        let mut str_len = ComputeStrLength::new();
+        let mut f = str_len.make_formatter(flags);
+        try_!(f.write_i32_display(n));
+        try_!(f.write_str(";"));
+        try_!(f.write_i32_debug(n));
+        try_!(f.write_str(";"));
+        try_!(f.write_u8_debug(n as u8));
+
+        Ok(str_len.len())
+    }
+
+    fn test_case(n: i32, flags: FormattingFlags, expected: &str) {
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 128]);
+        let len = inner(n, writer, flags).unwrap();
+
+        assert_eq!(writer.as_str(), expected);
+        assert_eq!(writer.len(), len, "{}", writer.as_str());
+    }
+
+    let plus = FormattingFlags::NEW.set_sign_plus(true);
+    let zeroes = FormattingFlags::NEW
+        .set_width(6)
+        .set_sign_aware_zero_pad(true);
+
+    test_case(10, plus, "+10;+10;+10");
+    test_case(-10, plus, "-10;-10;+246");
+    test_case(10, plus.set_hexadecimal(), "+10;+A;+A");
+    test_case(10, plus.set_lower_hexadecimal(), "+10;+a;+a");
+    test_case(10, plus.set_alternate(true).set_octal(), "+10;+0o12;+0o12");
+    test_case(
+        -1,
+        plus.set_alternate(true).set_binary(),
+        "-1;+0b11111111111111111111111111111111;+0b11111111",
+    );
+    test_case(10, zeroes, "000010;000010;000010");
+    test_case(-10, zeroes.set_sign_plus(true), "-00010;-00010;+00246");
+    test_case(
+        10,
+        zeroes
+            .set_sign_plus(true)
+            .set_alternate(true)
+            .set_hexadecimal(),
+        "+00010;+0x00A;+0x00A",
+    );
+    test_case(
+        10,
+        zeroes
+            .set_sign_plus(true)
+            .set_alternate(true)
+            .set_lower_hexadecimal(),
+        "+00010;+0x00a;+0x00a",
+    );
+    test_case(
+        -8,
+        zeroes.set_alternate(true).set_octal(),
+        "-00008;0o37777777770;0o0370",
+    );
+    test_case(
+        10,
+        plus.set_width(5).set_precision(3),
+        " +010; +010; +010",
+    );
+}
+
+#[test]
+fn rollback_on_error() {
+    const fn write_foo(foo: &Foo, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let checkpoint = f.checkpoint();
+        let res = {
+            let mut f = f.debug_tuple("Wrapper");
+            match foo.const_debug_fmt(f.field()) {
+                Ok(()) => f.finish(),
+                Err(e) => Err(e),
+            }
+        };
+        if let Err(e) = res {
+            f.rollback(checkpoint);
+            try_!(f.write_str("<error>"));
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    let foo = Foo {
+        x: 3,
+        y: "hello",
+        z: &[5, 8],
+    };
+
+    for &flags in &[
+        FormattingFlags::NEW,
+        FormattingFlags::NEW.set_alternate(true),
+    ] {
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 24]);
+        let mut fmt = writer.make_formatter(flags);
+        fmt.write_str("A: ").unwrap();
+        assert_eq!(write_foo(&foo, &mut fmt), Err(Error::NotEnoughSpace));
+        assert_eq!(fmt.margin(), 0);
+        assert_eq!(writer.as_str(), "A: <error>");
+    }
+
+    {
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+        assert_eq!(writec!(writer, "{}", 100u8), Ok(()));
+        assert_eq!(
+            writec!(@rollback, writer, "-{:?}", ["hello", "world", "foo", "bar"]),
+            Err(Error::NotEnoughSpace)
+        );
+        assert_eq!(writer.as_str(), "100");
+
+        assert_eq!(writec!(@rollback, writer, "-{}", 255u8), Ok(()));
+        assert_eq!(writer.as_str(), "100-255");
+    }
+
+    {
+        // A writer named `rollback` is written to without rolling back
+        let rollback: &mut StrWriter = &mut StrWriter::new([0; 8]);
+        assert_eq!(writec!(rollback, "{}", "a"), Ok(()));
+        assert_eq!(
+            writec!(rollback, "{}{}", "bc", "very long"),
+            Err(Error::NotEnoughSpace)
+        );
+        assert_eq!(rollback.as_str(), "abc");
+    }
+
+    {
+        let mut str_len = ComputeStrLength::new();
+        let mut fmt = str_len.make_formatter(FormattingFlags::NEW);
+        fmt.write_str("ñ").unwrap();
+        let checkpoint = fmt.checkpoint();
+        fmt.write_str("foo ñ").unwrap();
+        fmt.rollback(checkpoint);
+        fmt.write_str("ñ").unwrap();
+        assert_eq!(str_len.len(), 4);
+    }
+}
@@ -1,7 +1,7 @@
 use const_format::{
     ascii_str,
-    fmt::{ComputeStrLength, Formatter, FormattingFlags},
-    AsciiStr,
+    fmt::{ComputeStrLength, Error, Formatter, FormattingFlags, StrWriter},
+    AsciiStr, PWrapper,
 };
 
 mod debug_methods;
@@ -97,6 +97,33 @@ fn write_integers() {
 
         write_with_flag(FormattingFlags::NEW.set_hexadecimal(), &expected, &inner);
     }
+    {
+        let expected = format!(
+            "\
+                17,18,19,20,21,22,23,24,25,26,27,28,1d,1e,1f,20,21,22,23,24,25,26,27,28,d7,\
+                ffd6,ffffffd5,ffffffffffffffd4,ffffffffffffffffffffffffffffffd3,{:x},\
+            ",
+            -46_isize
+        );
+
+        write_with_flag(
+            FormattingFlags::NEW.set_lower_hexadecimal(),
+            &expected,
+            &inner,
+        );
+    }
+    {
+        let expected = format!(
+            "\
+                17,18,19,20,21,22,23,24,25,26,27,28,35,36,37,40,41,42,43,44,45,46,47,50,327,\
+                177726,37777777725,1777777777777777777724,\
+                3777777777777777777777777777777777777777723,{:o},\
+            ",
+            -46_isize
+        );
+
+        write_with_flag(FormattingFlags::NEW.set_octal(), &expected, &inner);
+    }
     {
         let expected = format!(
             "\
@@ -137,12 +164,63 @@ fn write_str_methods() {
 
     let expected = "\
         ello\n;;\nABCD\n;;\x1F bar;;what\0the;;----;;\
-        \"ello\\n\";;\"\\nABCD\\n\";;\"\\x1F bar\";;\"what\\x00the\";;\
+        \"ello\\n\";;\"\\nABCD\\n\";;\"\\u{1f} bar\";;\"what\\0the\";;\
     ";
 
     write_with_flag(FormattingFlags::NEW, expected, &inner);
 }
 
+#[test]
+fn join_entries() {
+    const fn write_joined(
+        fmt: &mut Formatter<'_>,
+        separator: &str,
+        numbers: &[u32],
+    ) -> Result<(), Error> {
+        let mut f = fmt.join(separator);
+        let mut i = 0;
+        while i < numbers.len() {
+            let res = PWrapper(numbers[i]).const_debug_fmt(f.entry());
+            if let Err(e) = res {
+                return Err(e);
+            }
+            i += 1;
+        }
+        f.finish()
+    }
+
+    fn inner(mut fmt: Formatter<'_>) {
+        append_str!(fmt,";";
+            write_joined(&mut fmt, ", ", &[]).unwrap();
+            write_joined(&mut fmt, ", ", &[3]).unwrap();
+            write_joined(&mut fmt, ", ", &[3, 5, 8]).unwrap();
+            write_joined(&mut fmt, "", &[13, 21]).unwrap();
+            write_joined(&mut fmt, " ñ ", &[34, 55]).unwrap();
+        );
+    }
+
+    write_with_flag(FormattingFlags::NEW, ";3;3, 5, 8;1321;34 ñ 55;", &inner);
+    write_with_flag(
+        FormattingFlags::NEW.set_hexadecimal(),
+        ";3;3, 5, 8;D15;22 ñ 37;",
+        &inner,
+    );
+    write_with_flag(
+        FormattingFlags::NEW.set_alternate(true).set_hexadecimal(),
+        ";0x3;0x3, 0x5, 0x8;0xD0x15;0x22 ñ 0x37;",
+        &inner,
+    );
+
+    // Errors from writing the entries are returned
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 4]);
+    let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    assert_eq!(
+        write_joined(&mut fmt, ", ", &[3, 5, 8]),
+        Err(Error::NotEnoughSpace)
+    );
+    assert_eq!(writer.as_str(), "3, 5");
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 fn remove_margin(s: &str) -> String {
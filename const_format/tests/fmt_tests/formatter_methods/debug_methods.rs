@@ -1,7 +1,7 @@
 use super::{remove_margin, write_with_flag};
 
 use const_format::{
-    fmt::{Error, Formatter, FormattingFlags},
+    fmt::{Error, Formatter, FormattingFlags, StrWriter},
     impl_fmt, try_, PWrapper,
 };
 
@@ -327,3 +327,345 @@ fn formatting_set() {
         set.const_debug_fmt(&mut fmt).unwrap();
     })
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Map {
+    a: u32,
+    b: &'static [u32],
+    rec: Option<&'static Map>,
+}
+
+impl_fmt! {
+    impl Map;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_map();
+        {
+            let mut fmt = fmt.entry("margin");
+            let margin = fmt.margin();
+            try_!(fmt.write_usize_display(margin));
+        }
+        try_!(fmt.entry("a").write_u32_debug(self.a));
+        try_!(fmt.key().write_u32_debug(self.a));
+        try_!(format_b_field(self.b, fmt.value()));
+        if let Some(x) = self.rec {
+            try_!(fmt.key().write_str_debug("rec"));
+            try_!(x.const_debug_fmt(fmt.value()));
+        }
+        fmt.finish()
+    }
+}
+
+#[test]
+fn formatting_map() {
+    let expected = remove_margin(
+        r#"
+        {
+            "margin": 4,
+            "a": 3,
+            3: [
+                0x9,
+                0xC,
+                0xF,
+            ],
+            "rec": {
+                "margin": 8,
+                "a": 8,
+                8: [A, E, 12],
+                "rec": {
+                    "margin": 12,
+                    "a": 21,
+                    21: [
+                        0xF,
+                        0x14,
+                        0x19,
+                    ],
+                },
+            },
+        }"#,
+    );
+
+    let map = Map {
+        a: 3,
+        b: &[9, 12, 15],
+        rec: Some(&Map {
+            a: 8,
+            b: &[10, 14, 18],
+            rec: Some(&Map {
+                a: 21,
+                b: &[15, 20, 25],
+                rec: None,
+            }),
+        }),
+    };
+
+    let flags = FormattingFlags::NEW.set_alternate(true);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        map.const_debug_fmt(&mut fmt).unwrap();
+    })
+}
+
+struct Table(&'static [(&'static str, u32)]);
+
+impl_fmt! {
+    impl Table;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_map();
+        let mut i = 0;
+        while i < self.0.len() {
+            let (key, value) = self.0[i];
+            try_!(fmt.entry(key).write_u32_debug(value));
+            i += 1;
+        }
+        fmt.finish()
+    }
+}
+
+impl std::fmt::Debug for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+#[test]
+fn formatting_map_like_std() {
+    let tables = [
+        Table(&[]),
+        Table(&[("a", 1)]),
+        Table(&[("a", 1), ("b", 2)]),
+        Table(&[("foo\n", 3), ("\"bar\"", 5), ("baz", 8)]),
+    ];
+
+    for table in tables.iter() {
+        for &alternate in [false, true].iter() {
+            let expected = if alternate {
+                format!("{:#?}", table)
+            } else {
+                format!("{:?}", table)
+            };
+
+            let flags = FormattingFlags::NEW.set_alternate(alternate);
+
+            write_with_flag(flags, &expected, &|mut fmt| {
+                table.const_debug_fmt(&mut fmt).unwrap();
+            })
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Layout {
+    nums: &'static [u32],
+    table: Table,
+    single: (u8,),
+}
+
+impl_fmt! {
+    impl Layout;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_struct("Layout");
+        try_!(PWrapper(self.nums).const_debug_fmt(fmt.field("nums")));
+        try_!(self.table.const_debug_fmt(fmt.field("table")));
+        {
+            let mut fmt = fmt.field("single").debug_tuple("");
+            try_!(PWrapper(self.single.0).const_debug_fmt(fmt.field()));
+            try_!(fmt.finish_tuple());
+        }
+        fmt.finish()
+    }
+}
+
+const LAYOUT: Layout = Layout {
+    nums: &[3, 5],
+    table: Table(&[("a", 8)]),
+    single: (13,),
+};
+
+#[test]
+fn pretty_printing_flags() {
+    let alternate = FormattingFlags::NEW.set_alternate(true);
+
+    let cases = [
+        (
+            alternate.set_indent_width(2),
+            "Layout {\n  nums: [\n    3,\n    5,\n  ],\n  table: {\n    \"a\": 8,\n  },\n  \
+             single: (\n    13,\n  ),\n}",
+        ),
+        (
+            alternate.set_indent_width(0),
+            "Layout {\nnums: [\n3,\n5,\n],\ntable: {\n\"a\": 8,\n},\nsingle: (\n13,\n),\n}",
+        ),
+        (
+            alternate.set_tab_indentation(true),
+            "Layout {\n\tnums: [\n\t\t3,\n\t\t5,\n\t],\n\ttable: {\n\t\t\"a\": 8,\n\t},\n\t\
+             single: (\n\t\t13,\n\t),\n}",
+        ),
+        (
+            alternate.set_trailing_commas(false),
+            "Layout {\n    nums: [\n        3,\n        5\n    ],\n    table: {\n        \
+             \"a\": 8\n    },\n    single: (\n        13\n    )\n}",
+        ),
+        (
+            alternate.set_compact_width(6),
+            "Layout {\n    nums: [3, 5],\n    table: {\n        \"a\": 8,\n    },\n    \
+             single: (\n        13,\n    ),\n}",
+        ),
+        (
+            alternate.set_compact_width(5),
+            "Layout {\n    nums: [\n        3,\n        5,\n    ],\n    table: {\n        \
+             \"a\": 8,\n    },\n    single: (\n        13,\n    ),\n}",
+        ),
+        (
+            alternate.set_hexadecimal().set_compact_width(10),
+            "Layout {\n    nums: [0x3, 0x5],\n    table: {\n        \"a\": 0x8,\n    },\n    \
+             single: (\n        0xD,\n    ),\n}",
+        ),
+        // These flags only affect pretty printing
+        (
+            FormattingFlags::NEW
+                .set_indent_width(2)
+                .set_tab_indentation(true)
+                .set_trailing_commas(false)
+                .set_compact_width(100),
+            "Layout { nums: [3, 5], table: {\"a\": 8}, single: (13,) }",
+        ),
+    ];
+
+    for (flags, expected) in cases.iter() {
+        write_with_flag(*flags, expected, &|mut fmt| {
+            LAYOUT.const_debug_fmt(&mut fmt).unwrap();
+        })
+    }
+}
+
+#[test]
+#[cfg(feature = "const_trait_impl")]
+fn compact_nested_lists() {
+    let list: &[&[u8]] = &[&[3, 5], &[8, 13, 21, 34]];
+
+    let alternate = FormattingFlags::NEW.set_alternate(true);
+
+    let cases = [
+        (alternate.set_compact_width(25), "[[3, 5], [8, 13, 21, 34]]"),
+        (
+            alternate.set_compact_width(24),
+            "[\n    [3, 5],\n    [8, 13, 21, 34],\n]",
+        ),
+        (
+            alternate.set_compact_width(6),
+            "[\n    [3, 5],\n    [\n        8,\n        13,\n        21,\n        34,\n    ],\n]",
+        ),
+    ];
+
+    for (flags, expected) in cases.iter() {
+        write_with_flag(*flags, expected, &|mut fmt| {
+            PWrapper(list).const_debug_fmt(&mut fmt).unwrap();
+        })
+    }
+}
+
+#[test]
+#[cfg(feature = "const_trait_impl")]
+fn depth_and_entry_limits() {
+    let list: &[&[&[u8]]] = &[&[&[3, 5], &[8]], &[], &[&[13, 21, 34]]];
+
+    let cases = [
+        (FormattingFlags::NEW.set_max_depth(0), "[..]"),
+        (FormattingFlags::NEW.set_max_depth(1), "[[..], [], [..]]"),
+        (
+            FormattingFlags::NEW.set_max_depth(2),
+            "[[[..], [..]], [], [[..]]]",
+        ),
+        (FormattingFlags::NEW.set_max_entries(0), "[..]"),
+        (
+            FormattingFlags::NEW.set_max_entries(2),
+            "[[[3, 5], [8]], [], ..]",
+        ),
+        (
+            FormattingFlags::NEW.set_max_depth(2).set_max_entries(1),
+            "[[[..], ..], ..]",
+        ),
+        (
+            FormattingFlags::NEW
+                .set_alternate(true)
+                .set_max_depth(2)
+                .set_max_entries(1),
+            "[\n    [\n        [\n            ..\n        ],\n        ..\n    ],\n    ..\n]",
+        ),
+        (
+            FormattingFlags::NEW
+                .set_alternate(true)
+                .set_max_entries(1)
+                .set_compact_width(13),
+            "[\n    [[3, ..], ..],\n    ..\n]",
+        ),
+    ];
+
+    for (flags, expected) in cases.iter() {
+        write_with_flag(*flags, expected, &|mut fmt| {
+            PWrapper(list).const_debug_fmt(&mut fmt).unwrap();
+        })
+    }
+}
+
+#[test]
+fn depth_and_entry_limits_of_fields() {
+    let layout_cases = [
+        (
+            FormattingFlags::NEW.set_max_entries(1),
+            "Layout { nums: [3, ..], .. }",
+        ),
+        (
+            FormattingFlags::NEW.set_max_depth(1),
+            "Layout { nums: [..], table: {..}, single: (..) }",
+        ),
+        (
+            FormattingFlags::NEW
+                .set_alternate(true)
+                .set_max_entries(1)
+                .set_trailing_commas(false),
+            "Layout {\n    nums: [\n        3,\n        ..\n    ],\n    ..\n}",
+        ),
+        (
+            FormattingFlags::NEW
+                .set_alternate(true)
+                .set_max_depth(1)
+                .set_compact_width(10),
+            "Layout {\n    nums: [..],\n    table: {\n        ..\n    },\n    single: (\n        ..\n    ),\n}",
+        ),
+    ];
+
+    for (flags, expected) in layout_cases.iter() {
+        write_with_flag(*flags, expected, &|mut fmt| {
+            LAYOUT.const_debug_fmt(&mut fmt).unwrap();
+        })
+    }
+}
+
+#[test]
+fn limits_bound_output_length() {
+    let mut table = [0u16; 1000];
+    for (i, x) in table.iter_mut().enumerate() {
+        *x = i as u16;
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+
+    PWrapper(&table[..])
+        .const_debug_fmt(&mut writer.make_formatter(FormattingFlags::NEW))
+        .unwrap_err();
+
+    writer.clear();
+    let flags = FormattingFlags::NEW.set_max_entries(3);
+    PWrapper(&table[..])
+        .const_debug_fmt(&mut writer.make_formatter(flags))
+        .unwrap();
+    assert_eq!(writer.as_str(), "[0, 1, 2, ..]");
+}
@@ -71,6 +71,85 @@ fn array_impls() {
             "[\n    \"foo\\n\",\n    \"bar\\t\",\n]"
         )
     }
+    test_fmt! {&[char];
+        (
+            ['a', 'ñ', '\n'],
+            "['a', 'ñ', '\\n']",
+            "[\n    'a',\n    'ñ',\n    '\\n',\n]"
+        )
+    }
+    test_fmt! {&[bool];
+        ([true, false], "[true, false]", "[\n    true,\n    false,\n]")
+    }
+    #[cfg(feature = "float")]
+    test_fmt! {&[f64];
+        (
+            [1.0, -0.5, 1e20],
+            "[1.0, -0.5, 1e20]",
+            "[\n    1.0,\n    -0.5,\n    1e20,\n]"
+        )
+    }
+}
+
+#[test]
+#[cfg(feature = "const_trait_impl")]
+fn nested_array_impls() {
+    test_fmt! {&[Option<&str>];
+        (
+            [Some("foo"), None],
+            "[Some(\"foo\"), None]",
+            "[\n    Some(\n        \"foo\",\n    ),\n    None,\n]",
+        )
+    }
+    test_fmt! {&[[u8; 2]];
+        (
+            [[3u8, 5], [8, 13]],
+            "[[3, 5], [8, 13]]",
+            "[\n    [\n        0x3,\n        0x5,\n    ],\n    [\n        0x8,\n        0xD,\n    ],\n]",
+        )
+    }
+    test_fmt! {&[&[u16]];
+        ([&[3u16][..], &[]], "[[3], []]", "[\n    [\n        0x3,\n    ],\n    [],\n]")
+    }
+    test_fmt! {&[[Option<char>; 1]];
+        ([[Some('a')], [None]], "[[Some('a')], [None]]", "[\n    [\n        Some(\n            'a',\n        ),\n    ],\n    [\n        None,\n    ],\n]")
+    }
+    test_fmt! {&[[[u8; 2]; 1]];
+        ([[[3u8, 5]], [[8, 13]]], "[[[3, 5]], [[8, 13]]]", &format!("{:#X?}", [[[3u8, 5]], [[8, 13]]]))
+    }
+}
+
+#[test]
+fn large_arrays() {
+    use const_format::formatc;
+
+    const LARGE: [u16; 100] = {
+        let mut arr = [0u16; 100];
+        let mut i = 0;
+        while i < arr.len() {
+            arr[i] = (i * 7) as u16;
+            i += 1;
+        }
+        arr
+    };
+
+    assert_eq!(formatc!("{:?}", LARGE), format!("{:?}", LARGE));
+    assert_eq!(formatc!("{:#x?}", LARGE), format!("{:#X?}", LARGE));
+}
+
+#[test]
+#[cfg(feature = "const_trait_impl")]
+fn large_nested_arrays() {
+    use const_format::formatc;
+
+    const MATRIX: [[u8; 4]; 4] = [[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]];
+
+    const TABLE: [Option<&str>; 3] = [Some("foo"), None, Some("bar")];
+
+    assert_eq!(formatc!("{:?}", MATRIX), format!("{:?}", MATRIX));
+    assert_eq!(formatc!("{:#?}", MATRIX), format!("{:#?}", MATRIX));
+    assert_eq!(formatc!("{:?}", TABLE), format!("{:?}", TABLE));
+    assert_eq!(formatc!("{:?}", [MATRIX; 2]), format!("{:?}", [MATRIX; 2]));
 }
 
 #[test]
@@ -85,24 +164,33 @@ fn range_impls() {
 
 #[test]
 fn options() {
-    test_fmt! {Option<&str>;
+    test_fmt! {&Option<&str>;
         (None::<&str>, "None", "None")
         (Some("hello\n"), "Some(\"hello\\n\")", "Some(\n    \"hello\\n\",\n)")
     }
-    test_fmt! {Option<u8>;
+    test_fmt! {&Option<u8>;
         (None::<u8>, "None", "None")
         (Some(10u8), "Some(10)", "Some(\n    0xA,\n)")
     }
-    test_fmt! {Option<bool>;
+    test_fmt! {&Option<bool>;
         (None::<bool>, "None", "None")
         (Some(false), "Some(false)", "Some(\n    false,\n)")
         (Some(true), "Some(true)", "Some(\n    true,\n)")
     }
-    test_fmt! {Option<NonZeroU8>;
+    test_fmt! {&Option<char>;
+        (None::<char>, "None", "None")
+        (Some('\''), "Some('\\'')", "Some(\n    '\\'',\n)")
+    }
+    #[cfg(feature = "float")]
+    test_fmt! {&Option<f32>;
+        (None::<f32>, "None", "None")
+        (Some(0.25f32), "Some(0.25)", "Some(\n    0.25,\n)")
+    }
+    test_fmt! {&Option<NonZeroU8>;
         (None::<NonZeroU8>, "None", "None")
         (NonZeroU8::new(10), "Some(10)", "Some(\n    0xA,\n)")
     }
-    test_fmt! {Option<NonNull<u8>>;
+    test_fmt! {&Option<NonNull<u8>>;
         (None::<NonNull<u8>>, "None", "None")
         (Some(NonNull::<u8>::dangling()), "Some(<pointer>)", "Some(\n    <pointer>,\n)")
     }
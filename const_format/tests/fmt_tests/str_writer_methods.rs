@@ -50,6 +50,10 @@ macro_rules! write_integer_tests {
                     writer.$debug_fn(number, flags.set_binary()).unwrap();
                     writer.write_str("_").unwrap();
                     writer.$debug_fn(number, flags.set_hexadecimal()).unwrap();
+                    writer.write_str("_").unwrap();
+                    writer.$debug_fn(number, flags.set_lower_hexadecimal()).unwrap();
+                    writer.write_str("_").unwrap();
+                    writer.$debug_fn(number, flags.set_octal()).unwrap();
                     writer.write_str("__").unwrap();
 
                     assert!( !writer.is_empty() );
@@ -69,11 +73,19 @@ macro_rules! write_integer_tests {
 
                     let fmt = &mut writer.make_formatter(flags.set_hexadecimal());
                     PWrapper(number).const_debug_fmt(fmt).unwrap();
+                    writer.write_str("_").unwrap();
+
+                    let fmt = &mut writer.make_formatter(flags.set_lower_hexadecimal());
+                    PWrapper(number).const_debug_fmt(fmt).unwrap();
+                    writer.write_str("_").unwrap();
+
+                    let fmt = &mut writer.make_formatter(flags.set_octal());
+                    PWrapper(number).const_debug_fmt(fmt).unwrap();
 
 
                     write!(
                         string,
-                        "{0}_{0:?}_{0:b}_{0:X}__{0}_{0:?}_{0:b}_{0:X}",
+                        "{0}_{0:?}_{0:b}_{0:X}_{0:x}_{0:o}__{0}_{0:?}_{0:b}_{0:X}_{0:x}_{0:o}",
                         number
                     ).unwrap();
 
@@ -132,7 +144,7 @@ fn test_write_ints() {
 fn basic() {
     assert_eq!(
         formatcp!("{:?}", r#" !Aq¡\"🧡🧠₀₁ "#),
-        r#"" !Aq¡\\\"🧡🧠₀₁ ""#
+        r#"" !Aq¡\\\"\u{7f}\u{80}🧡🧠₀₁ ""#
     );
 }
 
@@ -275,7 +287,7 @@ fn write_ascii() {
 }
 
 fn is_it_escaped(c: char) -> bool {
-    matches!(c, '\0'..='\u{1F}' | '\\' | '"' | '\'')
+    c.escape_debug().next() == Some('\\')
 }
 
 #[test]
@@ -316,7 +328,7 @@ fn write_str_debug() {
 
         writer.truncate(snapshot).unwrap();
         writer.write_ascii_debug(all_ascii).unwrap();
-        let end = ALL_ASCII_ESCAPED.find('\u{80}').unwrap();
+        let end = ALL_ASCII_ESCAPED.find("\\u{80}").unwrap();
 
         let bytes = writer.as_bytes();
         assert_eq!(bytes[0], b'"');
@@ -378,6 +390,49 @@ fn write_str_debug() {
     }
 }
 
+// Tests that every character is Debug formatted the same as std does,
+// both by itself and in a string.
+#[test]
+fn debug_escapes_all_chars() {
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    let mut std_string = ArrayString::<[u8; 64]>::new();
+
+    #[cfg(not(miri))]
+    let step = 1;
+
+    #[cfg(miri)]
+    let step = 0x1FFF;
+
+    let chars = (0..=0x10FFFF)
+        .step_by(step)
+        .filter_map(core::char::from_u32);
+
+    for c in chars {
+        let mut char_buffer = [0; 4];
+        let string = c.encode_utf8(&mut char_buffer);
+
+        writer.clear();
+        writer.as_mut().write_char_debug(c).unwrap();
+        std_string.clear();
+        write!(std_string, "{:?}", c).unwrap();
+        assert_eq!(writer.as_str(), std_string.as_str());
+        assert_eq!(
+            PWrapper(c).compute_debug_len(FormattingFlags::NEW),
+            writer.len()
+        );
+
+        writer.clear();
+        writer.as_mut().write_str_debug(string).unwrap();
+        std_string.clear();
+        write!(std_string, "{:?}", string).unwrap();
+        assert_eq!(writer.as_str(), std_string.as_str());
+        assert_eq!(
+            PWrapper(string.as_bytes()).compute_utf8_debug_len(),
+            writer.len()
+        );
+    }
+}
+
 // Makes sure that a StrWriter that's too small for a string returns an Error
 // instead of panicking
 #[test]
@@ -412,11 +467,11 @@ fn returns_error() {
 
     test_case!("foo\nb", 1);
     test_case!("foo\"ba", 1);
-    test_case!("foo\'bar", 1);
+    test_case!("foo\'bar", 0);
     test_case!("foo\rbarb", 1);
     test_case!("foo\\barba", 1);
-    test_case!("foo\u{5}bar", 3);
-    test_case!("foo\u{11}bar", 3);
+    test_case!("foo\u{5}bar", 4);
+    test_case!("foo\u{11}bar", 5);
 }
 
 #[test]
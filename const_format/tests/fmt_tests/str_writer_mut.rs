@@ -1,4 +1,5 @@
-use const_format::fmt::{Error, StrWriterMut};
+use const_format::fmt::{Error, Formatter, FormattingFlags, StrWriter, StrWriterMut, Truncation};
+use const_format::writec;
 
 pub trait Foo {
     fn as_str() -> u64 {
@@ -180,3 +181,139 @@ fn as_bytes() {
         assert_eq!(writer.remaining_capacity(), CAP - i - 1);
     }
 }
+
+#[test]
+fn truncating() -> Result<(), Error> {
+    let mut truncation = Truncation::new("...");
+    let mut len = 0;
+    let mut buffer = [0; 10];
+    let mut writer =
+        StrWriterMut::from_custom_cleared(&mut buffer, &mut len).truncating(&mut truncation);
+
+    writer.write_str("foo")?;
+    writer.write_u32_display(100)?;
+    assert!(!writer.is_truncated());
+    assert_eq!(writer.as_str(), "foo100");
+
+    // Numbers are written whole or not at all
+    writer.write_u32_display(12345)?;
+    assert!(writer.is_truncated());
+    assert_eq!(writer.as_str(), "foo100...");
+
+    // Writes after truncating do nothing, even if they'd fit
+    writer.write_str("a")?;
+    assert_eq!(writer.as_str(), "foo100...");
+
+    writer.clear();
+    assert!(!writer.is_truncated());
+
+    // Fits exactly, then makes room for the marker by removing written text
+    writer.write_str_debug("a\nbcdef")?;
+    assert!(!writer.is_truncated());
+    assert_eq!(writer.as_str(), r#""a\nbcdef""#);
+    writer.write_str("x")?;
+    assert_eq!(writer.as_str(), r#""a\nbcd..."#);
+
+    writer.clear();
+    writer.write_str_debug("ab\tcdefgh")?;
+    assert_eq!(writer.as_str(), r#""ab\tcd..."#);
+
+    writer.clear();
+    writer.write_str("abcdefgh")?;
+    writer.write_ascii_repeated(b'-', 5)?;
+    assert_eq!(writer.as_str(), "abcdefg...");
+
+    writer.clear();
+    writer.write_str("abcd")?;
+    writer.write_str("ñandú")?;
+    assert_eq!(writer.as_str(), "abcdña...");
+
+    writer.clear();
+    writer.write_str("abcdef")?;
+    writer.write_str("ñandú")?;
+    assert_eq!(writer.as_str(), "abcdef...");
+
+    assert!(truncation.is_truncated());
+
+    Ok(())
+}
+
+#[test]
+fn truncating_marker_longer_than_buffer() -> Result<(), Error> {
+    let mut truncation = Truncation::new("[ñ truncated]");
+    let mut len = 0;
+    let mut buffer = [0; 2];
+    let mut writer =
+        StrWriterMut::from_custom_cleared(&mut buffer, &mut len).truncating(&mut truncation);
+
+    writer.write_str("hello")?;
+    assert_eq!(writer.as_str(), "[");
+
+    Ok(())
+}
+
+#[test]
+fn truncating_padding() -> Result<(), Error> {
+    // Formats the arguments into a 10 byte writer, checking that the output is a prefix of
+    // what it'd be in a larger writer.
+    macro_rules! truncated {
+        ($fmt:literal, $($arg:expr),* => $expected:expr) => ({
+            let mut truncation = Truncation::new("…");
+            let writer: &mut StrWriter = &mut StrWriter::new([0; 10]);
+            writec!(writer.as_mut().truncating(&mut truncation), $fmt, $($arg),*)?;
+            assert_eq!(writer.as_str(), $expected);
+
+            let untruncated = format!($fmt, $($arg),*);
+            let prefix = writer.as_str().trim_end_matches('…');
+            assert!(untruncated.starts_with(prefix), "{:?} {:?}", untruncated, prefix);
+            assert_eq!(truncation.is_truncated(), untruncated.len() > 10);
+        })
+    }
+
+    truncated!("{:>20}", "x" => "       …");
+    truncated!("{:^12}", "abcdefgh" => "  abcde…");
+    truncated!("{:<12}", "abcdefgh" => "abcdefg…");
+    truncated!("{:<12}", "abc" => "abc    …");
+    truncated!("{:ñ>12}", "abc" => "ñññ…");
+    truncated!("{:^10}", "abc" => "   abc    ");
+
+    // Numbers are written whole or not at all, the padding before them is truncated
+    truncated!("{:>12}", 100u32 => "       …");
+    truncated!("{}{:>9}", "ab", 12345u32 => "ab    …");
+    truncated!("{}{:08}", "abc", -15i32 => "abc…");
+    truncated!("{}{:+}", "abcdefgh", 15i32 => "abcdefg…");
+    truncated!("{:<8}{}", 100u32, "ab" => "100     ab");
+
+    Ok(())
+}
+
+#[test]
+fn truncating_formatter() -> Result<(), Error> {
+    const fn write_pair(f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut list = f.debug_list();
+        let _ = writec!(list.entry(), "{:?}", "hello");
+        let _ = writec!(list.entry(), "{:#x}", 0xABCDu32);
+        list.finish()
+    }
+
+    let mut truncation = Truncation::new("…");
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+
+    let mut writer_mut = writer.as_mut().truncating(&mut truncation);
+    write_pair(&mut writer_mut.make_formatter(FormattingFlags::NEW))?;
+    assert!(writer_mut.is_truncated());
+    assert_eq!(writer.as_str(), r#"["hello", 0xA…"#);
+
+    let mut truncation = Truncation::new("…");
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 17]);
+    write_pair(
+        &mut writer
+            .as_mut()
+            .truncating(&mut truncation)
+            .make_formatter(FormattingFlags::NEW),
+    )?;
+    assert!(!truncation.is_truncated());
+    assert_eq!(writer.as_str(), r#"["hello", 0xABCD]"#);
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 #![cfg(feature = "fmt")]
 #![cfg_attr(feature = "fmt", feature(const_mut_refs))]
+#![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 
 const_format::__declare_rng_ext! {}
 
 mod fmt_tests {
+    mod fmt_writer;
+
     #[cfg(not(feature = "only_new_tests"))]
     mod display_formatting;
 
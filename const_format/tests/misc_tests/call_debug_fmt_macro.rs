@@ -47,6 +47,27 @@ fn all_macro_branches() {
     );
 }
 
+#[test]
+fn compact_arrays() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        call_debug_fmt!(array, [Some(3u8), None], f);
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    let alternate = FormattingFlags::NEW.set_alternate(true);
+
+    inner(&mut writer.make_formatter(alternate.set_compact_width(15))).unwrap();
+    assert_eq!(writer.as_str(), "[Some(3), None]");
+
+    writer.clear();
+    inner(&mut writer.make_formatter(alternate.set_compact_width(14))).unwrap();
+    assert_eq!(
+        writer.as_str(),
+        "[\n    Some(\n        3,\n    ),\n    None,\n]"
+    );
+}
+
 #[test]
 fn returns_error() {
     const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
@@ -142,3 +142,87 @@ fn enum_formatting() {
         ",
     );
 }
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, ConstDebug)]
+struct Octet(u8);
+
+#[derive(Debug, ConstDebug)]
+struct Arrays {
+    matrix: [[Octet; 2]; 2],
+    table: [Option<&'static str>; 3],
+    slices: &'static [&'static [Octet]],
+    cube: [[[u8; 2]; 2]; 2],
+    tuples: [(u8, Octet); 2],
+    options: &'static [Option<u8>],
+}
+
+#[test]
+fn nested_array_formatting() {
+    use const_format::formatc;
+
+    const ARRAYS: Arrays = Arrays {
+        matrix: [[Octet(1), Octet(0)], [Octet(0), Octet(1)]],
+        table: [Some("foo"), None, Some("bar")],
+        slices: &[&[Octet(3)], &[], &[Octet(5), Octet(8)]],
+        cube: [[[0, 1], [2, 3]], [[4, 5], [6, 7]]],
+        tuples: [(13, Octet(21)), (34, Octet(55))],
+        options: &[Some(89), None],
+    };
+
+    assert_eq!(formatc!("{:?}", ARRAYS), format!("{:?}", ARRAYS));
+    assert_eq!(formatc!("{:#?}", ARRAYS), format!("{:#?}", ARRAYS));
+    assert_eq!(formatc!("{:x?}", ARRAYS), format!("{:X?}", ARRAYS));
+}
+
+#[derive(ConstDebug)]
+struct Compact {
+    short: [u8; 3],
+    long: &'static [u32],
+    matrix: [[Octet; 2]; 2],
+}
+
+#[test]
+fn compact_array_formatting() {
+    use const_format::fmt::ComputeStrLength;
+
+    const COMPACT: Compact = Compact {
+        short: [1, 2, 3],
+        long: &[100000, 200000, 300000],
+        matrix: [[Octet(1), Octet(0)], [Octet(0), Octet(1)]],
+    };
+
+    let flags = FormattingFlags::NEW
+        .set_alternate(true)
+        .set_compact_width(20);
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+    COMPACT
+        .const_debug_fmt(&mut writer.make_formatter(flags))
+        .unwrap();
+
+    let mut str_len = ComputeStrLength::new();
+    COMPACT
+        .const_debug_fmt(&mut str_len.make_formatter(flags))
+        .unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+Compact {
+    short: [1, 2, 3],
+    long: [
+        100000,
+        200000,
+        300000,
+    ],
+    matrix: [
+        [Octet(1), Octet(0)],
+        [Octet(0), Octet(1)],
+    ],
+}\
+        "
+    );
+    assert_eq!(writer.len(), str_len.len());
+}
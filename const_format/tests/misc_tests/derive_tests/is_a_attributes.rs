@@ -268,3 +268,109 @@ fn opting_out_of_std() {
         "
     );
 }
+
+type BarTable = &'static [(&'static str, Bar)];
+
+#[derive(ConstDebug)]
+struct Maps {
+    #[cdeb(is_a(map))]
+    map_a: &'static [(&'static str, u32)],
+
+    #[cdeb(is_a(map))]
+    map_b: [(u32, Bar); 2],
+
+    #[cdeb(is_a(map))]
+    map_c: BarTable,
+
+    #[cdeb(is_a(map))]
+    map_d: &'static [(u8, Option<Bar>)],
+
+    #[cdeb(is_a(map))]
+    map_e: &'static [((u8, u8), [u8; 2])],
+
+    #[cdeb(is_a(map))]
+    map_f: &'static [(&'static str, u32)],
+}
+
+#[test]
+fn map_attribute() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        let maps = Maps {
+            map_a: &[("a", 1), ("b", 2)],
+            map_b: [(3, Bar(5)), (8, Bar(13))],
+            map_c: &[("foo", Bar(21))],
+            map_d: &[(34, Some(Bar(55))), (89, None)],
+            map_e: &[((1, 2), [3, 4])],
+            map_f: &[],
+        };
+
+        try_!(maps.const_debug_fmt(f));
+
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 1024]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+            Maps { \
+                map_a: {\"a\": 1, \"b\": 2}, \
+                map_b: {3: Bar(5), 8: Bar(13)}, \
+                map_c: {\"foo\": Bar(21)}, \
+                map_d: {34: Some(Bar(55)), 89: None}, \
+                map_e: {(1, 2): [3, 4]}, \
+                map_f: {} \
+            }\
+        "
+    );
+
+    writer.clear();
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW.set_alternate(true))).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+Maps {
+    map_a: {
+        \"a\": 1,
+        \"b\": 2,
+    },
+    map_b: {
+        3: Bar(
+            5,
+        ),
+        8: Bar(
+            13,
+        ),
+    },
+    map_c: {
+        \"foo\": Bar(
+            21,
+        ),
+    },
+    map_d: {
+        34: Some(
+            Bar(
+                55,
+            ),
+        ),
+        89: None,
+    },
+    map_e: {
+        (
+            1,
+            2,
+        ): [
+            3,
+            4,
+        ],
+    },
+    map_f: {},
+}\
+        "
+    );
+}
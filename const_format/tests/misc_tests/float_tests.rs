@@ -0,0 +1,70 @@
+use const_format::{concatcp, formatcp};
+
+#[cfg(feature = "fmt")]
+use const_format::formatc;
+
+macro_rules! same_as_std {
+    ( $fmt:literal, $($expr:expr),* $(,)* ) => ({
+        const VALUE: &str = formatcp!($fmt, $($expr),*);
+
+        assert_eq!(VALUE, format!($fmt, $($expr),*), "format string: {:?}", $fmt);
+
+        #[cfg(feature = "fmt")]
+        assert_eq!(formatc!($fmt, $($expr),*), VALUE, "format string: {:?}", $fmt);
+    })
+}
+
+macro_rules! all_formats {
+    ($($expr:expr),* $(,)*) => ($({
+        const VALUE: &str = concatcp!($expr);
+        assert_eq!(VALUE, format!("{}", $expr));
+
+        same_as_std!("{}", $expr);
+        same_as_std!("{:?}", $expr);
+        same_as_std!("{:+}|{:+?}", $expr, $expr);
+        same_as_std!("{:.0}|{:.1}|{:.3?}|{:.20}", $expr, $expr, $expr, $expr);
+        same_as_std!("{:12}|{:<12}|{:^12?}|{:>12.2}", $expr, $expr, $expr, $expr);
+        same_as_std!("{:012}|{:+012?}|{:_^+12.1}", $expr, $expr, $expr);
+    })*)
+}
+
+#[test]
+fn f64_formatting() {
+    all_formats! {
+        0.0f64, -0.0f64, 1.0f64, -1.0f64, 0.5f64, 1.5f64, 2.5f64, 0.25f64, 0.35f64,
+        0.1f64, 0.3f64, 1.0f64 / 3.0, 2.0f64 / 3.0, 9.9996f64, 999.9999f64, 123456.789f64,
+        1e15f64, 1e16f64, 1.5e16f64, 1e20f64, 1e300f64, 1e-4f64, 9.9e-5f64, 1.5e-7f64,
+        f64::MAX, f64::MIN, f64::MIN_POSITIVE, f64::EPSILON, 5e-324f64,
+        f64::INFINITY, f64::NEG_INFINITY, f64::NAN,
+    }
+}
+
+#[test]
+fn f32_formatting() {
+    all_formats! {
+        0.0f32, -0.0f32, 1.0f32, -1.0f32, 0.5f32, 1.5f32, 2.5f32, 0.1f32, 0.3f32,
+        1.0f32 / 3.0, 16777216.0f32, 123456.79f32,
+        1e16f32, 1e20f32, 1e-4f32, 9.9e-5f32,
+        f32::MAX, f32::MIN, f32::MIN_POSITIVE, f32::EPSILON, 1e-45f32,
+        f32::INFINITY, f32::NEG_INFINITY, f32::NAN,
+    }
+}
+
+#[test]
+fn large_precision() {
+    same_as_std!("{:.30}|{:.2}", 0.1f64, 1e300f64);
+    same_as_std!("{:.1074}", 5e-324f64);
+    same_as_std!("{:.60?}", f32::MIN_POSITIVE);
+}
+
+#[test]
+fn concatenated_floats() {
+    assert_eq!(
+        concatcp!(1.5f64, "|", -0.25f32, "|", 1e21f64),
+        "1.5|-0.25|1000000000000000000000"
+    );
+    assert_eq!(
+        formatcp!("{}-{:?}-{:?}", 1.0f64, 1.0f64, f64::MAX * 2.0),
+        "1-1.0-inf"
+    );
+}
@@ -0,0 +1,69 @@
+//! Tests for Debug formatting arrays, slices, tuples, `Option`s, and `Result`s
+//! of types that implement `FmtDebug`.
+
+use const_format::{
+    fmt::{Error, Formatter},
+    impl_fmt, try_, PWrapper,
+};
+
+macro_rules! same_as_std {
+    ( $fmt:literal, $($expr:expr),* $(,)* ) => ({
+        assert_eq!(
+            const_format::formatc!($fmt, $($expr),*),
+            format!($fmt, $($expr),*),
+            "{:?}",
+            $fmt,
+        );
+    })
+}
+
+macro_rules! all_formats {
+    ($($expr:expr),* $(,)*) => ($({
+        same_as_std!("{:?}", $expr);
+        same_as_std!("{:#?}", $expr);
+        same_as_std!("{:X?}|{:#X?}", $expr, $expr);
+        same_as_std!("{:<40?}|{:_>40?}|{:^40?}", $expr, $expr, $expr);
+    })*)
+}
+
+mod array_tests;
+
+mod option_result_tests;
+
+mod tuple_tests;
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl_fmt! {
+    impl Point;
+
+    const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_struct("Point");
+        try_!(PWrapper(self.x).const_debug_fmt(f.field("x")));
+        try_!(PWrapper(self.y).const_debug_fmt(f.field("y")));
+        f.finish()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Unit;
+
+impl_fmt! {
+    impl Unit;
+
+    const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("Unit")
+    }
+}
+
+const fn point(x: u32) -> Point {
+    Point { x, y: x * 2 }
+}
+
+const POINT: Point = Point { x: 3, y: 5 };
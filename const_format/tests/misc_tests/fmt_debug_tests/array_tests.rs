@@ -0,0 +1,90 @@
+use super::{point, Point, Unit};
+
+use const_format::{
+    fmt::{Error, Formatter, FormattingFlags, StrWriter},
+    try_, writec,
+};
+
+const POINTS: [Point; 3] = [point(1), point(2), point(3)];
+const POINT_SLICE: &[Point] = &POINTS;
+const EMPTY_SLICE: &[Point] = &[];
+const UNIT_SLICE: &[Unit] = &[Unit, Unit];
+const SLICE_OF_SLICES: &[&[Point]] = &[POINT_SLICE, EMPTY_SLICE, &[point(4)]];
+const SLICE_OF_ARRAYS: &[[Point; 2]] = &NESTED;
+const NESTED: [[Point; 2]; 2] = [[point(1), point(2)], [point(3), point(4)]];
+const DEEP: [[[[u8; 1]; 1]; 1]; 1] = [[[[1u8; 1]; 1]; 1]; 1];
+
+#[test]
+fn arrays_of_user_types() {
+    all_formats! {
+        [Unit; 0],
+        [Unit],
+        [Unit, Unit, Unit],
+        POINTS,
+        [point(1), point(2)],
+        [[point(1), point(2)], [point(3), point(4)]],
+        NESTED,
+        [NESTED, NESTED],
+        [[Unit; 3]; 2],
+    }
+}
+
+#[test]
+fn slices_of_user_types() {
+    all_formats! {
+        POINT_SLICE,
+        EMPTY_SLICE,
+        UNIT_SLICE,
+        SLICE_OF_SLICES,
+        SLICE_OF_ARRAYS,
+        [UNIT_SLICE, UNIT_SLICE],
+    }
+}
+
+#[test]
+fn arrays_of_options_and_results() {
+    all_formats! {
+        [Some(point(1)), None],
+        [None, Some(Unit)],
+        [Ok::<_, Unit>(point(1)), Err(Unit)],
+        [[Some(point(1))], [None]],
+        [Some([point(1), point(2)]), None],
+        Some([Some(Unit), None]),
+        Ok::<_, u8>([[Some(Unit)]; 2]),
+    }
+}
+
+#[test]
+fn deeply_nested_arrays() {
+    all_formats! {
+        DEEP,
+        [[[[1u8; 1]; 1]; 1]; 1],
+        [[[[[["foo"; 2]; 1]; 2]; 1]; 2]; 1],
+        [[[[[Some(point(1)); 1]; 2]; 1]; 2]; 1],
+        [[[[[Unit; 2]; 1]; 2]; 1]; 2],
+    }
+}
+
+#[test]
+fn array_writec() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(writec!(f, "{:?};", NESTED));
+        try_!(writec!(f, "{:?};", [Some(Unit), None]));
+        try_!(writec!(f, "{:#?}", [[point(1)]]));
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        concat!(
+            "[[Point { x: 1, y: 2 }, Point { x: 2, y: 4 }], ",
+            "[Point { x: 3, y: 6 }, Point { x: 4, y: 8 }]];",
+            "[Some(Unit), None];",
+            "[\n    [\n        Point {\n            x: 1,\n            y: 2,\n        },\n    ],\n]",
+        ),
+    );
+}
@@ -0,0 +1,148 @@
+use super::{Point, Unit, POINT};
+
+use const_format::{
+    call_debug_fmt,
+    fmt::{Error, Formatter, FormattingFlags, StrWriter},
+    formatc, try_, writec,
+};
+
+const SOME: Option<Point> = Some(POINT);
+const NONE: Option<Point> = None;
+const OK: Result<Point, Unit> = Ok(POINT);
+const ERR: Result<Point, Unit> = Err(Unit);
+
+#[test]
+fn option_result_of_user_types() {
+    all_formats! {
+        SOME,
+        NONE,
+        OK,
+        ERR,
+        Some(Unit),
+        Ok::<Unit, u8>(Unit),
+        Err::<u8, Point>(POINT),
+    }
+}
+
+#[test]
+fn nested_option_result() {
+    all_formats! {
+        Some(SOME),
+        Some(NONE),
+        None::<Option<Point>>,
+        Some(OK),
+        Some(ERR),
+        Ok::<_, Unit>(SOME),
+        Err::<Unit, _>(NONE),
+        Ok::<_, Unit>(Ok::<_, Unit>(POINT)),
+        Err::<Unit, _>(Err::<Unit, _>(Unit)),
+    }
+}
+
+#[test]
+fn deeply_nested_option_result() {
+    all_formats! {
+        Some(Some(Some(3u8))),
+        Some(Some(Some(Unit))),
+        Some(Some(Some(Some(SOME)))),
+        Ok::<_, u8>(Some(Some(Err::<u8, _>(POINT)))),
+        Err::<u8, _>(Ok::<_, Unit>(Some(Some(Some(Unit))))),
+        [Some(Some(Some(POINT))), Some(None), None],
+    }
+}
+
+#[test]
+fn option_result_in_tuples() {
+    all_formats! {
+        (SOME, OK),
+        (NONE, ERR, Some(Some(Unit))),
+    }
+}
+
+#[test]
+fn option_result_writec() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(writec!(f, "{:?};", SOME));
+        try_!(writec!(f, "{:?};", ERR));
+        try_!(writec!(f, "{:#?}", Some(OK)));
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        format!("{:?};{:?};{:#?}", SOME, ERR, Some(OK)),
+    );
+}
+
+#[test]
+fn call_debug_fmt_result() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        call_debug_fmt!(Result, OK, f);
+        try_!(f.write_str(";"));
+        call_debug_fmt!(Result, ERR, f);
+        try_!(f.write_str(";"));
+        call_debug_fmt!(Result, Ok::<_, Unit>(SOME), f);
+        try_!(f.write_str(";"));
+        call_debug_fmt!(Option, Some(ERR), f);
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        format!(
+            "{:?};{:?};{:?};{:?}",
+            OK,
+            ERR,
+            Ok::<_, Unit>(SOME),
+            Some(ERR)
+        ),
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_option_result_fields() {
+    use const_format::ConstDebug;
+
+    type Alias = Result<u8, Unit>;
+
+    #[derive(Debug, ConstDebug)]
+    struct Braced {
+        option: Option<Point>,
+        result: Result<Point, Unit>,
+        std_result: core::result::Result<u8, Point>,
+        nested: Option<Result<Option<Point>, [Unit; 2]>>,
+        in_tuple: (Option<Unit>, &'static [Result<Unit, u8>]),
+        #[cdeb(is_a(result))]
+        alias: Alias,
+    }
+
+    #[derive(Debug, ConstDebug)]
+    enum Enum {
+        Tupled(Option<Option<Point>>, Result<(u8, Point), Unit>),
+    }
+
+    const BRACED: Braced = Braced {
+        option: Some(POINT),
+        result: Err(Unit),
+        std_result: Ok(8),
+        nested: Some(Ok(Some(POINT))),
+        in_tuple: (None, &[Ok(Unit), Err(13)]),
+        alias: Err(Unit),
+    };
+
+    const ENUM: Enum = Enum::Tupled(Some(None), Ok((21, POINT)));
+
+    assert_eq!(formatc!("{:?}", BRACED), format!("{:?}", BRACED));
+    assert_eq!(formatc!("{:#?}", BRACED), format!("{:#?}", BRACED));
+    assert_eq!(formatc!("{:?}", ENUM), format!("{:?}", ENUM));
+    assert_eq!(formatc!("{:#?}", ENUM), format!("{:#?}", ENUM));
+}
@@ -0,0 +1,145 @@
+use super::{Point, POINT};
+
+use const_format::{
+    coerce_to_fmt,
+    fmt::{Error, Formatter, FormattingFlags, StrWriter},
+    formatc, impl_fmt, try_, writec,
+};
+
+const SINGLE: (Point,) = (POINT,);
+const PAIR: (u8, &str) = (3, "foo");
+const NESTED: ((u8, Point), [(char, bool); 2], Option<(Point,)>) =
+    ((5, POINT), [('a', true), ('b', false)], Some(SINGLE));
+const TWELVE: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, Point, &str) =
+    (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, POINT, "bar");
+
+#[test]
+fn tuple_expressions() {
+    all_formats! {
+        (),
+        (3u8,),
+        (3u8, "foo"),
+        (3u8, "foo", POINT),
+        (1u8, 2u16, 3u32, 4u64, 5u128, 6usize, 7i8, 8i16, 9i32, 10i64, 11i128, 12isize),
+        ((), (1u8,), ((2u8, POINT), 'c')),
+        ([3u8, 5], &[8u16, 13], Some(false), "bar"),
+        (core::convert::identity::<u8>(3), Some(POINT.x)),
+    }
+}
+
+#[test]
+fn tuple_constants() {
+    all_formats! {
+        SINGLE,
+        PAIR,
+        NESTED,
+        TWELVE,
+        [PAIR, PAIR],
+        Some((PAIR, SINGLE)),
+        Ok::<_, u8>(NESTED.0),
+        [SINGLE; 3],
+    }
+}
+
+#[test]
+fn tuple_named_arguments() {
+    assert_eq!(
+        formatc!("{a:?} {b:?} {a:?}", a = (3u8, false), b = ("foo",)),
+        r#"(3, false) ("foo",) (3, false)"#,
+    );
+
+    assert_eq!(
+        formatc!("{a:?} {PAIR:?} {SINGLE:?}", a = NESTED.0),
+        "(5, Point { x: 3, y: 5 }) (3, \"foo\") (Point { x: 3, y: 5 },)",
+    );
+
+    // A parenthesized expression isn't a tuple
+    assert_eq!(formatc!("{:?}", (POINT.x)), "3");
+}
+
+#[test]
+fn tuple_writec() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(writec!(f, "{:?};", (3u8, "foo", POINT)));
+        try_!(writec!(f, "{:>12?};", (3u8, (4u8,))));
+        try_!(writec!(f, "{:#?}", (3u8, (4u8,))));
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        format!(
+            "{:?};{:>12?};{:#?}",
+            (3u8, "foo", POINT),
+            (3u8, (4u8,)),
+            (3u8, (4u8,))
+        ),
+    );
+}
+
+#[test]
+fn impl_fmt_tuple_fields() {
+    #[derive(Debug)]
+    struct Pairs {
+        pair: (u8, Point),
+        nested: ((char, [u16; 2]), Option<(Point,)>),
+    }
+
+    impl_fmt! {
+        impl Pairs;
+
+        const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+            let mut f = f.debug_struct("Pairs");
+            try_!(coerce_to_fmt!(&self.pair).const_debug_fmt(f.field("pair")));
+            try_!(coerce_to_fmt!(&self.nested).const_debug_fmt(f.field("nested")));
+            f.finish()
+        }
+    }
+
+    const PAIRS: Pairs = Pairs {
+        pair: (8, POINT),
+        nested: (('c', [13, 21]), Some(SINGLE)),
+    };
+
+    all_formats! {
+        PAIRS,
+        (PAIRS.pair, PAIRS.nested),
+    }
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_tuple_fields() {
+    use const_format::ConstDebug;
+
+    #[derive(Debug, ConstDebug)]
+    struct Braced {
+        unit: (),
+        single: (u8,),
+        pair: (&'static str, Point),
+        nested: ((u8, [Point; 2]), Option<Point>, &'static [u8]),
+    }
+
+    #[derive(Debug, ConstDebug)]
+    enum Enum {
+        Tupled((u8, bool), &'static (Point, char)),
+    }
+
+    const BRACED: Braced = Braced {
+        unit: (),
+        single: (3,),
+        pair: ("foo", POINT),
+        nested: ((5, [POINT, POINT]), Some(POINT), &[8, 13]),
+    };
+
+    const ENUM: Enum = Enum::Tupled((21, true), &(POINT, 'a'));
+
+    assert_eq!(formatc!("{:?}", BRACED), format!("{:?}", BRACED));
+    assert_eq!(formatc!("{:#?}", BRACED), format!("{:#?}", BRACED));
+    assert_eq!(formatc!("{:?}", ENUM), format!("{:?}", ENUM));
+    assert_eq!(formatc!("{:#?}", ENUM), format!("{:#?}", ENUM));
+}
@@ -2,12 +2,34 @@ use const_format::formatcp;
 use const_format::test_utils::{ALL_ASCII, ALL_ASCII_ESCAPED};
 
 #[cfg(feature = "fmt")]
-use const_format::formatc;
+use const_format::{formatc, formatc_writer, formatted_len, StrWriter};
 
 use arrayvec::{ArrayString, ArrayVec};
 
 use core::fmt::Write;
 
+// Asserts that `formatcp` and `formatc` format the arguments the same as std.
+macro_rules! same_as_std {
+    ($fmt:literal $(, $($args:tt)* )?) => ({
+        let expected = format!($fmt $(, $($args)* )?);
+
+        assert_eq!(formatcp!($fmt $(, $($args)* )?), expected);
+
+        #[cfg(feature = "fmt")]
+        assert_eq!(formatc!($fmt $(, $($args)* )?), expected);
+    })
+}
+
+// Asserts that `formatcp` and `formatc` format the arguments into `$expected`.
+macro_rules! both {
+    ($fmt:literal, $($expr:expr),* => $expected:literal) => ({
+        assert_eq!(formatcp!($fmt, $($expr),*), $expected);
+
+        #[cfg(feature = "fmt")]
+        assert_eq!(formatc!($fmt, $($expr),*), $expected);
+    })
+}
+
 #[test]
 fn positional_and_named_arguments() {
     // Positional,implicit
@@ -162,6 +184,47 @@ macro_rules! binary_hex_test_case {
         .unwrap();
 
         assert_eq!(cp_string, $buffer.as_str());
+
+        let cp_string = $themacro!(
+            "{0:X}_{0:o}_{0:#X}_{0:#o}_{0:X?}_{0:o?}_\
+             {1:X}_{1:o}_{1:#X}_{1:#o}_{1:X?}_{1:o?}_\
+             {2:X}_{2:o}_{2:#X}_{2:#o}_{2:X?}_{2:o?}_\
+             {3:X}_{3:o}_{3:#X}_{3:#o}_{3:X?}_{3:o?}_\
+             {4:X}_{4:o}_{4:#X}_{4:#o}_{4:X?}_{4:o?}_\
+             {5:X}_{5:o}_{5:#X}_{5:#o}_{5:X?}_{5:o?}_\
+             {6:X}_{6:o}_{6:#X}_{6:#o}_{6:X?}_{6:o?}_\
+             {7:X}_{7:o}_{7:#X}_{7:#o}_{7:X?}_{7:o?}_\
+             {8:X}_{8:o}_{8:#X}_{8:#o}_{8:X?}_{8:o?}_\
+             ",
+            P[0],
+            P[1],
+            P[2],
+            P[3],
+            P[4],
+            P[5],
+            P[6],
+            P[7],
+            P[8]
+        );
+
+        $buffer.clear();
+        write!(
+            $buffer,
+            "{0:X}_{0:o}_{0:#X}_{0:#o}_{0:X}_{0:o}_\
+        {1:X}_{1:o}_{1:#X}_{1:#o}_{1:X}_{1:o}_\
+        {2:X}_{2:o}_{2:#X}_{2:#o}_{2:X}_{2:o}_\
+        {3:X}_{3:o}_{3:#X}_{3:#o}_{3:X}_{3:o}_\
+        {4:X}_{4:o}_{4:#X}_{4:#o}_{4:X}_{4:o}_\
+        {5:X}_{5:o}_{5:#X}_{5:#o}_{5:X}_{5:o}_\
+        {6:X}_{6:o}_{6:#X}_{6:#o}_{6:X}_{6:o}_\
+        {7:X}_{7:o}_{7:#X}_{7:#o}_{7:X}_{7:o}_\
+        {8:X}_{8:o}_{8:#X}_{8:#o}_{8:X}_{8:o}_\
+        ",
+            P[0], P[1], P[2], P[3], P[4], P[5], P[6], P[7], P[8]
+        )
+        .unwrap();
+
+        assert_eq!(cp_string, $buffer.as_str());
     }};
 }
 
@@ -180,6 +243,21 @@ fn binary_and_hex_formatting() {
     binary_hex_test_case!(i128, s);
 }
 
+// Unlike std, `{:x}` and `{:x?}` write uppercase hexadecimal, the same as `{:X}` and `{:X?}`.
+#[test]
+fn lowercase_x_is_uppercase_hexadecimal() {
+    both!("[{0:x}][{0:x?}][{0:#x?}][{1:x?}]", 0xABu8, -1i16 => "[AB][AB][0xAB][FFFF]");
+
+    assert_eq!(format!("{:x?}", 0xABu8), "ab");
+    assert_eq!(formatcp!("{:x?}", 0xABu8), format!("{:X?}", 0xABu8));
+
+    #[cfg(feature = "fmt")]
+    {
+        assert_eq!(formatc!("{:x?}", [0xABu8, 0xCD]), "[AB, CD]");
+        assert_eq!(formatc!("{:x?}", Some(0xEFu16)), "Some(EF)");
+    }
+}
+
 #[test]
 fn other_tests() {
     assert_eq!(formatcp!("{0:?}-{0:x?}-{0:b?}", ""), r#"""-""-"""#);
@@ -218,3 +296,163 @@ fn raw_literals() {
     assert_eq!(formatcp!(r##"r#"-{}_"#"##, "hello"), r##"r#"-hello_"#"##);
     assert_eq!(formatcp!(r##"r#"-{{}}_"#"##), r##"r#"-{}_"#"##);
 }
+
+#[test]
+fn width_fill_alignment() {
+    same_as_std!("[{:8}][{:<8}][{:>8}][{:^8}]", "foo", "foo", "foo", "foo");
+    same_as_std!("[{:8}][{:<8}][{:>8}][{:^8}]", 13u8, -13i8, 21u64, -21i128);
+    same_as_std!("[{:*^9}][{:ñ>5}][{:🧡<4}]", "ab", "ab", "ab");
+    same_as_std!("[{:-^7}][{:ñ^6}][{:_>3}]", 3u8, -3i32, 12345u32);
+    same_as_std!("[{:>4}][{:4}][{:^3}]", "ñóö", "🧡🧠", "🧡🧠₀₁");
+    same_as_std!("[{:8}][{:>8}][{:^7}]", true, false, true);
+    same_as_std!("[{:08}][{:08}][{:03}][{:*<08}]", 5u8, -5i8, -1234i32, -5i16);
+    same_as_std!("[{:0}][{:1}][{:>2}]", 5u8, "foo", -10i8);
+    same_as_std!("[{0:>6?}][{0:^#8x}][{0:<#10b}]", 9u32);
+    same_as_std!("[{0:>6?}][{0:6?}]", i8::MIN);
+    same_as_std!("[{0:08?}][{1:08?}]", u128::MAX, i128::MIN);
+    same_as_std!("[{0:#06X}][{0:>#8o}][{0:^7X}][{1:#o}]", 254u32, -9i8);
+
+    // std doesn't pad debug formatted strings and chars
+    same_as_std!("[{:>8?}][{:<7?}][{:^5?}]", "foo", "ñ\n", 'a');
+    same_as_std!("[{:>6?}][{:^7?}]", true, false);
+}
+
+#[test]
+fn precision() {
+    same_as_std!(
+        "[{:.0}][{:.2}][{:.5}][{:.9}]",
+        "hello",
+        "hello",
+        "hello",
+        "hello"
+    );
+    same_as_std!("[{:.1}][{:.2}][{:.3}]", "ñóö", "🧡🧠₀₁", "aé🧡ñ");
+    same_as_std!("[{:>6.3}][{:*^7.2}][{:5.1}]", "hello", "ñóö", "🧡🧠");
+    same_as_std!("[{:.2}][{:.3}][{:6.1}]", true, false, true);
+
+    // Unlike std, the precision is the minimum amount of digits of integers.
+    both!("[{:.0}][{:.1}][{:.4}][{:.4}]", 0u8, 5u8, 5u8, -5i8 => "[0][5][0005][-0005]");
+    both!("[{:.2}][{:.3?}][{:.3}]", 12345u32, -12i32, u128::MAX =>
+        "[12345][-012][340282366920938463463374607431768211455]");
+    both!("[{0:.4x}][{0:#.4x}][{0:#.6b}][{1:#.4x}]", 10u8, -1i8 =>
+        "[000A][0x000A][0b001010][0x00FF]");
+    both!("[{:8.4}][{:<8.4}][{:08.4}][{:^8.3}]", 5u8, -5i8, -5i16, 7u16 =>
+        "[    0005][-0005   ][-0000005][  007   ]");
+
+    // Debug formatted strings ignore the precision, like in std
+    both!("[{:.2?}]", "hello" => r#"["hello"]"#);
+}
+
+#[test]
+fn width_and_precision_arguments() {
+    const W: usize = 7;
+    const P: usize = 2;
+
+    same_as_std!("[{:1$}][{:>1$}]", "foo", 6);
+    same_as_std!("[{:0$}]", 5usize);
+    same_as_std!("[{:w$}][{:^w$}]", "ab", 3u8, w = 6);
+    same_as_std!("[{:W$}][{:*>W$}][{:0W$}]", "ab", 13u8, -13i8);
+    same_as_std!("[{:.1$}][{2:.p$}]", "hello", 3, "world", p = 4);
+    same_as_std!("[{:.*}][{:.*}]", 2, "hello", 0, "world");
+    same_as_std!("[{:>8.*}][{:W$.P$}]", 3, "hello", "world");
+    same_as_std!("[{:>w$.p$}]", "hello", w = W + 1, p = P * 2);
+
+    assert_eq!(
+        formatcp!("[{:.*}][{:w$.3}]", 4, 5u8, -5i8, w = 6),
+        "[0005][  -005]"
+    );
+
+    #[cfg(feature = "fmt")]
+    assert_eq!(
+        formatc!("[{:.*}][{:w$.3}]", 4, 5u8, -5i8, w = 6),
+        "[0005][  -005]"
+    );
+}
+
+#[test]
+fn sign_plus() {
+    same_as_std!("[{:+}][{:+}][{:+}][{:-}]", 0u8, 5u32, -5i8, 5u8);
+    same_as_std!("[{:+?}][{:+?}]", i128::MIN, u128::MAX);
+    same_as_std!("[{:+8}][{:<+8}][{:^+8}][{:+08}]", 5u8, 5u8, -5i8, 5u8);
+    same_as_std!("[{:+08}][{:+08?}][{:+01}][{:+02}]", -5i64, 5u16, 5u8, 5u8);
+    same_as_std!("[{0:+b}][{0:+#b}][{0:+#010b}][{1:+b}]", 5u8, -1i8);
+    same_as_std!("[{:+}][{:+5}][{:+}]", "foo", "bar", true);
+
+    both!("[{0:+x}][{0:+#x}][{0:+#010x}][{1:+#06x}]", 10u8, -1i8 =>
+        "[+A][+0xA][+0x000000A][+0x0FF]");
+    both!("[{:+.3}][{:+.3}][{:+8.3}][{:+#08.3x}]", 5u8, -5i8, 7u16, 10u8 =>
+        "[+005][-005][    +007][+0x0000A]");
+}
+
+#[test]
+fn chars() {
+    same_as_std!("[{}][{}][{}][{}]", 'a', 'ñ', '🧠', '\n');
+    same_as_std!("[{:?}][{:?}][{:?}][{:?}]", 'a', 'ñ', '🧠', '"');
+    same_as_std!("[{:?}][{:?}][{:?}][{:?}]", '\n', '\t', '\\', '\'');
+    same_as_std!("[{:>4}][{:*^5}][{:<3?}]", 'a', 'ñ', '🧠');
+    same_as_std!("[{:.0}][{:.1}][{:3.0}]", 'a', 'ñ', '🧠');
+
+    // std doesn't pad debug formatted chars
+    same_as_std!("[{:5?}][{:>5?}]", '\n', 'ñ');
+
+    // Control characters are escaped the same way as in strings.
+    both!("[{:?}][{:?}][{:?}]", '\x00', '\x1B', '\r' => r"['\0']['\u{1b}']['\r']");
+    both!("{0}{0:?}{1}{1:?}", 'c', "c" => r#"c'c'c"c""#);
+}
+
+#[test]
+fn unicode_escapes() {
+    // grapheme extend characters
+    same_as_std!("[{:?}][{:?}][{:?}]", '\u{301}', "e\u{301}", "\u{301}e");
+    // non-printable characters
+    same_as_std!(
+        "[{:?}][{:?}][{:?}]",
+        '\u{AD}',
+        '\u{200B}',
+        "a\u{7F}\u{85}\u{FEFF}b"
+    );
+    // unassigned and private use code points
+    same_as_std!("[{:?}][{:?}][{:?}]", '\u{378}', '\u{E000}', "\u{10FFFF}ñ🧠");
+    // printable non-ascii characters
+    same_as_std!("[{:?}][{:?}][{:?}]", 'ñ', '\u{A0}', "\u{301}ñ\u{A0}");
+    // std doesn't pad debug formatted strings
+    same_as_std!(
+        "[{:>12?}][{:*^14?}][{:<14?}]",
+        "\u{301}",
+        "ñ\u{200B}",
+        "\0\u{1F}"
+    );
+}
+
+#[cfg(feature = "fmt")]
+#[test]
+fn exactly_sized_formatting() {
+    const NUMS: &[u32] = &[3, 5, 8];
+
+    macro_rules! exactly_sized {
+        ($fmt:literal $(, $expr:expr)* => $expected:literal) => ({
+            const LEN: usize = formatted_len!($fmt $(, $expr)*);
+            assert_eq!(LEN, $expected.len());
+
+            static WRITER: StrWriter<[u8; LEN]> = formatc_writer!($fmt $(, $expr)*);
+            let writer: &StrWriter = &WRITER;
+            assert_eq!(writer.as_str(), $expected);
+            assert_eq!(writer.capacity(), $expected.len());
+
+            assert_eq!(formatc!($fmt $(, $expr)*), $expected);
+        })
+    }
+
+    exactly_sized!("" => "");
+    exactly_sized!("hello" => "hello");
+    exactly_sized!("{NUMS:?}" => "[3, 5, 8]");
+    exactly_sized!("{:#?}", ["ñ"] => "[\n    \"ñ\",\n]");
+    #[cfg(feature = "const_trait_impl")]
+    exactly_sized!("{:#?}", (1u8, "ñ") => "(\n    1,\n    \"ñ\",\n)");
+    exactly_sized!("{0:>5}|{0:#b}|{1:?}", 10u8, 'ñ' => "   10|0b1010|'ñ'");
+
+    assert_eq!(
+        const_format::fmt::formatted_len!("{:x}", u64::MAX),
+        formatted_len!("{}", "ffffffffffffffff")
+    );
+}
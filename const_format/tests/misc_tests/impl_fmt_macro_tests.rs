@@ -480,3 +480,86 @@ fn enum_inside_struct() {
         );
     }
 }
+
+#[test]
+fn runtime_display_formatting() {
+    use const_format::{fmt::ConstFmt, Sliced};
+
+    assert_eq!(format!("{:>6}", ConstFmt(13u8)), "    13");
+    assert_eq!(format!("{:*<6}", ConstFmt("ab")), "ab****");
+    assert_eq!(format!("{:+}", ConstFmt(8i32)), "+8");
+
+    assert_eq!(format!("{}", ConstFmt(Sliced("hello", 1..4))), "ell");
+
+    // Longer than the 64 KiB that used to be the limit
+    let long_string = "foo€".repeat(20_000);
+    assert_eq!(format!("{}", ConstFmt(long_string.as_str())), long_string);
+    assert_eq!(
+        format!("{:^65535}", ConstFmt("€")),
+        format!("{:^65535}", "€")
+    );
+}
+
+#[test]
+#[cfg(feature = "const_trait_impl")]
+fn runtime_formatting() {
+    use const_format::{fmt::ConstFmt, Sliced};
+    use std::cmp::Ordering;
+
+    let foo = BracedStruct::<u32> {
+        a: 10,
+        b: &[20, 30],
+        c: TupleStruct(40, 50),
+        d: UnitStruct,
+    };
+
+    assert_eq!(
+        format!("{:?}", ConstFmt(&foo)),
+        "BracedStruct { a: 10, b: [20, 30], c: TupleStruct(40, 50), d: UnitStruct }",
+    );
+    assert_eq!(
+        format!("{:x}", ConstFmt(&foo)),
+        "BracedStruct { a: a, b: [14, 1e], c: TupleStruct(28, 32), d: UnitStruct }",
+    );
+    assert_eq!(
+        format!("{:#X}", ConstFmt(&foo.c)),
+        "TupleStruct(\n    0x28,\n    0x32,\n)",
+    );
+    assert_eq!(
+        format!("{:#b}", ConstFmt(&foo.c)),
+        "TupleStruct(\n    0b101000,\n    0b110010,\n)"
+    );
+    assert_eq!(format!("{:o}", ConstFmt(&foo.c)), "TupleStruct(50, 62)");
+
+    assert_eq!(format!("{:?}", ConstFmt(Ordering::Less)), "Less");
+    assert_eq!(
+        format!("{:?}", ConstFmt([Some(3u8), None])),
+        "[Some(3), None]"
+    );
+    assert_eq!(format!("{:?}", ConstFmt("foo\n")), r#""foo\n""#);
+
+    assert_eq!(format!("{:?}", ConstFmt(Sliced("hello", 1..4))), r#""ell""#);
+
+    let results: [Result<u8, &str>; 2] = [Ok(3), Err("no")];
+    assert_eq!(format!("{:?}", ConstFmt(results)), r#"[Ok(3), Err("no")]"#);
+}
+
+#[test]
+#[cfg(feature = "const_trait_impl")]
+fn runtime_formatting_long_text() {
+    use const_format::fmt::ConstFmt;
+
+    // Longer than the 64 KiB that used to be the limit
+    let long_string = "foo€".repeat(20_000);
+    let long_str = long_string.as_str();
+    assert_eq!(
+        format!("{:?}", ConstFmt(long_str)),
+        format!("{:?}", long_str)
+    );
+
+    let list = [0xFFFF_FFFFu32; 10_000];
+    assert_eq!(
+        format!("{:#x}", ConstFmt(&list[..])),
+        format!("{:#x?}", &list[..])
+    );
+}
@@ -46,7 +46,7 @@ tests! {
         {}{}{}{}{}{}{}{}\
         {}{}{}{}{}{}{}{}\
         {}{}{}{}{}{}{}{}\
-        {}{}{}{}\
+        {}{}{}{}{}{}{}\
     ",
 
     i8::MIN, " ", i8::MAX, " ",
@@ -64,6 +64,7 @@ tests! {
     u128::MIN, " ", u128::MAX, " ",
     usize::MIN, " ", usize::MAX, " ",
     false, true,
+    'a', 'ñ', '🧡',
 }
 
 #[test]
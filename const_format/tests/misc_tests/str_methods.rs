@@ -0,0 +1,238 @@
+use const_format::{str_get, str_index, str_join, str_repeat, str_replace};
+
+macro_rules! assert_replace {
+    ($input:expr, $pattern:expr, $replace_with:expr) => {{
+        const REPLACED: &str = str_replace!($input, $pattern, $replace_with);
+        assert_eq!(REPLACED, $input.replace($pattern, $replace_with));
+    }};
+}
+
+#[test]
+fn replace_str_pattern() {
+    assert_replace!("", "", "");
+    assert_replace!("", "", "-");
+    assert_replace!("", "foo", "bar");
+    assert_replace!("abc", "", "");
+    assert_replace!("abc", "", "-");
+    assert_replace!("ñañ", "", "_");
+    assert_replace!("foo", "foo", "");
+    assert_replace!("foo", "foo", "barbaz");
+    assert_replace!("foofoo", "foo", "b");
+    assert_replace!("foofoo", "foofoofoo", "b");
+    assert_replace!("aaaaa", "aa", "b");
+    assert_replace!("my-crate-name", "-", "_");
+    assert_replace!("foo::bar::baz", "::", "/");
+    assert_replace!("hello world", "world", "ñandú");
+    assert_replace!("ñandú ñandú", "ñandú", "rhea");
+    assert_replace!("ñandú ñandú", "dú", "");
+    assert_replace!("path/to\\file", "\\", "/");
+}
+
+#[test]
+fn replace_char_pattern() {
+    assert_replace!("", 'a', "b");
+    assert_replace!("a", 'a', "");
+    assert_replace!("a", 'a', "bcd");
+    assert_replace!("abcabc", 'b', "_");
+    assert_replace!("my-crate-name", '-', "_");
+    assert_replace!("ñandú", 'ñ', "n");
+    assert_replace!("ñandú", 'ú', "u");
+    assert_replace!("a\u{10FFFF}b\u{10FFFF}", '\u{10FFFF}', "?");
+    assert_replace!("a\u{301}b", '\u{301}', "");
+}
+
+#[test]
+fn replace_const_args() {
+    const INPUT: &str = "foo.bar.baz";
+    const PATTERN: char = '.';
+    const REPLACE_WITH: &str = "::";
+
+    const REPLACED: &str = str_replace!(INPUT, PATTERN, REPLACE_WITH);
+    assert_eq!(REPLACED, "foo::bar::baz");
+
+    const TWICE: &str = str_replace!(REPLACED, "::", "");
+    assert_eq!(TWICE, "foobarbaz");
+}
+
+#[test]
+fn repeat() {
+    macro_rules! assert_repeat {
+        ($input:expr, $times:expr) => {{
+            const REPEATED: &str = str_repeat!($input, $times);
+            assert_eq!(REPEATED, $input.repeat($times));
+        }};
+    }
+
+    assert_repeat!("", 0);
+    assert_repeat!("", 10);
+    assert_repeat!("a", 0);
+    assert_repeat!("a", 1);
+    assert_repeat!("a", 5);
+    assert_repeat!(", ", 3);
+    assert_repeat!("ñandú", 4);
+    assert_repeat!("\u{10FFFF}\u{301}", 2);
+
+    const TIMES: usize = 2 + 1;
+    const SEPARATOR: &str = str_repeat!(str_replace!("a-", '-', "─"), TIMES);
+    assert_eq!(SEPARATOR, "a─a─a─");
+}
+
+#[test]
+fn index_and_get() {
+    macro_rules! assert_index {
+        ($input:expr, $range:expr) => {{
+            const INDEXED: &str = str_index!($input, $range);
+            const GOTTEN: Option<&str> = str_get!($input, $range);
+            assert_eq!(INDEXED, &$input[$range]);
+            assert_eq!(GOTTEN, Some(&$input[$range]));
+        }};
+    }
+    macro_rules! assert_get_none {
+        ($input:expr, $range:expr) => {{
+            const GOTTEN: Option<&str> = str_get!($input, $range);
+            assert_eq!(GOTTEN, None);
+            assert_eq!(GOTTEN, $input.get($range));
+        }};
+    }
+
+    const INPUT: &str = "my_crate::ñandú::bar";
+
+    assert_index!("", ..);
+    assert_index!("", 0..0);
+    assert_index!(INPUT, ..);
+    assert_index!(INPUT, 0..0);
+    assert_index!(INPUT, 0..8);
+    assert_index!(INPUT, ..8);
+    assert_index!(INPUT, ..=7);
+    assert_index!(INPUT, 10..);
+    assert_index!(INPUT, 10..17);
+    assert_index!(INPUT, 10..=16);
+    assert_index!(INPUT, INPUT.len()..);
+    assert_index!(INPUT, INPUT.len() - 3..INPUT.len());
+
+    assert_get_none!("", 0..1);
+    assert_get_none!(INPUT, 0..100);
+    assert_get_none!(INPUT, 100..);
+    assert_get_none!(INPUT, ..=INPUT.len());
+    assert_get_none!(INPUT, 11..);
+    assert_get_none!(INPUT, ..16);
+    assert_get_none!(INPUT, 10..=15);
+    assert_get_none!(INPUT, ..=usize::MAX);
+
+    #[allow(clippy::reversed_empty_ranges)]
+    {
+        assert_get_none!(INPUT, 8..0);
+    }
+}
+
+#[test]
+fn join() {
+    macro_rules! assert_join {
+        ($strings:expr, $separator:expr) => {{
+            const JOINED: &str = str_join!($strings, $separator);
+            assert_eq!(JOINED, $strings.join($separator));
+        }};
+    }
+
+    const NONE: &[&str] = &[];
+    const ONE: [&str; 1] = ["foo"];
+    const SLICE: &[&str] = &["foo", "", "bar", "ñandú"];
+    const ARRAY: [&str; 3] = ["my_crate", "foo", "bar"];
+
+    assert_join!(NONE, "");
+    assert_join!(NONE, ", ");
+    assert_join!(ONE, "");
+    assert_join!(ONE, ", ");
+    assert_join!(SLICE, "");
+    assert_join!(SLICE, ", ");
+    assert_join!(SLICE, "ñ");
+    assert_join!(ARRAY, "::");
+    assert_join!([""; 3], "");
+    assert_join!([""; 3], "-");
+
+    const JOINED: &str = str_join!(["a", "b", "c"], str_repeat!("-", 2));
+    assert_eq!(JOINED, "a--b--c");
+}
+
+#[cfg(feature = "rust_1_64")]
+#[test]
+fn split() {
+    use const_format::str_split;
+
+    macro_rules! assert_split {
+        ($input:expr, $delimiter:expr) => {{
+            const SPLIT: &[&str] = &str_split!($input, $delimiter);
+            let expected = $input.split($delimiter).collect::<Vec<_>>();
+            assert_eq!(SPLIT, &expected[..]);
+        }};
+    }
+
+    assert_split!("", "");
+    assert_split!("", ",");
+    assert_split!("", ',');
+    assert_split!("abc", "");
+    assert_split!("ñandú", "");
+    assert_split!("foo", ",");
+    assert_split!(",", ",");
+    assert_split!(",,", ',');
+    assert_split!("fmt,float,derive", ',');
+    assert_split!("fmt,float,derive,", ",");
+    assert_split!("my_crate::foo::bar", "::");
+    assert_split!(":::", "::");
+    assert_split!("ñandúñandú", 'ú');
+    assert_split!("ñandúñandú", "ñ");
+    assert_split!("a\u{10FFFF}b\u{10FFFF}c", '\u{10FFFF}');
+
+    const LIST: [&str; 3] = str_split!("foo bar baz", ' ');
+    assert_eq!(LIST, ["foo", "bar", "baz"]);
+}
+
+#[cfg(feature = "rust_1_64")]
+#[test]
+fn split_whitespace() {
+    use const_format::str_split_whitespace;
+
+    macro_rules! assert_split {
+        ($input:expr) => {{
+            const SPLIT: &[&str] = &str_split_whitespace!($input);
+            let expected = $input.split_whitespace().collect::<Vec<_>>();
+            assert_eq!(SPLIT, &expected[..]);
+        }};
+    }
+
+    assert_split!("");
+    assert_split!(" ");
+    assert_split!(" \t\n\r\x0B\x0C");
+    assert_split!("foo");
+    assert_split!("  foo  ");
+    assert_split!("foo bar\tbaz\nqux\r\nñandú");
+    assert_split!("foo\u{85}bar\u{A0}baz\u{1680}qux\u{2000}a\u{200A}b\u{2028}c\u{3000}");
+    assert_split!("foo\u{200B}bar\u{301}");
+}
+
+#[cfg(feature = "rust_1_64")]
+#[test]
+fn lines() {
+    use const_format::str_lines;
+
+    macro_rules! assert_lines {
+        ($input:expr) => {{
+            const LINES: &[&str] = &str_lines!($input);
+            let expected = $input.lines().collect::<Vec<_>>();
+            assert_eq!(LINES, &expected[..]);
+        }};
+    }
+
+    assert_lines!("");
+    assert_lines!("\n");
+    assert_lines!("\r\n");
+    assert_lines!("\n\n");
+    assert_lines!("\r");
+    assert_lines!("foo");
+    assert_lines!("foo\n");
+    assert_lines!("foo\r\nbar");
+    assert_lines!("foo\r\nbar\r\n");
+    assert_lines!("foo\rbar\r");
+    assert_lines!("foo\n\nbar\n\n");
+    assert_lines!("ñandú\r\n\r\nñandú");
+}
@@ -54,7 +54,7 @@ fn repeated_positional_args() {
     inner(&foo, writer).unwrap();
     assert_eq!(
         writer.as_str(),
-        "13,13,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\x00\""
+        "13,13,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\0\""
     );
 }
 
@@ -76,7 +76,7 @@ fn write_from_consts() {
     inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
     assert_eq!(
         writer.as_str(),
-        "13,13,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\x00\""
+        "13,13,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\0\""
     );
 }
 
@@ -99,3 +99,53 @@ fn named_parameters() {
     inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
     assert_eq!(writer.as_str(), "8,13,21,34,1000,D,55..89");
 }
+
+#[test]
+fn padded_arguments() {
+    use const_format::{impl_fmt, PWrapper};
+
+    struct Point(u32, u32);
+
+    impl_fmt! {
+        impl Point;
+
+        const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+            writec!(f, "({}, {})", self.0, self.1)
+        }
+
+        const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+            let mut f = f.debug_tuple("Point");
+            try_!(PWrapper(self.0).const_debug_fmt(f.field()));
+            try_!(PWrapper(self.1).const_debug_fmt(f.field()));
+            f.finish()
+        }
+    }
+
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        const P: Point = Point(3, 5);
+        try_!(writec!(f, "[{P:>8}][{P:*^10}][{:ñ<3}]", Point(8, 13)));
+        try_!(writec!(f, "[{P:3?}][{P:02x?}]"));
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+    assert_eq!(
+        writer.as_str(),
+        "[  (3, 5)][**(3, 5)**][(8, 13)][Point(  3,   5)][Point(03, 05)]"
+    );
+
+    const fn with_args(f: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        const P: Point = Point(3, 5);
+        try_!(writec!(f, "[{P:>0$}][{P:-^w$}]", width, w = width + 2));
+        try_!(writec!(f, "[{P:width$.2?}]"));
+        Ok(())
+    }
+
+    writer.clear();
+    with_args(&mut writer.make_formatter(FormattingFlags::NEW), 8).unwrap();
+    assert_eq!(
+        writer.as_str(),
+        "[  (3, 5)][--(3, 5)--][Point(      03,       05)]"
+    );
+}
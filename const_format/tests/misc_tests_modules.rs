@@ -1,4 +1,5 @@
 #![cfg_attr(feature = "fmt", feature(const_mut_refs))]
+#![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl))]
 
 mod misc_tests {
     #[cfg(feature = "fmt")]
@@ -8,6 +9,12 @@ mod misc_tests {
     #[cfg(feature = "derive")]
     mod derive_tests;
 
+    #[cfg(feature = "float")]
+    mod float_tests;
+
+    #[cfg(feature = "const_trait_impl")]
+    mod fmt_debug_tests;
+
     #[cfg(not(feature = "only_new_tests"))]
     mod formatc_macros;
 
@@ -18,6 +25,8 @@ mod misc_tests {
     #[cfg(not(feature = "only_new_tests"))]
     mod shared_cp_macro_tests;
 
+    mod str_methods;
+
     #[cfg(feature = "fmt")]
     #[cfg(not(feature = "only_new_tests"))]
     mod type_kind_coercion_macro_tests;
@@ -83,7 +83,8 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
                     return None;
                 }
 
-                let fspan = f.pattern_ident().span();
+                let var = f.pattern_ident();
+                let fspan = var.span();
 
                 let field_name_str = match variant.kind {
                     StructKind::Braced => Some(f.ident.to_string()),
@@ -98,8 +99,25 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
                 field_ts.append_all(match &how_to_fmt {
                     HowToFmt::Regular => coerce_and_fmt(&cratep, f),
                     HowToFmt::Ignore => unreachable!(),
-                    HowToFmt::Slice => fmt_slice(&cratep, f),
-                    HowToFmt::Option_ => fmt_option(&cratep, f),
+                    HowToFmt::Slice => {
+                        let elem_ty = type_detection::slice_elem_type(f.ty);
+                        fmt_slice(&cratep, var, quote!(field_formatter), elem_ty, 0)
+                    }
+                    HowToFmt::Map => {
+                        let elem_ty = type_detection::slice_elem_type(f.ty);
+                        fmt_map(&cratep, var, quote!(field_formatter), elem_ty, 0)
+                    }
+                    HowToFmt::Option_ => {
+                        let payload_ty = type_detection::option_payload_type(f.ty);
+                        fmt_option(&cratep, var, quote!(field_formatter), payload_ty, 0)
+                    }
+                    HowToFmt::Result_ => {
+                        let payload_tys = type_detection::result_payload_types(f.ty);
+                        fmt_result(&cratep, var, quote!(field_formatter), payload_tys, 0)
+                    }
+                    HowToFmt::Tuple(tuple) => {
+                        fmt_tuple(&cratep, tuple, var, quote!(field_formatter), 0)
+                    }
                     HowToFmt::Newtype(newtype) => fmt_newtype(&cratep, newtype, f),
                     HowToFmt::With(with) => call_with_function(&cratep, f, with),
                     HowToFmt::WithMacro(with) => call_with_macro(&cratep, f, with),
@@ -159,19 +177,42 @@ fn coerce_and_fmt(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
     )
 }
 
-fn fmt_slice(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
-    let var = field.pattern_ident();
+// `elem_ty` is the type of the elements,
+// used to format elements that are arrays/Options/tuples when it's known.
+fn fmt_slice(
+    cratep: &TokenStream2,
+    var: &Ident,
+    formatter: TokenStream2,
+    elem_ty: Option<&syn::Type>,
+    depth: usize,
+) -> TokenStream2 {
     let fspan = var.span();
 
-    let call = call_debug_fmt(
-        cratep,
-        quote_s!(fspan=> &#var[n]),
-        quote_s!(fspan=> slice_fmt.entry()),
-        fspan,
-    );
+    let call = match elem_ty {
+        Some(elem_ty) => {
+            let elem = Ident::new(&format!("{}_elem", var), fspan);
+            let call = fmt_detected(
+                cratep,
+                elem_ty,
+                &elem,
+                quote_s!(fspan=> slice_fmt.entry()),
+                depth,
+            );
+            quote_s!(fspan=>
+                let #elem = &#var[n];
+                #call
+            )
+        }
+        None => call_debug_fmt(
+            cratep,
+            quote_s!(fspan=> &#var[n]),
+            quote_s!(fspan=> slice_fmt.entry()),
+            fspan,
+        ),
+    };
 
-    quote_spanned!(fspan=>{
-        let mut slice_fmt = field_formatter.debug_list();
+    let list_loop = quote_spanned!(fspan=>
+        let mut slice_fmt = list_f.debug_list();
         let mut n = 0;
         let len = #var.len();
         while n != len {
@@ -179,32 +220,204 @@ fn fmt_slice(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
             n += 1;
         }
         #cratep::try_!(slice_fmt.finish());
+    );
+
+    // Measures the list written on one line to check whether it fits in the compact width,
+    // writing it on one line if it does.
+    quote_spanned!(fspan=>{
+        let list_f = #formatter;
+        let flags = list_f.flags();
+        let flags = if flags.__measures_compact() && {
+            let mut str_len = #cratep::pmr::ComputeStrLength::new();
+            let list_f = &mut list_f.__measuring_formatter(&mut str_len, flags.__single_line());
+            #list_loop
+            str_len.len() <= flags.compact_width()
+        } {
+            flags.__single_line()
+        } else {
+            flags
+        };
+        let list_f = &mut list_f.make_formatter(flags);
+        #list_loop
     })
 }
 
-fn fmt_option(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
-    let var = field.pattern_ident();
+// `elem_ty` is the `(Key, Value)` tuple type of the elements of the slice/array,
+// used to format keys and values that are arrays/Options/Results/tuples when it's known.
+fn fmt_map(
+    cratep: &TokenStream2,
+    var: &Ident,
+    formatter: TokenStream2,
+    elem_ty: Option<&syn::Type>,
+    depth: usize,
+) -> TokenStream2 {
     let fspan = var.span();
 
-    let call = call_debug_fmt(
-        cratep,
-        quote_s!(fspan=>val),
-        quote_s!(fspan=>f.field()),
-        fspan,
-    );
+    let key = Ident::new(&format!("{}_key", var), fspan);
+    let value = Ident::new(&format!("{}_value", var), fspan);
+    let key_formatter = quote_s!(fspan=> map_fmt.key());
+    let value_formatter = quote_s!(fspan=> map_fmt.value());
+
+    let (key_call, value_call) = match elem_ty {
+        Some(syn::Type::Tuple(tuple)) if tuple.elems.len() == 2 => (
+            fmt_detected(cratep, &tuple.elems[0], &key, key_formatter, depth + 1),
+            fmt_detected(cratep, &tuple.elems[1], &value, value_formatter, depth + 1),
+        ),
+        _ => (
+            call_debug_fmt(cratep, &key, key_formatter, fspan),
+            call_debug_fmt(cratep, &value, value_formatter, fspan),
+        ),
+    };
+
+    quote_spanned!(fspan=>{
+        let mut map_fmt = #formatter.debug_map();
+        let mut n = 0;
+        let len = #var.len();
+        while n != len {
+            let #key = &#var[n].0;
+            let #value = &#var[n].1;
+            #key_call
+            #value_call
+            n += 1;
+        }
+        #cratep::try_!(map_fmt.finish());
+    })
+}
+
+// `payload_ty` is the type of the `Some` payload,
+// used to format payloads that are arrays/Options/Results/tuples when it's known.
+fn fmt_option(
+    cratep: &TokenStream2,
+    var: &Ident,
+    formatter: TokenStream2,
+    payload_ty: Option<&syn::Type>,
+    depth: usize,
+) -> TokenStream2 {
+    let fspan = var.span();
+
+    let opt_fmt = Ident::new(&format!("opt_fmt_{}", depth), fspan);
+    let some = Ident::new(&format!("{}_some", var), fspan);
+    let call = fmt_payload(cratep, &some, &opt_fmt, payload_ty, depth);
 
     quote_spanned!(fspan=>
         #cratep::try_!(match #var {
-            #cratep::pmr::Some(val) => {
-                let mut f = field_formatter.debug_tuple("Some");
+            #cratep::pmr::Some(#some) => {
+                let mut #opt_fmt = #formatter.debug_tuple("Some");
                 #call
-                f.finish()
+                #opt_fmt.finish()
+            }
+            #cratep::pmr::None => #formatter.write_str("None"),
+        });
+    )
+}
+
+// `payload_tys` are the `Ok` and `Err` types,
+// used to format payloads that are arrays/Options/Results/tuples when they're known.
+fn fmt_result(
+    cratep: &TokenStream2,
+    var: &Ident,
+    formatter: TokenStream2,
+    payload_tys: Option<(&syn::Type, &syn::Type)>,
+    depth: usize,
+) -> TokenStream2 {
+    let fspan = var.span();
+
+    let res_fmt = Ident::new(&format!("res_fmt_{}", depth), fspan);
+    let ok = Ident::new(&format!("{}_ok", var), fspan);
+    let err = Ident::new(&format!("{}_err", var), fspan);
+    let ok_call = fmt_payload(cratep, &ok, &res_fmt, payload_tys.map(|x| x.0), depth);
+    let err_call = fmt_payload(cratep, &err, &res_fmt, payload_tys.map(|x| x.1), depth);
+
+    quote_spanned!(fspan=>
+        #cratep::try_!(match #var {
+            #cratep::pmr::Ok(#ok) => {
+                let mut #res_fmt = #formatter.debug_tuple("Ok");
+                #ok_call
+                #res_fmt.finish()
+            }
+            #cratep::pmr::Err(#err) => {
+                let mut #res_fmt = #formatter.debug_tuple("Err");
+                #err_call
+                #res_fmt.finish()
             }
-            #cratep::pmr::None => field_formatter.write_str("None"),
         });
     )
 }
 
+// Formats the payload of an Option/Result variant.
+fn fmt_payload(
+    cratep: &TokenStream2,
+    payload: &Ident,
+    tuple_fmt: &Ident,
+    payload_ty: Option<&syn::Type>,
+    depth: usize,
+) -> TokenStream2 {
+    let fspan = payload.span();
+    let formatter = quote_s!(fspan=> #tuple_fmt.field());
+
+    match payload_ty {
+        Some(ty) => fmt_detected(cratep, ty, payload, formatter, depth + 1),
+        None => call_debug_fmt(cratep, payload, formatter, fspan),
+    }
+}
+
+// Destructures the tuple to format each element based on its detected type,
+// recursing into nested tuples.
+fn fmt_tuple(
+    cratep: &TokenStream2,
+    tuple: &syn::TypeTuple,
+    var: &Ident,
+    formatter: TokenStream2,
+    depth: usize,
+) -> TokenStream2 {
+    let fspan = var.span();
+
+    let tuple_fmt = Ident::new(&format!("tuple_fmt_{}", depth), fspan);
+
+    let elem_vars = (0..tuple.elems.len())
+        .map(|i| Ident::new(&format!("{}_{}", var, i), fspan))
+        .collect::<Vec<Ident>>();
+
+    let elem_calls = tuple.elems.iter().zip(&elem_vars).map(|(ty, elem)| {
+        let elem_formatter = quote_s!(fspan=> #tuple_fmt.field());
+        fmt_detected(cratep, ty, elem, elem_formatter, depth + 1)
+    });
+
+    quote_spanned!(fspan=>{
+        let (#(#elem_vars,)*) = #var;
+        let mut #tuple_fmt = #formatter.debug_tuple("");
+        #(#elem_calls)*
+        #cratep::try_!(#tuple_fmt.finish_tuple());
+    })
+}
+
+// Formats `var` based on the detected kind of `ty`,
+// used for the elements of arrays/slices/tuples, and the payloads of Options/Results.
+fn fmt_detected(
+    cratep: &TokenStream2,
+    ty: &syn::Type,
+    var: &Ident,
+    formatter: TokenStream2,
+    depth: usize,
+) -> TokenStream2 {
+    match type_detection::detect_type_formatting(ty) {
+        HowToFmt::Slice => {
+            let elem_ty = type_detection::slice_elem_type(ty);
+            fmt_slice(cratep, var, formatter, elem_ty, depth)
+        }
+        HowToFmt::Option_ => {
+            let payload_ty = type_detection::option_payload_type(ty);
+            fmt_option(cratep, var, formatter, payload_ty, depth)
+        }
+        HowToFmt::Result_ => {
+            let payload_tys = type_detection::result_payload_types(ty);
+            fmt_result(cratep, var, formatter, payload_tys, depth)
+        }
+        HowToFmt::Tuple(tuple) => fmt_tuple(cratep, tuple, var, formatter, depth),
+        _ => call_debug_fmt(cratep, var, formatter, var.span()),
+    }
+}
+
 fn fmt_newtype(cratep: &TokenStream2, newtype: &syn::Ident, field: &Field<'_>) -> TokenStream2 {
     let var = field.pattern_ident();
     let fspan = var.span();
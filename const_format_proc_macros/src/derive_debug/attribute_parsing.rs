@@ -58,8 +58,15 @@ pub(crate) enum HowToFmt<'a> {
     Ignore,
     /// A slice or an array
     Slice,
-    /// A single field tuple struct, èg: `struct Foo(u32);;`
+    /// A slice or an array of key-value pairs, formatted as a map.
+    Map,
+    /// An `Option`, the payload is formatted based on its detected type.
     Option_,
+    /// A `Result`, the payloads are formatted based on their detected types.
+    Result_,
+    /// A tuple type, eg: `(u8, Foo, [u32; 2])`,
+    /// each element is formatted based on its detected type.
+    Tuple(&'a syn::TypeTuple),
     /// A single field tuple struct, èg: `struct Foo(u32);;`
     /// The path of the field is parsed from the type, erroring if it's not a path.
     Newtype(&'a syn::Ident),
@@ -237,8 +244,12 @@ fn parse_the_is_a_attribute<'a>(
         Meta::Path(path) => {
             if path.is_ident("array") || path.is_ident("slice") {
                 Ok(HowToFmt::Slice)
+            } else if path.is_ident("map") {
+                Ok(HowToFmt::Map)
             } else if path.is_ident("Option") || path.is_ident("option") {
                 Ok(HowToFmt::Option_)
+            } else if path.is_ident("Result") || path.is_ident("result") {
+                Ok(HowToFmt::Result_)
             } else if path.is_ident("newtype") {
                 let newtype = type_detection::parse_type_as_ident(f.ty)?;
                 Ok(HowToFmt::Newtype(newtype))
@@ -2,18 +2,71 @@ use super::HowToFmt;
 
 use syn::Type;
 
-pub(super) fn detect_type_formatting(ty: &Type) -> HowToFmt {
+pub(super) fn detect_type_formatting(ty: &Type) -> HowToFmt<'_> {
     let ty = unwrap_reference(ty);
 
     // println!("{:?} {}", ty, ty.to_token_stream());
 
     match ty {
         Type::Array { .. } | Type::Slice { .. } => HowToFmt::Slice,
-        Type::Path(ty) if ty.qself.is_none() && is_path_an_option(&ty.path) => HowToFmt::Option_,
+        Type::Tuple(ty) => HowToFmt::Tuple(ty),
+        Type::Path(ty) if ty.qself.is_none() && is_path_a(&ty.path, "option", "Option") => {
+            HowToFmt::Option_
+        }
+        Type::Path(ty) if ty.qself.is_none() && is_path_a(&ty.path, "result", "Result") => {
+            HowToFmt::Result_
+        }
         _ => HowToFmt::Regular,
     }
 }
 
+/// Gets the element type of an array or slice type.
+pub(super) fn slice_elem_type(ty: &Type) -> Option<&Type> {
+    match unwrap_reference(ty) {
+        Type::Array(ty) => Some(&ty.elem),
+        Type::Slice(ty) => Some(&ty.elem),
+        _ => None,
+    }
+}
+
+/// Gets the payload type of an `Option` type.
+pub(super) fn option_payload_type(ty: &Type) -> Option<&Type> {
+    match std_generic_args(ty, "option", "Option")?.as_slice() {
+        [payload] => Some(payload),
+        _ => None,
+    }
+}
+
+/// Gets the `Ok` and `Err` types of a `Result` type.
+pub(super) fn result_payload_types(ty: &Type) -> Option<(&Type, &Type)> {
+    match std_generic_args(ty, "result", "Result")?.as_slice() {
+        [ok, err] => Some((ok, err)),
+        _ => None,
+    }
+}
+
+// Gets the type arguments of the `module::Type` standard library type,
+// returning None if `ty` isn't that type.
+fn std_generic_args<'a>(ty: &'a Type, module: &str, type_name: &str) -> Option<Vec<&'a Type>> {
+    let path = match unwrap_reference(ty) {
+        Type::Path(ty) if ty.qself.is_none() && is_path_a(&ty.path, module, type_name) => &ty.path,
+        _ => return None,
+    };
+
+    match &path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(args) => Some(
+            args.args
+                .iter()
+                .filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
 fn unwrap_reference(mut ty: &Type) -> &Type {
     loop {
         match ty {
@@ -26,10 +79,10 @@ fn unwrap_reference(mut ty: &Type) -> &Type {
     ty
 }
 
-fn is_path_an_option(path: &syn::Path) -> bool {
+fn is_path_a(path: &syn::Path, module: &str, type_name: &str) -> bool {
     let segments = &path.segments;
 
-    if segments[0].ident == "Option" {
+    if segments[0].ident == type_name {
         return true;
     }
 
@@ -40,8 +93,8 @@ fn is_path_an_option(path: &syn::Path) -> bool {
     let thecrate = &segments[0].ident;
 
     (thecrate == "core" || thecrate == "alloc" || thecrate == "std")
-        && segments[1].ident == "option"
-        && segments[2].ident == "Option"
+        && segments[1].ident == module
+        && segments[2].ident == type_name
 }
 
 /// Parses the type as an identifier, or the last identifier of a path.
@@ -1,4 +1,8 @@
-use crate::{format_str_parsing::FormatStr, formatting::FormattingFlags, parse_utils::StrRawness};
+use crate::{
+    format_str_parsing::FormatStr,
+    formatting::{Formatting, FormattingFlags},
+    parse_utils::StrRawness,
+};
 
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 
@@ -32,6 +36,8 @@ pub(crate) struct FormatArgs {
 /// The arguments of `writec`
 pub(crate) struct WriteArgs {
     pub(crate) writer_expr: TokenStream2,
+    /// Whether the writer is rolled back to its state before the macro on error.
+    pub(crate) rollback: bool,
     pub(crate) format_args: FormatArgs,
 }
 
@@ -43,6 +49,10 @@ pub(crate) enum ExpandInto {
 pub(crate) struct ExpandFormatted {
     pub(crate) format: FormattingFlags,
     pub(crate) local_variable: Ident,
+    /// The variable that the width is taken from, eg: `{:1$}`, `{:w$}`.
+    pub(crate) width_variable: Option<Ident>,
+    /// The variable that the precision is taken from, eg: `{:.1$}`, `{:.p$}`, `{:.*}`.
+    pub(crate) precision_variable: Option<Ident>,
 }
 
 pub(crate) struct FormatArg {
@@ -57,50 +67,120 @@ pub(crate) struct FormatArg {
 
 ////////////////////////////////////////////////
 
-impl ExpandInto {
-    pub(crate) fn formatting_flags(&self) -> FormattingFlags {
-        match self {
-            Self::Str { .. } => FormattingFlags::NEW,
-            Self::Formatted(fmted) => fmted.format,
+impl ExpandFormatted {
+    /// Whether the argument has a width, which may be taken from another argument.
+    pub(crate) fn is_padded(&self) -> bool {
+        self.format.padding.is_padded() || self.width_variable.is_some()
+    }
+
+    /// Outputs the `FormattingFlags` for this argument,
+    /// setting the width and precision that are taken from other arguments.
+    pub(crate) fn flags_tokens(&self) -> TokenStream2 {
+        let flags = self.format;
+        let mut tokens = quote!(#flags);
+
+        // `identity::<&usize>` allows the variable to be either a `usize` or a `&usize`,
+        // since the arguments of some macros are references,
+        // while constants from scope aren't.
+        if let Some(width) = &self.width_variable {
+            tokens.extend(quote!(
+                .set_width(*__cf_osRcTFl4A::pmr::identity::<&usize>(&#width))
+            ));
+        }
+        if let Some(precision) = &self.precision_variable {
+            tokens.extend(quote!(
+                .set_precision(*__cf_osRcTFl4A::pmr::identity::<&usize>(&#precision))
+            ));
+        }
+
+        tokens
+    }
+}
+
+impl ExpandFormatted {
+    /// Outputs the call that formats this argument into `formatter`,
+    /// which evaluates to a `Result<(), const_format::Error>`.
+    ///
+    /// `formatter` must be an expression of type `&mut Formatter<'_>`
+    /// or a `Formatter<'_>` variable.
+    fn fmt_arg_call(&self, formatter: TokenStream2) -> TokenStream2 {
+        let local_variable = &self.local_variable;
+        let span = local_variable.span();
+
+        match self.format.formatting {
+            Formatting::Display => quote_spanned!(span=>
+                __cf_osRcTFl4A::coerce_to_fmt!(&#local_variable)
+                    .const_display_fmt(#formatter)
+            ),
+            Formatting::Debug { .. } => quote_spanned!(span=>
+                __cf_osRcTFl4A::coerce_to_fmt!(&#local_variable)
+                    .const_debug_fmt(#formatter)
+            ),
         }
     }
+
+    /// Outputs the call that formats this argument, including the padding.
+    fn padded_fmt_call(&self, formatter: &Ident) -> TokenStream2 {
+        let flags = self.flags_tokens();
+        let span = self.local_variable.span();
+
+        if self.is_padded() && self.format.formatting == Formatting::Display {
+            // Padding the whole argument,
+            // in case that it was not padded by its `const_display_fmt` method.
+            //
+            // Debug formatted arguments aren't padded as a whole,
+            // the width is passed down to the numbers that they contain, like std does.
+            let call = self.fmt_arg_call(quote_spanned!(span=> &mut fmt));
+
+            // Formatters that write into a `core::fmt::Write` need the length of the argument
+            // to write the padding before it.
+            let measure_call = self.fmt_arg_call(quote_spanned!(span=>
+                &mut fmt.__measuring_formatter(&mut str_len, fmt.flags())
+            ));
+            quote_spanned!(span=>{
+                let mut fmt = #formatter.make_formatter(#flags);
+                let start = if fmt.__pads_ahead() {
+                    let mut str_len = __cf_osRcTFl4A::pmr::ComputeStrLength::new();
+                    let _ = #measure_call;
+                    fmt.__padding_start_measured(&str_len)
+                } else {
+                    __cf_osRcTFl4A::pmr::Ok(fmt.padding_start())
+                };
+                match start {
+                    __cf_osRcTFl4A::pmr::Ok(start) => match #call {
+                        __cf_osRcTFl4A::pmr::Ok(()) => fmt.pad_since(start),
+                        __cf_osRcTFl4A::pmr::Err(e) => __cf_osRcTFl4A::pmr::Err(e),
+                    },
+                    __cf_osRcTFl4A::pmr::Err(e) => __cf_osRcTFl4A::pmr::Err(e),
+                }
+            })
+        } else {
+            self.fmt_arg_call(quote_spanned!(span=> &mut #formatter.make_formatter(#flags)))
+        }
+    }
+}
+
+impl ExpandInto {
     pub(crate) fn len_call(&self, strlen: &Ident) -> TokenStream2 {
-        let flags = self.formatting_flags();
         match self {
             ExpandInto::Str(str, _) => {
                 let len = str.len();
                 quote!( #strlen.add_len(#len); )
             }
             ExpandInto::Formatted(fmted) => {
-                let len_method = fmted.format.len_method_name();
-                let local_variable = &fmted.local_variable;
-                let span = local_variable.span();
-
-                quote_spanned!(span=>
-                    let _ = __cf_osRcTFl4A::coerce_to_fmt!(#local_variable)
-                        .#len_method(&mut #strlen.make_formatter(#flags));
-                )
+                let call = fmted.padded_fmt_call(strlen);
+                quote_spanned!(fmted.local_variable.span()=> let _ = #call; )
             }
         }
     }
     pub(crate) fn fmt_call(&self, formatter: &Ident) -> TokenStream2 {
-        let flags = self.formatting_flags();
         match self {
             ExpandInto::Str(str, rawness) => {
                 let str_tokens = rawness.tokenize_sub(str);
 
                 quote_spanned!(rawness.span()=> #formatter.write_str(#str_tokens) )
             }
-            ExpandInto::Formatted(fmted) => {
-                let fmt_method = fmted.format.fmt_method_name();
-                let local_variable = &fmted.local_variable;
-                let span = local_variable.span();
-
-                quote_spanned!(span=>
-                    __cf_osRcTFl4A::coerce_to_fmt!(&#local_variable)
-                        .#fmt_method(&mut #formatter.make_formatter(#flags))
-                )
-            }
+            ExpandInto::Formatted(fmted) => fmted.padded_fmt_call(formatter),
         }
     }
 }
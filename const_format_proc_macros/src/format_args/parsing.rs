@@ -9,7 +9,7 @@ use crate::{
     utils::{dummy_ident, LinearResult},
 };
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenTree as TokenTree2};
 
 ////////////////////////////////////////////////
 
@@ -186,10 +186,19 @@ impl FormatArgs {
                 .into_iter()
                 .map(|fmt_str_comp| match fmt_str_comp {
                     FmtStrComponent::Str(str) => ExpandInto::Str(str, str_rawness),
-                    FmtStrComponent::Arg(arg) => ExpandInto::Formatted(ExpandFormatted {
-                        local_variable: get_variable_name(arg.which_arg),
-                        format: arg.formatting,
-                    }),
+                    FmtStrComponent::Arg(arg) => {
+                        // The precision goes first,
+                        // because `{:.*}` takes it from the next positional argument,
+                        // before the formatted argument.
+                        let precision_variable = arg.precision_arg.map(&mut get_variable_name);
+                        let width_variable = arg.width_arg.map(&mut get_variable_name);
+                        ExpandInto::Formatted(ExpandFormatted {
+                            local_variable: get_variable_name(arg.which_arg),
+                            format: arg.formatting,
+                            width_variable,
+                            precision_variable,
+                        })
+                    }
                 })
                 .collect()
         };
@@ -228,10 +237,16 @@ impl MyParse for WriteArgs {
         let (writer_expr, _span) =
             content.parse_unwrap_tt(|content| Ok(content.parse_token_stream_and_span()))?;
 
+        let rollback = matches!(input.peek(), Some(TokenTree2::Ident(x)) if x == "rollback");
+        if rollback {
+            input.next();
+        }
+
         let format_args = FormatArgs::parse_with(input, prefix)?;
 
         Ok(Self {
             writer_expr,
+            rollback,
             format_args,
         })
     }
@@ -17,7 +17,7 @@ mod tests;
 pub(crate) fn concatcp_impl(
     args: WithProcMacroArgs<ConcatArgs>,
 ) -> Result<TokenStream2, crate::Error> {
-    let cratep = args.crate_path.to_string().parse::<TokenStream2>().unwrap();
+    let cratep = args.crate_path;
 
     let fmt_var = Ident::new("fmt", Span::mixed_site());
 
@@ -55,7 +55,7 @@ pub(crate) fn concatcp_impl(
 pub(crate) fn formatcp_impl(
     args: WithProcMacroArgs<FormatArgs>,
 ) -> Result<TokenStream2, crate::Error> {
-    let cratep = args.crate_path.to_string().parse::<TokenStream2>().unwrap();
+    let cratep = args.crate_path;
     let fmt_args = args.value;
 
     let locals = fmt_args.args.iter().map(|arg| {
@@ -75,7 +75,7 @@ pub(crate) fn formatcp_impl(
         }
         ExpandInto::Formatted(fmted) => {
             let to_pargument_m = fmted.format.to_pargument_method_name();
-            let formatting = fmted.format;
+            let formatting = fmted.flags_tokens();
             let local_variable = &fmted.local_variable;
             let span = local_variable.span();
             // I had to use `set_span_recursive` to set the span to that of the argument,
@@ -114,10 +114,22 @@ pub(crate) fn formatcp_impl(
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// What the `formatc`-like macros evaluate to.
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum FormatcOutput {
+    /// A `&'static str`, for `formatc`
+    Str,
+    /// The length of the formatted string, for `formatted_len`
+    Length,
+    /// An exactly sized `StrWriter<[u8; N]>`, for `formatc_writer`
+    Writer,
+}
+
 pub(crate) fn formatc_macro_impl(
     args: WithProcMacroArgs<FormatArgs>,
+    output: FormatcOutput,
 ) -> Result<TokenStream2, crate::Error> {
-    let cratep = args.crate_path.to_string().parse::<TokenStream2>().unwrap();
+    let cratep = args.crate_path;
 
     let fmt_args = args.value;
 
@@ -136,7 +148,7 @@ pub(crate) fn formatc_macro_impl(
         .iter()
         .map(|ei| ei.fmt_call(&strwriter));
 
-    Ok(quote!(({
+    let computing_length = quote!(
         use #cratep as __cf_osRcTFl4A;
 
         const fn len_NHPMWYD3NJA() ->  usize {
@@ -149,6 +161,29 @@ pub(crate) fn formatc_macro_impl(
             #strlen.len()
         }
         const LEN_NHPMWYD3NJA: usize = len_NHPMWYD3NJA();
+    );
+
+    if output == FormatcOutput::Length {
+        return Ok(quote!(({
+            #computing_length
+
+            LEN_NHPMWYD3NJA
+        })));
+    }
+
+    let returned = if output == FormatcOutput::Writer {
+        quote!(STR_WRITER_NHPMWYD3NJA.writer)
+    } else {
+        quote!({
+            const STR_NHPMWYD3NJA: &str =
+                __cf_osRcTFl4A::strwriter_as_str!(&STR_WRITER_NHPMWYD3NJA.writer);
+
+            STR_NHPMWYD3NJA
+        })
+    };
+
+    Ok(quote!(({
+        #computing_length
 
         const fn str_writer_NHPMWYD3NJA(
         )-> __cf_osRcTFl4A::msg::ErrorTupleAndStrWriter<[u8; LEN_NHPMWYD3NJA]> {
@@ -188,17 +223,15 @@ pub(crate) fn formatc_macro_impl(
             >::NEW
         };
 
-        const STR_NHPMWYD3NJA: &str =
-            __cf_osRcTFl4A::strwriter_as_str!(&STR_WRITER_NHPMWYD3NJA.writer);
-
-        STR_NHPMWYD3NJA
+        #returned
     })))
 }
 
 pub(crate) fn writec_macro_impl(args: WithProcMacroArgs<WriteArgs>) -> Result<TokenStream2, Error> {
-    let cratep = args.crate_path.to_string().parse::<TokenStream2>().unwrap();
+    let cratep = args.crate_path;
 
     let writer_expr = args.value.writer_expr;
+    let rollback = args.value.rollback;
     let FormatArgs {
         expanded_into,
         args,
@@ -211,6 +244,35 @@ pub(crate) fn writec_macro_impl(args: WithProcMacroArgs<WriteArgs>) -> Result<To
 
     let writing_formatted = expanded_into.iter().map(|ei| ei.fmt_call(&strwriter));
 
+    let checkpoint = Ident::new("checkpoint", Span::mixed_site());
+
+    let writing = quote! {
+        loop {
+            #(
+                __cf_osRcTFl4A::unwrap_or_else!(
+                    #writing_formatted,
+                    |e| break Err(e)
+                );
+            )*
+            break Ok(());
+        }
+    };
+
+    let writing = if rollback {
+        quote! {
+            let #checkpoint = #strwriter.checkpoint();
+            match #writing {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    #strwriter.rollback(#checkpoint);
+                    Err(e)
+                }
+            }
+        }
+    } else {
+        writing
+    };
+
     Ok(quote! {({
         use #cratep as __cf_osRcTFl4A;
 
@@ -225,15 +287,7 @@ pub(crate) fn writec_macro_impl(args: WithProcMacroArgs<WriteArgs>) -> Result<To
                 let mut #strwriter =
                     #strwriter.make_formatter(__cf_osRcTFl4A::FormattingFlags::NEW);
 
-                loop {
-                    #(
-                        __cf_osRcTFl4A::unwrap_or_else!(
-                            #writing_formatted,
-                            |e| break Err(e)
-                        );
-                    )*
-                    break Ok(());
-                }
+                #writing
             }
         }
     })})
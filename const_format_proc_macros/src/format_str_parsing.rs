@@ -1,5 +1,5 @@
 use crate::{
-    formatting::{FormattingFlags, IsAlternate, NumberFormatting},
+    formatting::{Alignment, FormattingFlags, IsAlternate, NumberFormatting, Padding},
     parse_utils::StrRawness,
 };
 
@@ -26,6 +26,10 @@ pub(crate) enum FmtStrComponent {
 pub(crate) struct FmtArg {
     pub(crate) which_arg: WhichArg,
     pub(crate) formatting: FormattingFlags,
+    /// The argument that the width is taken from, eg: `{:1$}`, `{:w$}`.
+    pub(crate) width_arg: Option<WhichArg>,
+    /// The argument that the precision is taken from, eg: `{:.1$}`, `{:.p$}`, `{:.*}`.
+    pub(crate) precision_arg: Option<WhichArg>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -34,6 +38,14 @@ pub(crate) enum WhichArg {
     Positional(Option<usize>),
 }
 
+/// The width or precision of an argument.
+#[derive(Debug, PartialEq)]
+enum Count {
+    Literal(usize),
+    /// Taken from an argument, eg: `{:1$}`, `{:w$}`.
+    Arg(WhichArg),
+}
+
 /////////////////////////////////////
 
 #[allow(dead_code)]
@@ -42,10 +54,7 @@ impl FmtStrComponent {
         Self::Str(s.to_string())
     }
     fn arg(which_arg: WhichArg, formatting: FormattingFlags) -> Self {
-        Self::Arg(FmtArg {
-            which_arg,
-            formatting,
-        })
+        Self::Arg(FmtArg::new(which_arg, formatting))
     }
 }
 
@@ -55,8 +64,20 @@ impl FmtArg {
         Self {
             which_arg,
             formatting,
+            width_arg: None,
+            precision_arg: None,
         }
     }
+
+    fn with_count_args(
+        mut self,
+        width_arg: Option<WhichArg>,
+        precision_arg: Option<WhichArg>,
+    ) -> Self {
+        self.width_arg = width_arg;
+        self.precision_arg = precision_arg;
+        self
+    }
 }
 
 #[allow(dead_code)]
@@ -158,10 +179,11 @@ fn parse_fmt_arg(input: &str, starts_at: usize) -> Result<FmtArg, ParseError> {
     let formatting_str = colon.map_or("", |x| &input[x + 1..]);
     let formatting_starts_at = colon.map_or(input.len(), |x| starts_at + x + 1);
 
-    Ok(FmtArg::new(
-        parse_which_arg(which_arg_str, starts_at)?,
-        parse_formatting(formatting_str, formatting_starts_at)?,
-    ))
+    let which_arg = parse_which_arg(which_arg_str, starts_at)?;
+    let (formatting, width_arg, precision_arg) =
+        parse_formatting(formatting_str, formatting_starts_at)?;
+
+    Ok(FmtArg::new(which_arg, formatting).with_count_args(width_arg, precision_arg))
 }
 
 /// Parses the name of the argument in `{foo}`, `{}`, `{bar:?}`
@@ -185,18 +207,14 @@ fn parse_which_arg(input: &str, starts_at: usize) -> Result<WhichArg, ParseError
     }
 }
 
-/// Parses the `?` and other formatters inside formatting arguments (`{}`).
+/// Parses the `?` and other formatters inside formatting arguments (`{}`),
+/// returning the arguments that the width and precision are taken from.
 ///
 /// `starts_at` is the offset of `input` in the formatting string.
-fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, ParseError> {
-    match input {
-        "#" => return Ok(FormattingFlags::display(IsAlternate::Yes)),
-        "" => return Ok(FormattingFlags::display(IsAlternate::No)),
-        _ => {}
-    }
-
-    let mut bytes = input.as_bytes();
-
+fn parse_formatting(
+    input: &str,
+    starts_at: usize,
+) -> Result<(FormattingFlags, Option<WhichArg>, Option<WhichArg>), ParseError> {
     let make_error = || ParseError {
         pos: starts_at,
         kind: ParseErrorKind::UnknownFormatting {
@@ -204,22 +222,164 @@ fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, Pa
         },
     };
 
+    let (padding, mut rem) = parse_padding(input);
+
+    // The `-` flag is accepted for compatibility with std, which doesn't use it either.
+    let mut sign_plus = false;
+    if let Some(after) = rem.strip_prefix('+') {
+        sign_plus = true;
+        rem = after;
+    } else if let Some(after) = rem.strip_prefix('-') {
+        rem = after;
+    }
+
+    let mut is_alternate = IsAlternate::No;
+    if let Some(after) = rem.strip_prefix('#') {
+        is_alternate = IsAlternate::Yes;
+        rem = after;
+    }
+
+    let (padding, width_arg, rem) = parse_width(padding, rem).ok_or_else(make_error)?;
+    let (precision, rem) = parse_precision(rem).ok_or_else(make_error)?;
+
+    let (precision, precision_arg) = match precision {
+        Some(Count::Literal(x)) => (Some(x), None),
+        Some(Count::Arg(x)) => (None, Some(x)),
+        None => (None, None),
+    };
+
+    if rem.is_empty() {
+        let flags = FormattingFlags::display(is_alternate)
+            .with_padding(padding)
+            .with_sign_plus(sign_plus)
+            .with_precision(precision);
+        return Ok((flags, width_arg, precision_arg));
+    }
+
+    let mut bytes = rem.as_bytes();
+
     if let [before @ .., b'?'] = bytes {
         bytes = before;
     }
 
     let mut num_fmt = NumberFormatting::Decimal;
-    let mut is_alternate = IsAlternate::No;
 
     for byte in bytes {
         match byte {
             b'b' if num_fmt.is_regular() => num_fmt = NumberFormatting::Binary,
-            b'x' if num_fmt.is_regular() => num_fmt = NumberFormatting::Hexadecimal,
+            // `{:x}` and `{:x?}` write uppercase hexadecimal,
+            // the same as std's `{:X}` and `{:X?}`
+            b'x' | b'X' if num_fmt.is_regular() => num_fmt = NumberFormatting::Hexadecimal,
+            b'o' if num_fmt.is_regular() => num_fmt = NumberFormatting::Octal,
             b'#' => is_alternate = IsAlternate::Yes,
             _ => return Err(make_error()),
         }
     }
-    Ok(FormattingFlags::debug(num_fmt, is_alternate))
+    let flags = FormattingFlags::debug(num_fmt, is_alternate)
+        .with_padding(padding)
+        .with_sign_plus(sign_plus)
+        .with_precision(precision);
+    Ok((flags, width_arg, precision_arg))
+}
+
+/// Parses the `[[fill]align]` at the start of the formatting in an argument,
+/// returning the unparsed remainder of `input`.
+fn parse_padding(input: &str) -> (Padding, &str) {
+    let mut padding = Padding::NONE;
+    let mut chars = input.chars();
+
+    let first = match chars.next() {
+        Some(x) => x,
+        None => return (padding, input),
+    };
+
+    if let Some(alignment) = chars.next().and_then(Alignment::from_char) {
+        padding.fill = first;
+        padding.alignment = Some(alignment);
+        (padding, &input[first.len_utf8() + 1..])
+    } else if let Some(alignment) = Alignment::from_char(first) {
+        padding.alignment = Some(alignment);
+        (padding, &input[1..])
+    } else {
+        (padding, input)
+    }
+}
+
+/// Parses the `[0][width]` of the formatting in an argument,
+/// returning the argument that the width is taken from,
+/// and the unparsed remainder of `input`.
+fn parse_width(mut padding: Padding, mut input: &str) -> Option<(Padding, Option<WhichArg>, &str)> {
+    // `{:0$}` takes the width from the first argument
+    if input.starts_with('0') && !input.starts_with("0$") {
+        padding.sign_aware_zero_pad = true;
+        input = &input[1..];
+    }
+
+    let (width, rem) = parse_count(input)?;
+
+    let width_arg = match width {
+        Some(Count::Literal(x)) => {
+            padding.width = x;
+            None
+        }
+        Some(Count::Arg(x)) => Some(x),
+        None => None,
+    };
+
+    Some((padding, width_arg, rem))
+}
+
+/// Parses the `[.precision]` of the formatting in an argument,
+/// returning the unparsed remainder of `input`.
+fn parse_precision(input: &str) -> Option<(Option<Count>, &str)> {
+    let input = match input.strip_prefix('.') {
+        Some(x) => x,
+        None => return Some((None, input)),
+    };
+
+    if let Some(rem) = input.strip_prefix('*') {
+        // `{:.*}` takes the precision from the next positional argument
+        return Some((Some(Count::Arg(WhichArg::Positional(None))), rem));
+    }
+
+    match parse_count(input)? {
+        (Some(count), rem) => Some((Some(count), rem)),
+        (None, _) => None,
+    }
+}
+
+/// Parses a width or precision, either an integer or an argument followed by `$`,
+/// returning the unparsed remainder of `input`.
+///
+/// Returns `None` if the count is invalid.
+fn parse_count(input: &str) -> Option<(Option<Count>, &str)> {
+    use unicode_xid::UnicodeXID;
+
+    let arg_len = input
+        .char_indices()
+        .find(|(_, c)| !c.is_xid_continue())
+        .map_or(input.len(), |(i, _)| i);
+
+    if let Some(rem) = input[arg_len..].strip_prefix('$') {
+        if arg_len == 0 {
+            return None;
+        }
+        let arg = parse_which_arg(&input[..arg_len], 0).ok()?;
+        return Some((Some(Count::Arg(arg)), rem));
+    }
+
+    let digits = input
+        .bytes()
+        .position(|b| !b.is_ascii_digit())
+        .unwrap_or(input.len());
+
+    if digits == 0 {
+        return Some((None, input));
+    }
+
+    let count = input[..digits].parse::<usize>().ok()?;
+
+    Some((Some(Count::Literal(count)), &input[digits..]))
 }
 
 /// Parses an identifier in a formatting argument.
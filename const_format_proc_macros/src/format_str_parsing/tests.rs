@@ -2,7 +2,7 @@ use super::*;
 
 use super::{ParseError as PE, ParseErrorKind as PEK};
 
-use crate::formatting::{FormattingFlags as FF, IsAlternate};
+use crate::formatting::{Alignment, FormattingFlags as FF, IsAlternate, Padding};
 
 use fastrand::Rng;
 
@@ -193,6 +193,346 @@ fn ok_cases() {
     );
 }
 
+#[test]
+fn number_formatting_cases() {
+    use self::NumberFormatting as NF;
+
+    let cases = [
+        ("{:x}", FF::debug(NF::Hexadecimal, NOALT)),
+        ("{:X}", FF::debug(NF::Hexadecimal, NOALT)),
+        ("{:o}", FF::debug(NF::Octal, NOALT)),
+        ("{:b}", FF::debug(NF::Binary, NOALT)),
+        ("{:#x}", FF::debug(NF::Hexadecimal, IsAlternate::Yes)),
+        ("{:#X}", FF::debug(NF::Hexadecimal, IsAlternate::Yes)),
+        ("{:#o}", FF::debug(NF::Octal, IsAlternate::Yes)),
+        ("{:x?}", FF::debug(NF::Hexadecimal, NOALT)),
+        ("{:X?}", FF::debug(NF::Hexadecimal, NOALT)),
+        ("{:#X?}", FF::debug(NF::Hexadecimal, IsAlternate::Yes)),
+        ("{:o?}", FF::debug(NF::Octal, NOALT)),
+    ];
+
+    for (input, flags) in cases.iter().cloned() {
+        assert_eq!(
+            ok(input).list,
+            vec![FmtStrComponent::arg(WhichArg::Positional(None), flags)],
+            "input: {:?}",
+            input,
+        );
+    }
+
+    for input in ["{:xX}", "{:oX}", "{:bo}", "{:O}", "{:B}"].iter().cloned() {
+        assert_eq!(
+            err(input),
+            PE {
+                pos: 2,
+                kind: PEK::unknown_formatting(&input[2..input.len() - 1])
+            }
+        );
+    }
+}
+
+#[test]
+fn padding_cases() {
+    const ALT: IsAlternate = IsAlternate::Yes;
+
+    fn padding(
+        width: usize,
+        fill: char,
+        alignment: Option<Alignment>,
+        sign_aware_zero_pad: bool,
+    ) -> Padding {
+        Padding {
+            width,
+            fill,
+            alignment,
+            sign_aware_zero_pad,
+        }
+    }
+
+    let cases = vec![
+        (
+            "{:8}",
+            FF::display(NOALT).with_padding(padding(8, ' ', None, false)),
+        ),
+        (
+            "{:<12}",
+            FF::display(NOALT).with_padding(padding(12, ' ', Some(Alignment::Left), false)),
+        ),
+        (
+            "{:>8}",
+            FF::display(NOALT).with_padding(padding(8, ' ', Some(Alignment::Right), false)),
+        ),
+        (
+            "{:*^20}",
+            FF::display(NOALT).with_padding(padding(20, '*', Some(Alignment::Center), false)),
+        ),
+        (
+            "{:ñ<3}",
+            FF::display(NOALT).with_padding(padding(3, 'ñ', Some(Alignment::Left), false)),
+        ),
+        (
+            "{:>>3}",
+            FF::display(NOALT).with_padding(padding(3, '>', Some(Alignment::Right), false)),
+        ),
+        (
+            "{:08}",
+            FF::display(NOALT).with_padding(padding(8, ' ', None, true)),
+        ),
+        ("{:#}", FF::display(ALT)),
+        (
+            "{:#5}",
+            FF::display(ALT).with_padding(padding(5, ' ', None, false)),
+        ),
+        (
+            "{:#010x}",
+            FF::debug(NumberFormatting::Hexadecimal, ALT)
+                .with_padding(padding(10, ' ', None, true)),
+        ),
+        (
+            "{:_<5?}",
+            FF::debug(NFDEC, NOALT).with_padding(padding(5, '_', Some(Alignment::Left), false)),
+        ),
+        (
+            "{:^#9b?}",
+            FF::debug(NumberFormatting::Binary, ALT).with_padding(padding(
+                9,
+                ' ',
+                Some(Alignment::Center),
+                false,
+            )),
+        ),
+    ];
+
+    for (input, flags) in cases {
+        assert_eq!(
+            ok(input).list,
+            vec![FmtStrComponent::arg(WhichArg::Positional(None), flags)],
+            "input: {:?}",
+            input,
+        );
+    }
+
+    assert_eq!(
+        err("  {:<8!} "),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("<8!")
+        }
+    );
+    assert_eq!(
+        err("  {:<8<} "),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("<8<")
+        }
+    );
+    assert_eq!(
+        err("  {:99999999999999999999999} "),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("99999999999999999999999")
+        }
+    );
+}
+
+#[test]
+fn precision_cases() {
+    let cases = vec![
+        ("{:.0}", FF::display(NOALT).with_precision(Some(0))),
+        ("{:.5}", FF::display(NOALT).with_precision(Some(5))),
+        (
+            "{:>8.3}",
+            FF::display(NOALT)
+                .with_padding(Padding {
+                    width: 8,
+                    fill: ' ',
+                    alignment: Some(Alignment::Right),
+                    sign_aware_zero_pad: false,
+                })
+                .with_precision(Some(3)),
+        ),
+        (
+            "{:#.4x}",
+            FF::debug(NumberFormatting::Hexadecimal, IsAlternate::Yes).with_precision(Some(4)),
+        ),
+        ("{:.12?}", FF::debug(NFDEC, NOALT).with_precision(Some(12))),
+    ];
+
+    for (input, flags) in cases {
+        assert_eq!(
+            ok(input).list,
+            vec![FmtStrComponent::arg(WhichArg::Positional(None), flags)],
+            "input: {:?}",
+            input,
+        );
+    }
+
+    for (format_str, input) in &[
+        ("  {:.} ", "."),
+        ("  {:.x} ", ".x"),
+        ("  {:8.} ", "8."),
+        ("  {:8.?} ", "8.?"),
+    ] {
+        assert_eq!(
+            err(format_str),
+            PE {
+                pos: 4,
+                kind: PEK::unknown_formatting(input)
+            }
+        );
+    }
+}
+
+#[test]
+fn sign_cases() {
+    let zero_pad = |width: usize| Padding {
+        width,
+        sign_aware_zero_pad: true,
+        ..Padding::NONE
+    };
+
+    let cases = vec![
+        ("{:+}", FF::display(NOALT).with_sign_plus(true)),
+        ("{:-}", FF::display(NOALT)),
+        ("{:+?}", FF::debug(NFDEC, NOALT).with_sign_plus(true)),
+        (
+            "{:+08}",
+            FF::display(NOALT)
+                .with_padding(zero_pad(8))
+                .with_sign_plus(true),
+        ),
+        (
+            "{:+#010x}",
+            FF::debug(NumberFormatting::Hexadecimal, IsAlternate::Yes)
+                .with_padding(zero_pad(10))
+                .with_sign_plus(true),
+        ),
+        (
+            "{:*>+5.2}",
+            FF::display(NOALT)
+                .with_padding(Padding {
+                    width: 5,
+                    fill: '*',
+                    alignment: Some(Alignment::Right),
+                    sign_aware_zero_pad: false,
+                })
+                .with_sign_plus(true)
+                .with_precision(Some(2)),
+        ),
+        // `+` is a fill character here
+        (
+            "{:+>3}",
+            FF::display(NOALT).with_padding(Padding {
+                width: 3,
+                fill: '+',
+                alignment: Some(Alignment::Right),
+                sign_aware_zero_pad: false,
+            }),
+        ),
+    ];
+
+    for (input, flags) in cases {
+        assert_eq!(
+            ok(input).list,
+            vec![FmtStrComponent::arg(WhichArg::Positional(None), flags)],
+            "input: {:?}",
+            input,
+        );
+    }
+
+    for (format_str, input) in &[("  {:++} ", "++"), ("  {:#+} ", "#+"), ("  {:+-} ", "+-")] {
+        assert_eq!(
+            err(format_str),
+            PE {
+                pos: 4,
+                kind: PEK::unknown_formatting(input)
+            }
+        );
+    }
+}
+
+#[test]
+fn count_arg_cases() {
+    let pos = |x: Option<usize>| Some(WhichArg::Positional(x));
+    let ident = |x: &str| Some(WhichArg::ident(x));
+    let width = |width: usize, sign_aware_zero_pad: bool| Padding {
+        width,
+        sign_aware_zero_pad,
+        ..Padding::NONE
+    };
+    let right = Padding {
+        alignment: Some(Alignment::Right),
+        ..Padding::NONE
+    };
+
+    let cases = vec![
+        ("{:1$}", FF::display(NOALT), pos(Some(1)), None),
+        ("{:0$}", FF::display(NOALT), pos(Some(0)), None),
+        ("{:w$}", FF::display(NOALT), ident("w"), None),
+        (
+            "{:>width$?}",
+            FF::debug(NFDEC, NOALT).with_padding(right),
+            ident("width"),
+            None,
+        ),
+        (
+            "{:0w$}",
+            FF::display(NOALT).with_padding(width(0, true)),
+            ident("w"),
+            None,
+        ),
+        ("{:.2$}", FF::display(NOALT), None, pos(Some(2))),
+        ("{:.prec$}", FF::display(NOALT), None, ident("prec")),
+        ("{:.*}", FF::display(NOALT), None, pos(None)),
+        (
+            "{:8.*}",
+            FF::display(NOALT).with_padding(width(8, false)),
+            None,
+            pos(None),
+        ),
+        (
+            "{:w$.p$x}",
+            FF::debug(NumberFormatting::Hexadecimal, NOALT),
+            ident("w"),
+            ident("p"),
+        ),
+        (
+            "{:#3$.3x}",
+            FF::debug(NumberFormatting::Hexadecimal, IsAlternate::Yes).with_precision(Some(3)),
+            pos(Some(3)),
+            None,
+        ),
+    ];
+
+    for (input, flags, width_arg, precision_arg) in cases {
+        assert_eq!(
+            ok(input).list,
+            vec![FmtStrComponent::Arg(
+                FmtArg::new(WhichArg::Positional(None), flags)
+                    .with_count_args(width_arg, precision_arg)
+            )],
+            "input: {:?}",
+            input,
+        );
+    }
+
+    for (format_str, input) in &[
+        ("  {:$} ", "$"),
+        ("  {:.$} ", ".$"),
+        ("  {:_$} ", "_$"),
+        ("  {:.**} ", ".**"),
+        ("  {:99999999999999999999999$} ", "99999999999999999999999$"),
+    ] {
+        assert_eq!(
+            err(format_str),
+            PE {
+                pos: 4,
+                kind: PEK::unknown_formatting(input)
+            }
+        );
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 trait RngExt {
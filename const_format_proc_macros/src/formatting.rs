@@ -12,6 +12,7 @@ pub(crate) enum Formatting {
 pub(crate) enum NumberFormatting {
     Decimal,
     Hexadecimal,
+    Octal,
     Binary,
 }
 
@@ -26,6 +27,7 @@ impl ToTokens for NumberFormatting {
         ts.append_all(match self {
             Self::Decimal => return,
             Self::Hexadecimal => quote!(.set_hexadecimal()),
+            Self::Octal => quote!(.set_octal()),
             Self::Binary => quote!(.set_binary()),
         });
     }
@@ -41,10 +43,91 @@ pub(crate) enum IsAlternate {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+impl Alignment {
+    pub(crate) fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Alignment::Left),
+            '>' => Some(Alignment::Right),
+            '^' => Some(Alignment::Center),
+            _ => None,
+        }
+    }
+}
+
+impl ToTokens for Alignment {
+    fn to_tokens(&self, ts: &mut TokenStream2) {
+        ts.append_all(match self {
+            Self::Left => quote!(__cf_osRcTFl4A::pmr::Alignment::Left),
+            Self::Right => quote!(__cf_osRcTFl4A::pmr::Alignment::Right),
+            Self::Center => quote!(__cf_osRcTFl4A::pmr::Alignment::Center),
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The width, fill, and alignment of a formatted argument.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Padding {
+    pub(crate) width: usize,
+    pub(crate) fill: char,
+    pub(crate) alignment: Option<Alignment>,
+    pub(crate) sign_aware_zero_pad: bool,
+}
+
+impl Padding {
+    pub(crate) const NONE: Self = Self {
+        width: 0,
+        fill: ' ',
+        alignment: None,
+        sign_aware_zero_pad: false,
+    };
+
+    pub(crate) fn is_padded(&self) -> bool {
+        self.width != 0
+    }
+}
+
+impl ToTokens for Padding {
+    fn to_tokens(&self, ts: &mut TokenStream2) {
+        let Self {
+            width,
+            fill,
+            alignment,
+            sign_aware_zero_pad,
+        } = *self;
+
+        if width != 0 {
+            ts.append_all(quote!(.set_width(#width)));
+        }
+        if fill != ' ' {
+            ts.append_all(quote!(.set_fill(#fill)));
+        }
+        if let Some(alignment) = alignment {
+            ts.append_all(quote!(.set_alignment(#alignment)));
+        }
+        if sign_aware_zero_pad {
+            ts.append_all(quote!(.set_sign_aware_zero_pad(true)));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) struct FormattingFlags {
     pub(crate) formatting: Formatting,
     pub(crate) is_alternate: IsAlternate,
+    pub(crate) padding: Padding,
+    pub(crate) sign_plus: bool,
+    pub(crate) precision: Option<usize>,
 }
 
 impl FormattingFlags {
@@ -53,6 +136,9 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Display,
             is_alternate,
+            padding: Padding::NONE,
+            sign_plus: false,
+            precision: None,
         }
     }
 
@@ -61,11 +147,33 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Debug(num_fmt),
             is_alternate,
+            padding: Padding::NONE,
+            sign_plus: false,
+            precision: None,
         }
     }
+
+    #[inline]
+    pub(crate) const fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    #[inline]
+    pub(crate) const fn with_sign_plus(mut self, sign_plus: bool) -> Self {
+        self.sign_plus = sign_plus;
+        self
+    }
+
+    #[inline]
+    pub(crate) const fn with_precision(mut self, precision: Option<usize>) -> Self {
+        self.precision = precision;
+        self
+    }
 }
 
 impl FormattingFlags {
+    #[allow(dead_code)]
     pub(crate) const NEW: Self = Self::display(IsAlternate::No);
 
     pub(crate) fn to_pargument_method_name(self) -> Ident {
@@ -110,10 +218,22 @@ impl ToTokens for FormattingFlags {
         ts.append_all(match (self.is_alternate, formatting) {
             (IA::No, FM::Decimal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__REG),
             (IA::No, FM::Hexadecimal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__HEX),
+            (IA::No, FM::Octal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__OCT),
             (IA::No, FM::Binary) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__BIN),
             (IA::Yes, FM::Decimal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_REG),
             (IA::Yes, FM::Hexadecimal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_HEX),
+            (IA::Yes, FM::Octal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_OCT),
             (IA::Yes, FM::Binary) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_BIN),
         });
+
+        self.padding.to_tokens(ts);
+
+        if self.sign_plus {
+            ts.append_all(quote!(.set_sign_plus(true)));
+        }
+
+        if let Some(precision) = self.precision {
+            ts.append_all(quote!(.set_precision(#precision)));
+        }
     }
 }
@@ -32,6 +32,7 @@ mod utils;
 mod test_utils;
 
 use crate::error::Error;
+use crate::format_macro::FormatcOutput;
 use crate::parse_utils::MyParse;
 
 fn compile_err_empty_str(e: crate::Error) -> TokenStream2 {
@@ -71,11 +72,29 @@ pub fn __formatcp_impl(input: TokenStream1) -> TokenStream1 {
 #[proc_macro]
 pub fn __formatc_impl(input: TokenStream1) -> TokenStream1 {
     MyParse::parse_token_stream_1(input)
-        .and_then(format_macro::formatc_macro_impl)
+        .and_then(|args| format_macro::formatc_macro_impl(args, FormatcOutput::Str))
         .unwrap_or_else(compile_err_empty_str)
         .into()
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __formatted_len_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(|args| format_macro::formatc_macro_impl(args, FormatcOutput::Length))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __formatc_writer_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(|args| format_macro::formatc_macro_impl(args, FormatcOutput::Writer))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn __writec_impl(input: TokenStream1) -> TokenStream1 {